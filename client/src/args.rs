@@ -1,4 +1,5 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 pub struct Args {
@@ -6,4 +7,35 @@ pub struct Args {
     pub port: u16,
     #[clap(long, value_parser, default_value = "65536")]
     pub resend_budget: usize,
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum Command {
+    /// Runs a battle without rendering or audio, then prints the final state
+    Simulate(SimulateArgs),
+    /// Runs the scripts in the `tests` folder of a local package
+    Test(TestArgs),
+}
+
+#[derive(clap::Args, Clone)]
+pub struct SimulateArgs {
+    /// Package id of a local battle package
+    #[clap(long, value_parser)]
+    pub battle: String,
+    /// File with one `<frames> [inputs...]` entry per line for the local player
+    #[clap(long, value_parser)]
+    pub inputs: Option<PathBuf>,
+    #[clap(long, value_parser, default_value = "3600")]
+    pub frames: i64,
+    #[clap(long, value_parser)]
+    pub seed: Option<u64>,
+}
+
+#[derive(clap::Args, Clone)]
+pub struct TestArgs {
+    /// Package id of a local package
+    #[clap(long, value_parser)]
+    pub package: String,
 }
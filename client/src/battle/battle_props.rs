@@ -1,3 +1,4 @@
+use crate::bindable::{Emotion, Team};
 use crate::packages::*;
 use crate::render::*;
 use crate::resources::*;
@@ -9,9 +10,12 @@ use std::collections::VecDeque;
 pub struct PlayerSetup<'a> {
     pub player_package: &'a PlayerPackage,
     pub folder: Folder,
+    pub emotion: Emotion,
     // todo: blocks
     pub index: usize,
     pub local: bool,
+    /// Unset to take the team of the spawn tile
+    pub team: Team,
     pub input_buffer: VecDeque<Vec<Input>>,
 }
 
@@ -21,16 +25,35 @@ impl<'a> PlayerSetup<'a> {
         let global_save = &globals.global_save;
 
         let player_package = global_save.player_package(game_io).unwrap();
-        let folder = global_save.active_folder().cloned().unwrap_or_default();
+        let mut folder = global_save.active_folder().cloned().unwrap_or_default();
+
+        // leave out cards breaking the folder rules
+        for index in folder.invalid_card_indices(game_io).into_iter().rev() {
+            let card = folder.cards.remove(index);
+            log::warn!(
+                "{:?} breaks the folder rules, leaving it out",
+                card.package_id
+            );
+        }
 
         Self {
             player_package,
             index: 0,
             folder,
+            emotion: Emotion::Normal,
             local: true,
+            team: Team::Unset,
             input_buffer: VecDeque::new(),
         }
     }
+
+    pub fn namespace(&self) -> PackageNamespace {
+        if self.local {
+            PackageNamespace::Local
+        } else {
+            PackageNamespace::Remote(self.index)
+        }
+    }
 }
 
 pub type BattleStatisticsCallback = Box<dyn FnOnce(Option<BattleStatistics>)>;
@@ -0,0 +1,164 @@
+use super::{BattleCallback, BattleSimulation, Entity, RollbackVM};
+use crate::bindable::EntityID;
+use crate::render::FrameTime;
+use crate::resources::Globals;
+use framework::prelude::GameIO;
+
+#[derive(Clone)]
+enum ScheduleTrigger {
+    After,
+    Every(FrameTime),
+    Until(BattleCallback<(), bool>),
+}
+
+#[derive(Clone)]
+struct ScheduledCallback {
+    id: usize,
+    owner: Option<EntityID>,
+    remaining_time: FrameTime,
+    trigger: ScheduleTrigger,
+    /// Repeating callbacks stop after returning true
+    callback: BattleCallback<(), bool>,
+}
+
+/// Callbacks stored in the simulation to survive rollback,
+/// entries with an owner are paused while the owner is frozen and cancelled when it's deleted
+#[derive(Default, Clone)]
+pub struct BattleScheduler {
+    entries: Vec<ScheduledCallback>,
+    next_id: usize,
+}
+
+impl BattleScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn after(
+        &mut self,
+        owner: Option<EntityID>,
+        frames: FrameTime,
+        callback: BattleCallback<(), bool>,
+    ) -> usize {
+        self.schedule(owner, frames, ScheduleTrigger::After, callback)
+    }
+
+    pub fn every(
+        &mut self,
+        owner: Option<EntityID>,
+        frames: FrameTime,
+        callback: BattleCallback<(), bool>,
+    ) -> usize {
+        let frames = frames.max(1);
+
+        self.schedule(owner, frames, ScheduleTrigger::Every(frames), callback)
+    }
+
+    /// Checks the condition every frame, calling the callback once it returns true
+    pub fn until(
+        &mut self,
+        owner: Option<EntityID>,
+        condition: BattleCallback<(), bool>,
+        callback: BattleCallback<(), bool>,
+    ) -> usize {
+        self.schedule(owner, 0, ScheduleTrigger::Until(condition), callback)
+    }
+
+    fn schedule(
+        &mut self,
+        owner: Option<EntityID>,
+        frames: FrameTime,
+        trigger: ScheduleTrigger,
+        callback: BattleCallback<(), bool>,
+    ) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.entries.push(ScheduledCallback {
+            id,
+            owner,
+            remaining_time: frames,
+            trigger,
+            callback,
+        });
+
+        id
+    }
+
+    pub fn cancel(&mut self, id: usize) {
+        self.entries.retain(|entry| entry.id != id);
+    }
+
+    pub fn update(
+        game_io: &GameIO<Globals>,
+        simulation: &mut BattleSimulation,
+        vms: &[RollbackVM],
+    ) {
+        let time_is_frozen = simulation.time_freeze_tracker.time_is_frozen();
+        let entities = &mut simulation.entities;
+        let scheduler = &mut simulation.scheduler;
+
+        // cancel entries for deleted entities
+        scheduler.entries.retain(|entry| {
+            let Some(owner) = entry.owner else {
+                return true;
+            };
+
+            entities
+                .query_one_mut::<&Entity>(owner.into())
+                .map(|entity| !entity.deleted)
+                .unwrap_or_default()
+        });
+
+        let mut due_ids = Vec::new();
+
+        for entry in &mut scheduler.entries {
+            let frozen = match entry.owner {
+                Some(owner) => entities
+                    .query_one_mut::<&Entity>(owner.into())
+                    .map(|entity| entity.time_frozen_count > 0)
+                    .unwrap_or_default(),
+                None => time_is_frozen,
+            };
+
+            if frozen {
+                continue;
+            }
+
+            entry.remaining_time -= 1;
+
+            if entry.remaining_time <= 0 {
+                due_ids.push(entry.id);
+            }
+        }
+
+        for id in due_ids {
+            // earlier callbacks may have cancelled this entry
+            let scheduler = &simulation.scheduler;
+            let Some(entry) = scheduler.entries.iter().find(|entry| entry.id == id) else {
+                continue;
+            };
+
+            let entry = entry.clone();
+
+            if let ScheduleTrigger::Until(condition) = &entry.trigger {
+                if !condition.call(game_io, simulation, vms, ()) {
+                    continue;
+                }
+            }
+
+            let stop = entry.callback.call(game_io, simulation, vms, ());
+
+            let scheduler = &mut simulation.scheduler;
+
+            match entry.trigger {
+                ScheduleTrigger::Every(frames) if !stop => {
+                    if let Some(entry) = scheduler.entries.iter_mut().find(|entry| entry.id == id) {
+                        entry.remaining_time = frames;
+                    }
+                }
+                _ => scheduler.cancel(id),
+            }
+        }
+    }
+}
@@ -9,10 +9,11 @@ use crate::resources::*;
 use crate::saves::Card;
 use framework::prelude::*;
 use generational_arena::Arena;
-use packets::structures::BattleStatistics;
+use packets::structures::{BattleStatistics, FileHash};
 use rand::SeedableRng;
 use rand_xoshiro::Xoshiro256PlusPlus;
 use std::cell::RefCell;
+use std::collections::HashMap;
 
 const DEFAULT_PLAYER_LAYOUTS: [[(i32, i32); 4]; 4] = [
     [(2, 2), (0, 0), (0, 0), (0, 0)],
@@ -39,8 +40,12 @@ pub struct BattleSimulation {
     pub animators: Arena<BattleAnimator>,
     pub card_actions: Arena<CardAction>,
     pub time_freeze_tracker: TimeFreezeTracker,
+    pub status_registry: StatusRegistry,
+    pub element_chart: ElementChart,
+    pub card_combos: HashMap<PackageNamespace, Vec<CardCombo>>,
     pub components: Arena<Component>,
     pub pending_callbacks: Vec<BattleCallback>,
+    pub scheduler: BattleScheduler,
     pub local_player_id: EntityID,
     pub local_health_ui: PlayerHealthUI,
     pub player_spawn_positions: Vec<(i32, i32)>,
@@ -82,8 +87,12 @@ impl BattleSimulation {
             animators: Arena::new(),
             card_actions: Arena::new(),
             time_freeze_tracker: TimeFreezeTracker::new(),
+            status_registry: StatusRegistry::new(),
+            element_chart: ElementChart::new(),
+            card_combos: HashMap::new(),
             components: Arena::new(),
             pending_callbacks: Vec::new(),
+            scheduler: BattleScheduler::new(),
             local_player_id: EntityID::DANGLING,
             local_health_ui: PlayerHealthUI::new(game_io),
             player_spawn_positions,
@@ -149,8 +158,12 @@ impl BattleSimulation {
             animators: self.animators.clone(),
             card_actions: self.card_actions.clone(),
             time_freeze_tracker: self.time_freeze_tracker.clone(),
+            status_registry: self.status_registry.clone(),
+            element_chart: self.element_chart.clone(),
+            card_combos: self.card_combos.clone(),
             components: self.components.clone(),
             pending_callbacks: self.pending_callbacks.clone(),
+            scheduler: self.scheduler.clone(),
             local_player_id: self.local_player_id.clone(),
             local_health_ui: self.local_health_ui.clone(),
             player_spawn_positions: self.player_spawn_positions.clone(),
@@ -161,10 +174,75 @@ impl BattleSimulation {
         }
     }
 
+    /// Resizes the field, moving player spawns and spawned entities to stay on the same side
+    pub fn resize_field(&mut self, cols: usize, rows: usize) {
+        let old_cols = self.field.cols();
+        let max_row = rows as i32 - 2;
+
+        for (x, y) in &mut self.player_spawn_positions {
+            *x = Field::nearest_resized_col(*x, old_cols, cols);
+            *y = (*y).min(max_row);
+        }
+
+        self.field.resize(cols, rows);
+
+        for (_, entity) in self.entities.query_mut::<&mut Entity>() {
+            let resized_x = Field::resized_col(entity.x, old_cols, cols);
+            entity.x = Field::nearest_resized_col(entity.x, old_cols, cols);
+
+            if !entity.on_field {
+                continue;
+            }
+
+            let x = entity.x.clamp(1, cols as i32 - 2);
+            let y = entity.y.min(max_row);
+
+            if resized_x.is_some() && (entity.x, entity.y) == (x, y) {
+                // reservations moved with the tile
+                continue;
+            }
+
+            // the tile was dropped, reserve the closest one
+            entity.x = x;
+            entity.y = y;
+
+            if let Some(tile) = self.field.tile_at_mut((x, y)) {
+                tile.handle_auto_reservation_addition(&self.card_actions, entity);
+            }
+        }
+    }
+
+    /// Zooms out to fit larger fields, keeping the bottom of the field at the same spot on screen
+    pub fn fit_camera_to_field(&mut self, game_io: &GameIO<Globals>) {
+        const TOP_MARGIN: f32 = 70.0;
+        const BOTTOM_MARGIN: f32 = 15.0;
+
+        let tile_size = self.field.tile_size();
+        let visible_cols = self.field.cols().saturating_sub(2) as f32;
+        let visible_rows = self.field.rows().saturating_sub(2) as f32;
+        let field_size = Vec2::new(visible_cols, visible_rows) * tile_size;
+
+        let available_height = RESOLUTION_F.y - TOP_MARGIN - BOTTOM_MARGIN;
+        let scale = (field_size.x / RESOLUTION_F.x)
+            .max(field_size.y / available_height)
+            .max(1.0);
+
+        let view_size = RESOLUTION_F * scale;
+
+        // the first visible row starts at 0
+        let field_bottom = field_size.y;
+        let camera_y = field_bottom + BOTTOM_MARGIN * scale - view_size.y * 0.5;
+
+        self.camera.resize(game_io, view_size);
+        self.camera.snap(Vec2::new(0.0, camera_y));
+    }
+
     pub fn initialize_uninitialized(&mut self) {
         self.field.initialize_uninitialized();
 
-        type PlayerQuery<'a> = (&'a mut Entity, &'a Player, &'a Living);
+        type PlayerQuery<'a> = (&'a mut Entity, &'a mut Player, &'a Living);
+
+        let mut taken_positions = self.player_spawn_positions.clone();
 
         for (_, (entity, player, living)) in self.entities.query_mut::<PlayerQuery>() {
             if player.local {
@@ -172,15 +250,34 @@ impl BattleSimulation {
                 self.local_health_ui.snap_health(living.health);
             }
 
-            let pos = self
+            let mut pos = self
                 .player_spawn_positions
                 .get(player.index)
                 .cloned()
                 .unwrap_or_default();
 
+            // players assigned to a team spawn on their team's tiles
+            if entity.team != Team::Unset {
+                let tile_team = self.field.tile_at_mut(pos).map(|tile| tile.original_team());
+
+                if tile_team != Some(entity.team) {
+                    let team_pos = self.field.find_team_spawn(entity.team, &taken_positions);
+
+                    if let Some(team_pos) = team_pos {
+                        pos = team_pos;
+                        taken_positions.push(pos);
+                    }
+                }
+            }
+
             entity.x = pos.0;
             entity.y = pos.1;
 
+            // players starting on tiles facing left view the field mirrored
+            if let Some(tile) = self.field.tile_at_mut(pos) {
+                player.perspective_flipped = tile.direction() == Direction::Left;
+            }
+
             let animator = &mut self.animators[entity.animator_index];
 
             if animator.current_state().is_none() {
@@ -191,9 +288,42 @@ impl BattleSimulation {
         }
     }
 
+    /// Hashes the time, rng, and the state of every entity, used to compare separate runs
+    pub fn checksum(&self) -> FileHash {
+        use rand::RngCore;
+
+        let mut bytes = Vec::new();
+        bytes.extend(self.time.to_le_bytes());
+        bytes.extend(self.rng.clone().next_u64().to_le_bytes());
+
+        for (_, (entity, living)) in self.entities.query::<(&Entity, Option<&Living>)>().iter() {
+            bytes.extend(entity.name.as_bytes());
+            bytes.extend(entity.x.to_le_bytes());
+            bytes.extend(entity.y.to_le_bytes());
+            bytes.push(entity.deleted as u8);
+
+            if let Some(living) = living {
+                bytes.extend(living.health.to_le_bytes());
+                bytes.extend(living.max_health.to_le_bytes());
+            }
+        }
+
+        FileHash::hash(&bytes)
+    }
+
     pub fn play_sound(&self, game_io: &GameIO<Globals>, sound_buffer: &SoundBuffer) {
+        self.play_sound_with_options(game_io, sound_buffer, SoundOptions::default());
+    }
+
+    pub fn play_sound_with_options(
+        &self,
+        game_io: &GameIO<Globals>,
+        sound_buffer: &SoundBuffer,
+        options: SoundOptions,
+    ) {
         if !self.is_resimulation {
-            game_io.globals().audio.play_sound(sound_buffer);
+            let audio = &game_io.globals().audio;
+            audio.play_sound_with_options(sound_buffer, options);
         }
     }
 
@@ -255,7 +385,10 @@ impl BattleSimulation {
             }
 
             if let Some(living) = self.entities.query_one::<&Living>(id).unwrap().get() {
-                if living.status_director.is_inactionable() {
+                if living
+                    .status_director
+                    .is_inactionable(&self.status_registry)
+                {
                     continue;
                 }
             }
@@ -278,7 +411,10 @@ impl BattleSimulation {
             }
 
             if let Ok(living) = self.entities.query_one_mut::<&Living>(action.entity.into()) {
-                if living.status_director.is_inactionable() {
+                if living
+                    .status_director
+                    .is_inactionable(&self.status_registry)
+                {
                     continue;
                 }
             }
@@ -442,7 +578,9 @@ impl BattleSimulation {
         }
 
         if let Ok(living) = entities.query_one_mut::<&Living>(entity_id.into()) {
-            return !living.status_director.is_inactionable();
+            return !living
+                .status_director
+                .is_inactionable(&self.status_registry);
         };
 
         true
@@ -480,13 +618,33 @@ impl BattleSimulation {
         if card_action.properties.time_freeze {
             let time_freeze_tracker = &mut self.time_freeze_tracker;
 
-            if time_is_frozen && !self.is_resimulation {
-                // must be countering, play sfx
-                let globals = game_io.globals();
-                globals.audio.play_sound(&globals.trap_sfx);
+            if time_is_frozen {
+                // must be countering
+                if !self.is_resimulation {
+                    let globals = game_io.globals();
+                    globals.audio.play_sound(&globals.trap_sfx);
+                }
+
+                let countered_id = time_freeze_tracker
+                    .last_action()
+                    .and_then(|index| self.card_actions.get(index))
+                    .map(|action| action.entity);
+
+                if let Some(countered_id) = countered_id {
+                    for callback in time_freeze_tracker.counter_callbacks() {
+                        let callback = callback.clone();
+
+                        self.pending_callbacks.push(BattleCallback::new(
+                            move |game_io, simulation, vms, _| {
+                                callback.call(game_io, simulation, vms, (entity_id, countered_id));
+                            },
+                        ));
+                    }
+                }
             }
 
-            time_freeze_tracker.set_team_action(entity.team, index);
+            let card_action = &self.card_actions[index];
+            time_freeze_tracker.set_team_action(entity.team, index, &card_action.properties);
         } else {
             entity.card_action_index = Some(index);
         }
@@ -749,6 +907,8 @@ impl BattleSimulation {
         living.set_health(player_package.health);
         living.status_director.set_input_index(index);
 
+        let emotion_window = EmotionWindow::new(game_io, player_package);
+
         // derive states
         let move_anim_state = BattleAnimator::derive_state(
             &mut self.animators,
@@ -839,9 +999,7 @@ impl BattleSimulation {
                     return;
                 }
 
-                if !entity.element.is_weak_to(hit_props.element)
-                    && !entity.element.is_weak_to(hit_props.secondary_element)
-                {
+                if !hit_props.is_super_effective(&simulation.element_chart, entity.element) {
                     // not super effective
                     return;
                 }
@@ -879,10 +1037,38 @@ impl BattleSimulation {
             },
         ));
 
+        // hit callback for emotions
+        living.register_hit_callback(BattleCallback::new(
+            move |game_io, simulation, vms, hit_props: HitProperties| {
+                let player = simulation
+                    .entities
+                    .query_one_mut::<&Player>(id.into())
+                    .unwrap();
+
+                if hit_props.damage >= Player::ANGER_DAMAGE_THRESHOLD {
+                    Player::set_emotion(simulation, id, Emotion::Angry as usize);
+                } else if hit_props.damage > 0
+                    && player.emotion_id() == Emotion::FullSynchro as usize
+                {
+                    // getting hit breaks synchro
+                    Player::set_emotion(simulation, id, Emotion::Normal as usize);
+                }
+
+                simulation.call_pending_callbacks(game_io, vms);
+            },
+        ));
+
         self.entities
             .insert(
                 id.into(),
-                (Player::new(game_io, index, local, charge_index, cards),),
+                (Player::new(
+                    game_io,
+                    index,
+                    local,
+                    charge_index,
+                    emotion_window,
+                    cards,
+                ),),
             )
             .unwrap();
 
@@ -1055,6 +1241,25 @@ impl BattleSimulation {
         id
     }
 
+    pub fn create_counter_flash(&mut self, game_io: &GameIO<Globals>) -> EntityID {
+        let id = self.create_animated_artifact(
+            game_io,
+            ResourcePaths::BATTLE_COUNTER_REVEAL,
+            ResourcePaths::BATTLE_COUNTER_REVEAL_ANIMATION,
+        );
+
+        let entity = self
+            .entities
+            .query_one_mut::<&mut Entity>(id.into())
+            .unwrap();
+
+        entity.spawn_callback = BattleCallback::new(|game_io, simulation, _, _| {
+            simulation.play_sound(game_io, &game_io.globals().counter_sfx);
+        });
+
+        id
+    }
+
     pub fn create_transformation_shine(&mut self, game_io: &GameIO<Globals>) -> EntityID {
         self.create_animated_artifact(
             game_io,
@@ -1073,13 +1278,16 @@ impl BattleSimulation {
 
     pub fn draw(&mut self, game_io: &mut GameIO<Globals>, render_pass: &mut RenderPass) {
         let mut blind_filter = None;
+        let mut local_team = Team::Red;
 
         // resolve perspective
-        if let Ok((entity, living)) =
-            (self.entities).query_one_mut::<(&Entity, &Living)>(self.local_player_id.into())
+        type LocalQuery<'a> = (&'a Entity, &'a Player, &'a Living);
+
+        if let Ok((entity, player, living)) =
+            (self.entities).query_one_mut::<LocalQuery>(self.local_player_id.into())
         {
-            //Bool assignment. If entity is team blue set the fact that they're perspective flipped to true. -Dawn
-            self.perspective_flipped = entity.team == Team::Blue;
+            self.perspective_flipped = player.perspective_flipped;
+            local_team = entity.team;
 
             if living.status_director.remaining_status_time(HitFlag::BLIND) > 0 {
                 blind_filter = Some(entity.team);
@@ -1095,8 +1303,12 @@ impl BattleSimulation {
             SpriteColorQueue::new(game_io, &self.camera, SpriteColorMode::default());
 
         // draw field
-        self.field
-            .draw(game_io, &mut sprite_queue, self.perspective_flipped);
+        self.field.draw(
+            game_io,
+            &mut sprite_queue,
+            self.perspective_flipped,
+            local_team,
+        );
 
         // draw dramatic fade
         if self.fade_sprite.color().a > 0.0 {
@@ -1208,10 +1420,14 @@ impl BattleSimulation {
 
         sprite_queue.set_shader_effect(SpriteShaderEffect::Default);
 
+        // keeps labels the same size on screen when the camera zooms out for larger fields
+        let ui_scale = self.camera.size() / RESOLUTION_F;
+
         // draw hp on living entities
         if self.intro_complete {
             let mut hp_text = Text::new(game_io, FontStyle::EntityHP);
             hp_text.style.letter_spacing = 0.0;
+            hp_text.style.scale = ui_scale;
             let tile_size = self.field.tile_size();
 
             type Query<'a> = hecs::Without<(&'a Entity, &'a Living, &'a Character), &'a Obstacle>;
@@ -1238,7 +1454,7 @@ impl BattleSimulation {
                 let entity_offset = entity.corrected_offset(self.perspective_flipped);
 
                 hp_text.text = living.health.to_string();
-                let text_size = hp_text.measure().size;
+                let text_size = hp_text.measure().size * ui_scale;
 
                 (hp_text.style.bounds).set_position(tile_center + entity_offset);
                 hp_text.style.bounds.x -= text_size.x * 0.5;
@@ -1257,11 +1473,11 @@ impl BattleSimulation {
             let tile_center =
                 (self.field).calc_tile_center((entity.x, entity.y), self.perspective_flipped);
 
-            let base_position = tile_center + vec2(offset.x, -entity.height - 16.0);
+            let base_position = tile_center + vec2(offset.x, -entity.height - 16.0 * ui_scale.y);
 
             let mut border_sprite = assets.new_sprite(game_io, ResourcePaths::WHITE_PIXEL);
             border_sprite.set_color(Color::BLACK);
-            border_sprite.set_size(Vec2::new(16.0, 16.0));
+            border_sprite.set_size(Vec2::new(16.0, 16.0) * ui_scale);
 
             for i in 0..character.cards.len() {
                 let card = &character.cards[i];
@@ -1271,13 +1487,13 @@ impl BattleSimulation {
                 };
 
                 let cards_before = character.cards.len() - i;
-                let card_offset = 2.0 * cards_before as f32;
+                let card_offset = 2.0 * cards_before as f32 * ui_scale;
                 let position = base_position - card_offset;
 
-                border_sprite.set_position(position - 1.0);
+                border_sprite.set_position(position - ui_scale);
                 sprite_queue.draw_sprite(&border_sprite);
 
-                blank_card.draw_icon(game_io, &mut sprite_queue, position);
+                blank_card.draw_scaled_icon(game_io, &mut sprite_queue, position, ui_scale);
             }
         }
 
@@ -100,17 +100,30 @@ impl Living {
             .query_one_mut::<(&Entity, &mut Living)>(entity_id.into())
             .unwrap();
 
+        // counter hits
+        let countered = living.counterable
+            && hit_props.flags & HitFlag::IMPACT != HitFlag::NONE
+            && hit_props.flags & HitFlag::NO_COUNTER == HitFlag::NONE;
+
+        if countered {
+            living.counterable = false;
+            hit_props.flags |= HitFlag::PARALYZE;
+        }
+
         let original_damage = hit_props.damage;
 
         // super effective bonus
-        if hit_props.is_super_effective(entity.element) {
-            hit_props.damage += original_damage;
+        let element_chart = &simulation.element_chart;
+        let multiplier = element_chart.super_effective_multiplier(entity.element, &hit_props);
+
+        if let Some(multiplier) = multiplier {
+            hit_props.damage += (original_damage as f32 * (multiplier - 1.0)) as i32;
         }
 
         // tile bonus
         let tile = simulation.field.tile_at_mut(tile_pos).unwrap();
 
-        if tile.apply_bonus_damage(&hit_props) {
+        if tile.apply_bonus_damage(element_chart, &hit_props) {
             hit_props.damage += original_damage;
         }
 
@@ -122,11 +135,23 @@ impl Living {
         }
 
         // apply statuses
-        living.status_director.apply_hit_flags(hit_props.flags);
+        let status_registry = &simulation.status_registry;
+        living
+            .status_director
+            .apply_hit_flags(status_registry, hit_props.flags);
 
         // store callbacks
         let hit_callbacks = living.hit_callbacks.clone();
 
+        if countered {
+            Living::apply_counter(game_io, simulation, entity_id, hit_props.aggressor);
+            simulation.call_pending_callbacks(game_io, vms);
+        }
+
+        let entity = (simulation.entities)
+            .query_one_mut::<&Entity>(entity_id.into())
+            .unwrap();
+
         // handle drag
         if hit_props.drags() && entity.move_action.is_none() {
             let can_move_to_callback = entity.can_move_to_callback.clone();
@@ -162,4 +187,34 @@ impl Living {
             callback.call(game_io, simulation, vms, hit_props);
         }
     }
+
+    fn apply_counter(
+        game_io: &GameIO<Globals>,
+        simulation: &mut BattleSimulation,
+        entity_id: EntityID,
+        aggressor_id: EntityID,
+    ) {
+        let entity = (simulation.entities)
+            .query_one_mut::<&Entity>(entity_id.into())
+            .unwrap();
+
+        let mut full_position = entity.full_position();
+        full_position.offset += Vec2::new(0.0, -entity.height * 0.5);
+
+        // display the counter flash over the countered entity
+        let flash_id = simulation.create_counter_flash(game_io);
+        let flash_entity = (simulation.entities)
+            .query_one_mut::<&mut Entity>(flash_id.into())
+            .unwrap();
+
+        flash_entity.copy_full_position(full_position);
+        flash_entity.pending_spawn = true;
+
+        // reward the aggressor
+        Player::set_emotion(simulation, aggressor_id, Emotion::FullSynchro as usize);
+
+        if aggressor_id == simulation.local_player_id {
+            simulation.statistics.counters += 1;
+        }
+    }
 }
@@ -10,6 +10,7 @@ use framework::prelude::*;
 pub struct Player {
     pub index: usize,
     pub local: bool,
+    pub perspective_flipped: bool,
     pub cards: Vec<Card>,
     pub card_use_requested: bool,
     pub charge: u8,
@@ -17,6 +18,10 @@ pub struct Player {
     pub speed: u8,
     pub card_view_size: u8,
     pub modded_hp: i32,
+    pub emotion: Emotion,
+    pub custom_emotions: Vec<CustomEmotion>,
+    pub custom_emotion_index: Option<usize>,
+    pub emotion_window: EmotionWindow,
     pub charging_time: FrameTime,
     pub max_charging_time: FrameTime,
     pub charge_sprite_index: TreeIndex,
@@ -33,6 +38,8 @@ pub struct Player {
 impl Player {
     pub const IDLE_STATE: &str = "PLAYER_IDLE";
     pub const CHARGE_DELAY: FrameTime = 10;
+    pub const ANGER_DAMAGE_THRESHOLD: i32 = 300;
+    pub const CUSTOM_EMOTION_START: usize = Emotion::Happy as usize + 1;
 
     pub const MOVE_FRAMES: [DerivedFrame; 7] = [
         DerivedFrame::new(0, 1),
@@ -51,6 +58,7 @@ impl Player {
         index: usize,
         local: bool,
         charge_sprite_index: TreeIndex,
+        emotion_window: EmotionWindow,
         cards: Vec<Card>,
     ) -> Self {
         let assets = &game_io.globals().assets;
@@ -58,6 +66,7 @@ impl Player {
         Self {
             index,
             local,
+            perspective_flipped: false,
             cards,
             card_use_requested: false,
             charge: 1,
@@ -65,6 +74,10 @@ impl Player {
             speed: 1,
             card_view_size: 5,
             modded_hp: 0,
+            emotion: Emotion::Normal,
+            custom_emotions: Vec::new(),
+            custom_emotion_index: None,
+            emotion_window,
             charging_time: 0,
             max_charging_time: 100,
             charge_sprite_index,
@@ -86,6 +99,81 @@ impl Player {
             .filter(|(_, form)| !form.activated)
     }
 
+    pub fn active_custom_emotion(&self) -> Option<&CustomEmotion> {
+        self.custom_emotion_index
+            .and_then(|index| self.custom_emotions.get(index))
+    }
+
+    /// Built in emotions use their own value, custom emotions start after the built in emotions
+    pub fn emotion_id(&self) -> usize {
+        match self.custom_emotion_index {
+            Some(index) => Self::CUSTOM_EMOTION_START + index,
+            None => self.emotion as usize,
+        }
+    }
+
+    pub fn emotion_damage_multiplier(&self) -> i32 {
+        if let Some(emotion) = self.active_custom_emotion() {
+            return emotion.damage_multiplier;
+        }
+
+        match self.emotion {
+            Emotion::FullSynchro | Emotion::Angry => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns false if the emotion is unknown to the player
+    pub fn set_emotion(
+        simulation: &mut BattleSimulation,
+        entity_id: EntityID,
+        emotion_id: usize,
+    ) -> bool {
+        use num_traits::FromPrimitive;
+
+        let Ok(player) = (simulation.entities).query_one_mut::<&mut Player>(entity_id.into())
+        else {
+            return false;
+        };
+
+        let (emotion, custom_emotion_index) =
+            match emotion_id.checked_sub(Self::CUSTOM_EMOTION_START) {
+                Some(index) => match player.custom_emotions.get(index) {
+                    Some(custom_emotion) => (custom_emotion.base_emotion, Some(index)),
+                    None => return false,
+                },
+                None => match Emotion::from_usize(emotion_id) {
+                    Some(emotion) => (emotion, None),
+                    None => return false,
+                },
+            };
+
+        if player.emotion_id() == emotion_id {
+            return true;
+        }
+
+        if let Some(emotion) = player.active_custom_emotion() {
+            if let Some(callback) = emotion.deactivate_callback.clone() {
+                simulation.pending_callbacks.push(callback);
+            }
+        }
+
+        player.emotion = emotion;
+        player.custom_emotion_index = custom_emotion_index;
+
+        if let Some(emotion) = player.active_custom_emotion() {
+            if let Some(callback) = emotion.activate_callback.clone() {
+                simulation.pending_callbacks.push(callback);
+            }
+        }
+
+        if player.local {
+            simulation.statistics.emotion = emotion;
+        }
+
+        true
+    }
+
     pub fn namespace(&self) -> PackageNamespace {
         if self.local {
             PackageNamespace::Local
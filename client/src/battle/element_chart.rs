@@ -0,0 +1,189 @@
+use crate::bindable::{Element, HitProperties, TileState};
+
+const DEFAULT_SUPER_EFFECTIVE_MULTIPLIER: f32 = 2.0;
+
+lazy_static::lazy_static! {
+    static ref BUILT_IN_CHART: ElementChart = ElementChart::default();
+}
+
+#[derive(Clone)]
+pub struct ElementDefinition {
+    pub name: String,
+    pub element: Element,
+    /// Uses the shared element sheet when unset
+    pub icon_texture_path: Option<String>,
+    pub super_effective_multiplier: f32,
+}
+
+impl ElementDefinition {
+    pub fn new(name: &str, element: Element) -> Self {
+        Self {
+            name: name.to_string(),
+            element,
+            icon_texture_path: None,
+            super_effective_multiplier: DEFAULT_SUPER_EFFECTIVE_MULTIPLIER,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ElementWeakness {
+    pub element: Element,
+    pub weak_to: Element,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TileWash {
+    pub state: TileState,
+    pub element: Element,
+}
+
+#[derive(Clone)]
+pub struct ElementChart {
+    elements: Vec<ElementDefinition>,
+    weaknesses: Vec<ElementWeakness>,
+    washes: Vec<TileWash>,
+}
+
+impl Default for ElementChart {
+    fn default() -> Self {
+        let elements = Element::BUILT_IN
+            .into_iter()
+            .map(|element| ElementDefinition::new(&element.to_string(), element))
+            .collect();
+
+        let weaknesses = [
+            (Element::Aqua, Element::Elec),
+            (Element::Fire, Element::Aqua),
+            (Element::Wood, Element::Fire),
+            (Element::Elec, Element::Wood),
+            (Element::Sword, Element::Break),
+            (Element::Wind, Element::Sword),
+            (Element::Cursor, Element::Wind),
+            (Element::Break, Element::Cursor),
+        ]
+        .into_iter()
+        .map(|(element, weak_to)| ElementWeakness { element, weak_to })
+        .collect();
+
+        let washes = [
+            (TileState::Sand, Element::Wind),
+            (TileState::Grass, Element::Fire),
+            (TileState::Volcano, Element::Aqua),
+        ]
+        .into_iter()
+        .map(|(state, element)| TileWash { state, element })
+        .collect();
+
+        Self {
+            elements,
+            weaknesses,
+            washes,
+        }
+    }
+}
+
+impl ElementChart {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chart without package registered elements, used outside of battles
+    pub fn built_in() -> &'static Self {
+        &BUILT_IN_CHART
+    }
+
+    pub fn is_registered(&self, element: Element) -> bool {
+        (element.0 as usize) < self.elements.len()
+    }
+
+    /// Returns None if every element id is already in use
+    pub fn register(&mut self, mut definition: ElementDefinition) -> Option<Element> {
+        if let Some(existing) = self.definition_by_name(&definition.name) {
+            return Some(existing.element);
+        }
+
+        let id = u8::try_from(self.elements.len()).ok()?;
+
+        definition.element = Element(id);
+        self.elements.push(definition);
+
+        Some(Element(id))
+    }
+
+    pub fn definition(&self, element: Element) -> Option<&ElementDefinition> {
+        self.elements.get(element.0 as usize)
+    }
+
+    pub fn definition_mut(&mut self, element: Element) -> Option<&mut ElementDefinition> {
+        self.elements.get_mut(element.0 as usize)
+    }
+
+    pub fn definition_by_name(&self, name: &str) -> Option<&ElementDefinition> {
+        self.elements
+            .iter()
+            .find(|definition| definition.name == name)
+    }
+
+    pub fn add_weakness(&mut self, element: Element, weak_to: Element) {
+        let weakness = ElementWeakness { element, weak_to };
+
+        if !self.weaknesses.contains(&weakness) {
+            self.weaknesses.push(weakness);
+        }
+    }
+
+    pub fn remove_weakness(&mut self, element: Element, weak_to: Element) {
+        let weakness = ElementWeakness { element, weak_to };
+
+        self.weaknesses.retain(|stored| *stored != weakness);
+    }
+
+    pub fn clear_weaknesses(&mut self) {
+        self.weaknesses.clear();
+    }
+
+    pub fn is_weak_to(&self, element: Element, other: Element) -> bool {
+        let weakness = ElementWeakness {
+            element,
+            weak_to: other,
+        };
+
+        self.weaknesses.contains(&weakness)
+    }
+
+    /// Returns the highest multiplier from the elements the defending element is weak to
+    pub fn super_effective_multiplier(
+        &self,
+        element: Element,
+        hit_props: &HitProperties,
+    ) -> Option<f32> {
+        [hit_props.element, hit_props.secondary_element]
+            .into_iter()
+            .filter(|attacking_element| self.is_weak_to(element, *attacking_element))
+            .map(|attacking_element| {
+                self.definition(attacking_element)
+                    .map(|definition| definition.super_effective_multiplier)
+                    .unwrap_or(DEFAULT_SUPER_EFFECTIVE_MULTIPLIER)
+            })
+            .reduce(f32::max)
+    }
+
+    pub fn add_wash(&mut self, state: TileState, element: Element) {
+        let wash = TileWash { state, element };
+
+        if !self.washes.contains(&wash) {
+            self.washes.push(wash);
+        }
+    }
+
+    pub fn remove_wash(&mut self, state: TileState, element: Element) {
+        let wash = TileWash { state, element };
+
+        self.washes.retain(|stored| *stored != wash);
+    }
+
+    pub fn washes(&self, state: TileState, element: Element) -> bool {
+        self.washes.contains(&TileWash { state, element })
+    }
+}
@@ -1 +1,67 @@
+use super::Player;
+use crate::packages::PlayerPackage;
+use crate::render::*;
+use crate::resources::*;
+use framework::prelude::*;
 
+const EMOTION_HEIGHT: f32 = 16.0;
+
+#[derive(Clone)]
+pub struct EmotionWindow {
+    mug_sprite: Sprite,
+    emotion_sprite: Sprite,
+}
+
+impl EmotionWindow {
+    pub fn new(game_io: &GameIO<Globals>, player_package: &PlayerPackage) -> Self {
+        let assets = &game_io.globals().assets;
+
+        // only the first frame of the mugshot is used
+        let mut mug_sprite = assets.new_sprite(game_io, &player_package.mugshot_texture_path);
+        let mut mug_animator = Animator::load_new(assets, &player_package.mugshot_animation_path);
+        mug_animator.set_state("IDLE");
+        mug_animator.apply(&mut mug_sprite);
+
+        let emotions_texture_path = if player_package.emotions_texture_path.is_empty() {
+            ResourcePaths::BATTLE_EMOTIONS
+        } else {
+            &player_package.emotions_texture_path
+        };
+
+        let emotion_sprite = assets.new_sprite(game_io, emotions_texture_path);
+
+        Self {
+            mug_sprite,
+            emotion_sprite,
+        }
+    }
+
+    pub fn draw(&self, sprite_queue: &mut SpriteColorQueue, player: &Player, position: Vec2) {
+        let custom_emotion = player.active_custom_emotion();
+
+        // draw mug
+        let mut mug_sprite = match custom_emotion.and_then(|emotion| emotion.mug_texture.clone()) {
+            Some(texture) => Sprite::new(texture, self.mug_sprite.sampler().clone()),
+            None => self.mug_sprite.clone(),
+        };
+
+        mug_sprite.set_position(position);
+        sprite_queue.draw_sprite(&mug_sprite);
+
+        // draw emotion below the mug
+        let mut emotion_sprite = match custom_emotion.and_then(|emotion| emotion.texture.clone()) {
+            Some(texture) => Sprite::new(texture, self.emotion_sprite.sampler().clone()),
+            None => {
+                let mut sprite = self.emotion_sprite.clone();
+                let width = sprite.size().x;
+                let y = player.emotion as u8 as f32 * EMOTION_HEIGHT;
+
+                sprite.set_frame(Rect::new(0.0, y, width, EMOTION_HEIGHT));
+                sprite
+            }
+        };
+
+        emotion_sprite.set_position(position + Vec2::new(0.0, mug_sprite.size().y));
+        sprite_queue.draw_sprite(&emotion_sprite);
+    }
+}
@@ -4,11 +4,31 @@ use crate::render::*;
 use crate::resources::*;
 use framework::prelude::*;
 
+const DEFAULT_TILE_SIZE: Vec2 = Vec2::new(40.0, 25.0);
+
+const CUSTOM_TEAM_TINTS: [Color; 6] = [
+    Color::new(0.3, 0.25, 0.0, 1.0),
+    Color::new(0.0, 0.3, 0.05, 1.0),
+    Color::new(0.2, 0.0, 0.3, 1.0),
+    Color::new(0.3, 0.12, 0.0, 1.0),
+    Color::new(0.0, 0.25, 0.3, 1.0),
+    Color::new(0.3, 0.0, 0.15, 1.0),
+];
+
+#[derive(Clone)]
+pub struct TeamRegion {
+    pub team: Team,
+    pub position: (i32, i32),
+    pub size: (i32, i32),
+    pub direction: Option<Direction>,
+}
+
 #[derive(Clone)]
 pub struct Field {
     rows: usize,
     cols: usize,
     tiles: Vec<Tile>,
+    team_regions: Vec<TeamRegion>,
     tile_size: Vec2,
     red_tile_sprite: Sprite,
     blue_tile_sprite: Sprite,
@@ -19,17 +39,6 @@ pub struct Field {
 
 impl Field {
     pub fn new(game_io: &GameIO<Globals>, cols: usize, rows: usize) -> Self {
-        let mut tiles = Vec::with_capacity(cols * rows);
-
-        for row in 0..rows as i32 {
-            for col in 0..cols as i32 {
-                let position = (col, row);
-                let immutable_team = col <= 1 || col + 2 >= cols as i32;
-
-                tiles.push(Tile::new(position, immutable_team));
-            }
-        }
-
         let globals = game_io.globals();
         let assets = &globals.assets;
 
@@ -41,19 +50,141 @@ impl Field {
         blue_tile_sprite.set_color(Color::BLACK);
         other_tile_sprite.set_color(Color::BLACK);
 
+        let tile_animator = Animator::load_new(assets, ResourcePaths::BATTLE_TILE_ANIMATION);
+
         Self {
             cols,
             rows,
-            tiles,
-            tile_size: Vec2::new(40.0, 25.0), // todo: read from .animation?
+            tiles: Self::create_tiles(cols, rows),
+            team_regions: Vec::new(),
+            tile_size: Self::read_tile_size(&tile_animator),
             red_tile_sprite,
             blue_tile_sprite,
             other_tile_sprite,
-            tile_animator: Animator::load_new(assets, ResourcePaths::BATTLE_TILE_ANIMATION),
+            tile_animator,
             time: 0,
         }
     }
 
+    fn create_tiles(cols: usize, rows: usize) -> Vec<Tile> {
+        let mut tiles = Vec::with_capacity(cols * rows);
+
+        for row in 0..rows as i32 {
+            for col in 0..cols as i32 {
+                let position = (col, row);
+                let immutable_team = Self::is_immutable_team_col(col, cols);
+
+                tiles.push(Tile::new(position, immutable_team));
+            }
+        }
+
+        tiles
+    }
+
+    fn is_immutable_team_col(col: i32, cols: usize) -> bool {
+        col <= 1 || col + 2 >= cols as i32
+    }
+
+    /// Uses the SPACING point on the first frame of `row_1_normal`, falls back to the frame size
+    fn read_tile_size(animator: &Animator) -> Vec2 {
+        let frame = animator
+            .frame_list("row_1_normal")
+            .and_then(|frame_list| frame_list.frame(0));
+
+        match frame {
+            Some(frame) => frame.point("SPACING").unwrap_or_else(|| frame.size()),
+            None => DEFAULT_TILE_SIZE,
+        }
+    }
+
+    /// Maps a column to its position after a resize, the right half moves with the right edge.
+    /// Shrinking drops columns from the middle of the field, these resolve to None
+    pub fn resized_col(col: i32, old_cols: usize, cols: usize) -> Option<i32> {
+        let (left_cols, right_start) = Self::kept_cols(old_cols, cols);
+
+        if col < left_cols {
+            Some(col)
+        } else if col >= right_start {
+            Some(col + cols as i32 - old_cols as i32)
+        } else {
+            None
+        }
+    }
+
+    /// Like `resized_col`, but dropped columns resolve to the closest remaining column
+    pub fn nearest_resized_col(col: i32, old_cols: usize, cols: usize) -> i32 {
+        if let Some(col) = Self::resized_col(col, old_cols, cols) {
+            return col;
+        }
+
+        let (left_cols, right_start) = Self::kept_cols(old_cols, cols);
+
+        if col - left_cols < right_start - col {
+            left_cols - 1
+        } else {
+            left_cols
+        }
+    }
+
+    /// Returns the count of columns kept on the left, and the first old column kept on the right
+    fn kept_cols(old_cols: usize, cols: usize) -> (i32, i32) {
+        let kept_cols = old_cols.min(cols) as i32;
+        let left_cols = kept_cols / 2;
+        let right_start = old_cols as i32 - (kept_cols - left_cols);
+
+        (left_cols, right_start)
+    }
+
+    /// Dimensions include the hidden edge, tiles keep their state and reservations
+    /// unless they fall outside of the new size or are dropped from the middle when shrinking
+    pub fn resize(&mut self, cols: usize, rows: usize) {
+        let old_cols = self.cols;
+        let old_rows = self.rows;
+        let old_tiles = std::mem::replace(&mut self.tiles, Self::create_tiles(cols, rows));
+
+        self.cols = cols;
+        self.rows = rows;
+
+        for region in &mut self.team_regions {
+            let (x, y) = region.position;
+            let (width, height) = region.size;
+
+            // regions stretch or shrink with the columns between their edges
+            let start = Self::nearest_resized_col(x, old_cols, cols).clamp(0, cols as i32);
+            let end = Self::nearest_resized_col(x + width - 1, old_cols, cols) + 1;
+            let end = end.clamp(start, cols as i32);
+            let y = y.clamp(0, rows as i32);
+
+            region.position = (start, y);
+            region.size = (end - start, height.clamp(0, rows as i32 - y));
+        }
+
+        for (index, mut tile) in old_tiles.into_iter().enumerate() {
+            let old_col = (index % old_cols) as i32;
+            let row = (index / old_cols) as i32;
+
+            // edges are recreated to match the new size
+            let old_edge = old_col == 0
+                || row == 0
+                || old_col + 1 == old_cols as i32
+                || row + 1 == old_rows as i32;
+
+            let Some(col) = Self::resized_col(old_col, old_cols, cols) else {
+                // dropped from the middle
+                continue;
+            };
+
+            let position = (col, row);
+
+            if old_edge || !self.in_bounds(position) || self.is_edge(position) {
+                continue;
+            }
+
+            tile.relocate(position, Self::is_immutable_team_col(position.0, cols));
+            self.tiles[row as usize * cols + position.0 as usize] = tile;
+        }
+    }
+
     pub fn set_sprites(
         &mut self,
         game_io: &GameIO<Globals>,
@@ -61,7 +192,7 @@ impl Field {
         blue_texture_path: &str,
         other_texture_path: &str,
         animation_path: &str,
-        spacing: Vec2,
+        spacing: Option<Vec2>,
     ) {
         let globals = game_io.globals();
         let assets = &globals.assets;
@@ -79,7 +210,7 @@ impl Field {
         self.other_tile_sprite.set_color(Color::BLACK);
 
         self.tile_animator = Animator::load_new(assets, animation_path);
-        self.tile_size = spacing;
+        self.tile_size = spacing.unwrap_or_else(|| Self::read_tile_size(&self.tile_animator));
     }
 
     pub fn cols(&self) -> usize {
@@ -150,7 +281,62 @@ impl Field {
         }
     }
 
+    /// Permanently assigns tiles to a team, tiles outside of every region are left to
+    /// `initialize_uninitialized`
+    pub fn set_team_region(&mut self, region: TeamRegion) {
+        let (x, y) = region.position;
+        let (width, height) = region.size;
+
+        for row in y..y + height {
+            for col in x..x + width {
+                let Some(tile) = self.tile_at_mut((col, row)) else {
+                    continue;
+                };
+
+                tile.set_original_team(region.team);
+
+                if let Some(direction) = region.direction {
+                    tile.set_direction(direction);
+                }
+            }
+        }
+
+        self.team_regions.push(region);
+    }
+
+    /// Finds a visible tile for the team closest to the center of the team's tiles
+    pub fn find_team_spawn(&self, team: Team, taken: &[(i32, i32)]) -> Option<(i32, i32)> {
+        let team_tiles: Vec<_> = self
+            .tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| tile.original_team() == team)
+            .map(|(i, _)| ((i % self.cols) as i32, (i / self.cols) as i32))
+            .filter(|&position| !self.is_edge(position))
+            .collect();
+
+        if team_tiles.is_empty() {
+            return None;
+        }
+
+        let total = team_tiles
+            .iter()
+            .fold((0, 0), |acc, (col, row)| (acc.0 + col, acc.1 + row));
+        let center = (
+            total.0 / team_tiles.len() as i32,
+            total.1 / team_tiles.len() as i32,
+        );
+
+        team_tiles
+            .into_iter()
+            .filter(|position| !taken.contains(position))
+            .min_by_key(|(col, row)| (col - center.0).abs() + (row - center.1).abs())
+    }
+
     pub fn initialize_uninitialized(&mut self) {
+        // regions from the battle package replace the default split between Red and Blue
+        let has_team_regions = !self.team_regions.is_empty();
+
         for row in 0..self.rows {
             for col in 0..self.cols {
                 let tile = &mut self.tiles[row * self.cols + col];
@@ -166,6 +352,8 @@ impl Field {
                     direction = Direction::Left;
                 }
 
+                let team = if has_team_regions { Team::Other } else { team };
+
                 if tile.team() == Team::Unset {
                     tile.set_team(team);
                 }
@@ -181,6 +369,13 @@ impl Field {
         }
     }
 
+    fn team_tint(team: Team) -> Color {
+        match team {
+            Team::Custom(number) => CUSTOM_TEAM_TINTS[number as usize % CUSTOM_TEAM_TINTS.len()],
+            _ => Color::BLACK,
+        }
+    }
+
     pub fn reset_highlight(&mut self) {
         for tile in &mut self.tiles {
             tile.reset_highlight();
@@ -264,6 +459,7 @@ impl Field {
         game_io: &GameIO<Globals>,
         sprite_queue: &mut SpriteColorQueue,
         flipped: bool,
+        local_team: Team,
     ) {
         sprite_queue.set_color_mode(SpriteColorMode::Add);
 
@@ -273,17 +469,6 @@ impl Field {
         let x_start = self.cols as f32 * 0.5 * -self.tile_size.x + sprite_origin.x;
         let y_start = -self.tile_size.y;
 
-        let red_sprite;
-        let blue_sprite;
-
-        if flipped {
-            red_sprite = &mut self.blue_tile_sprite;
-            blue_sprite = &mut self.red_tile_sprite;
-        } else {
-            red_sprite = &mut self.red_tile_sprite;
-            blue_sprite = &mut self.blue_tile_sprite;
-        }
-
         let flip_multiplier = if flipped { -1.0 } else { 1.0 };
 
         let mut highlight_positions = Vec::new();
@@ -303,10 +488,16 @@ impl Field {
                 self.tile_animator.set_loop_mode(AnimatorLoopMode::Loop);
                 self.tile_animator.sync_time(self.time);
 
+                // the local team always sees their own tiles as red
                 let sprite = match tile.visible_team() {
-                    Team::Red => &mut *red_sprite,
-                    Team::Blue => &mut *blue_sprite,
-                    _ => &mut self.other_tile_sprite,
+                    team if team == local_team => &mut self.red_tile_sprite,
+                    Team::Red | Team::Blue => &mut self.blue_tile_sprite,
+                    Team::Unset | Team::Other => &mut self.other_tile_sprite,
+                    team => {
+                        // additional teams tint the neutral tiles
+                        self.other_tile_sprite.set_color(Self::team_tint(team));
+                        &mut self.other_tile_sprite
+                    }
                 };
 
                 // set position
@@ -319,6 +510,7 @@ impl Field {
                 self.tile_animator.apply(sprite);
                 sprite.set_origin(sprite_origin);
                 sprite_queue.draw_sprite(sprite);
+                sprite.set_color(Color::BLACK);
 
                 // resolve highlight
                 if tile.should_highlight() {
@@ -0,0 +1,65 @@
+use crate::render::FrameTime;
+use crate::resources::Input;
+
+/// Pre-recorded input for a single player, one entry per frame
+#[derive(Default, Clone)]
+pub struct InputScript {
+    frames: Vec<Vec<Input>>,
+}
+
+impl InputScript {
+    /// Each line is `<frames> [inputs...]`, holding the listed inputs for the given amount of frames.
+    /// Empty lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        use strum::IntoEnumIterator;
+
+        let mut frames = Vec::new();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let line_number = line_index + 1;
+
+            let duration = words
+                .next()
+                .and_then(|word| word.parse::<usize>().ok())
+                .ok_or_else(|| format!("line {line_number}: expected a frame count"))?;
+
+            let mut pressed = Vec::new();
+
+            for word in words {
+                let input = Input::iter()
+                    .find(|input| <&str>::from(*input).eq_ignore_ascii_case(word))
+                    .ok_or_else(|| format!("line {line_number}: unknown input {word:?}"))?;
+
+                pressed.push(input);
+            }
+
+            frames.extend(std::iter::repeat(pressed).take(duration));
+        }
+
+        Ok(Self { frames })
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Nothing is pressed after the script ends
+    pub fn inputs_at(&self, time: FrameTime) -> Vec<Input> {
+        usize::try_from(time)
+            .ok()
+            .and_then(|index| self.frames.get(index))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
@@ -2,6 +2,7 @@ mod attack_box;
 mod battle_animator;
 mod battle_callback;
 mod battle_props;
+mod battle_scheduler;
 mod battle_script_context;
 mod battle_simulation;
 mod card_action;
@@ -9,15 +10,19 @@ mod component;
 mod defense_rule;
 mod delete_animations;
 mod ecs_components;
+mod element_chart;
 mod emotion_window;
 mod field;
+mod input_script;
 mod intangibility;
+mod player_emotion;
 mod player_form;
 mod player_input;
 mod rollback_vm;
 mod shared_battle_assets;
 mod states;
 mod status_director;
+mod status_registry;
 mod tile;
 mod time_freeze_tracker;
 mod turn_gauge;
@@ -26,6 +31,7 @@ pub use attack_box::*;
 pub use battle_animator::*;
 pub use battle_callback::*;
 pub use battle_props::*;
+pub use battle_scheduler::*;
 pub use battle_script_context::*;
 pub use battle_simulation::*;
 pub use card_action::*;
@@ -33,15 +39,19 @@ pub use component::*;
 pub use defense_rule::*;
 pub use delete_animations::*;
 pub use ecs_components::*;
+pub use element_chart::*;
 pub use emotion_window::*;
 pub use field::*;
+pub use input_script::*;
 pub use intangibility::*;
+pub use player_emotion::*;
 pub use player_form::*;
 pub use player_input::*;
 pub use rollback_vm::*;
 pub use shared_battle_assets::*;
 pub use states::*;
 pub use status_director::*;
+pub use status_registry::*;
 pub use tile::*;
 pub use time_freeze_tracker::*;
 pub use turn_gauge::*;
@@ -0,0 +1,29 @@
+use super::BattleCallback;
+use crate::bindable::Emotion;
+use framework::prelude::Texture;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CustomEmotion {
+    pub name: String,
+    pub base_emotion: Emotion, // reported to the server
+    pub damage_multiplier: i32,
+    pub texture: Option<Arc<Texture>>,
+    pub mug_texture: Option<Arc<Texture>>,
+    pub activate_callback: Option<BattleCallback>,
+    pub deactivate_callback: Option<BattleCallback>,
+}
+
+impl CustomEmotion {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            base_emotion: Emotion::Normal,
+            damage_multiplier: 1,
+            texture: None,
+            mug_texture: None,
+            activate_callback: None,
+            deactivate_callback: None,
+        }
+    }
+}
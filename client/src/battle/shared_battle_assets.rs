@@ -1,11 +1,13 @@
 use crate::render::Animator;
 use crate::resources::{AssetManager, Globals, ResourcePaths};
 use framework::prelude::{GameIO, Texture};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct SharedBattleAssets {
     pub statuses_texture: Arc<Texture>,
     pub statuses_animator: Animator,
+    pub custom_status_animators: HashMap<String, Animator>,
 }
 
 impl SharedBattleAssets {
@@ -15,6 +17,13 @@ impl SharedBattleAssets {
         Self {
             statuses_texture: assets.texture(game_io, ResourcePaths::BATTLE_STATUSES),
             statuses_animator: Animator::load_new(assets, ResourcePaths::BATTLE_STATUSES_ANIMATION),
+            custom_status_animators: HashMap::new(),
         }
     }
+
+    pub fn status_animator(&mut self, game_io: &GameIO<Globals>, path: &str) -> &mut Animator {
+        self.custom_status_animators
+            .entry(path.to_string())
+            .or_insert_with(|| Animator::load_new(&game_io.globals().assets, path))
+    }
 }
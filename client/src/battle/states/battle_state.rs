@@ -11,11 +11,14 @@ use rand::Rng;
 use std::cell::RefCell;
 
 const GRACE_TIME: FrameTime = 5;
+const REAL_TIME_HAND_LIMIT: usize = 5;
 
 #[derive(Clone)]
 pub struct BattleState {
     complete: bool,
     message: Option<(&'static str, FrameTime)>,
+    acted_players: Vec<usize>,
+    resolving_turn: bool,
 }
 
 impl State for BattleState {
@@ -50,6 +53,15 @@ impl State for BattleState {
         // new: process player input
         self.process_input(game_io, simulation, vms);
 
+        if self.update_turn_based_wait(simulation) {
+            // time is stopped until every player acts,
+            // status timers keep running to release players locked out of input
+            self.update_living(game_io, simulation, vms, false);
+            BattleScheduler::update(game_io, simulation, vms);
+            simulation.call_pending_callbacks(game_io, vms);
+            return;
+        }
+
         // update time freeze first as it affects the rest of the updates
         self.update_time_freeze(game_io, simulation, vms);
 
@@ -73,7 +85,7 @@ impl State for BattleState {
         self.mark_deleted(game_io, simulation, vms);
 
         // new: update living, processes statuses
-        self.update_living(game_io, simulation, vms);
+        self.update_living(game_io, simulation, vms, true);
 
         // update artifacts
         self.update_artifacts(game_io, simulation, vms);
@@ -92,6 +104,10 @@ impl State for BattleState {
 
         simulation.call_pending_callbacks(game_io, vms);
 
+        // scheduled callbacks
+        BattleScheduler::update(game_io, simulation, vms);
+        simulation.call_pending_callbacks(game_io, vms);
+
         if self.message.is_none() {
             // only update the time statistic if the battle is still going for the local player
             simulation.statistics.time += 1;
@@ -165,6 +181,8 @@ impl BattleState {
         Self {
             complete: false,
             message: None,
+            acted_players: Vec::new(),
+            resolving_turn: false,
         }
     }
 
@@ -188,20 +206,114 @@ impl BattleState {
             simulation.play_sound(game_io, &globals.turn_gauge_sfx);
         }
 
-        self.complete = simulation
+        match simulation.turn_gauge.mode() {
+            TurnMode::Classic | TurnMode::TurnBased => {
+                self.complete = simulation
+                    .entities
+                    .query_mut::<(&Entity, &mut Player)>()
+                    .into_iter()
+                    .any(|(_, (entity, player))| {
+                        if entity.deleted {
+                            return false;
+                        }
+
+                        let input = &simulation.inputs[player.index];
+                        input.was_just_pressed(Input::EndTurn)
+                    });
+            }
+            TurnMode::AutoOpen => {
+                self.complete = true;
+            }
+            TurnMode::RealTime => {
+                Self::draw_real_time_cards(game_io, simulation);
+                simulation.turn_gauge.set_time(0);
+            }
+        }
+    }
+
+    fn draw_real_time_cards(game_io: &GameIO<Globals>, simulation: &mut BattleSimulation) {
+        let card_packages = &game_io.globals().card_packages;
+
+        for (_, (entity, player, character)) in
+            (simulation.entities).query_mut::<(&Entity, &mut Player, &mut Character)>()
+        {
+            if entity.deleted
+                || player.cards.is_empty()
+                || character.cards.len() >= REAL_TIME_HAND_LIMIT
+            {
+                continue;
+            }
+
+            let card = player.cards.remove(0);
+            let namespace = player.namespace();
+
+            if let Some(package) = card_packages.package_or_fallback(namespace, &card.package_id) {
+                // cards are popped off the end, drawn cards are used after the current hand
+                character.cards.insert(0, package.card_properties.clone());
+            }
+        }
+    }
+
+    /// Returns true while time is stopped, waiting for every player to act
+    fn update_turn_based_wait(&mut self, simulation: &mut BattleSimulation) -> bool {
+        if simulation.turn_gauge.mode() != TurnMode::TurnBased
+            || simulation.time_freeze_tracker.time_is_frozen()
+            || self.message.is_some()
+        {
+            return false;
+        }
+
+        let card_actions = &simulation.card_actions;
+        let status_registry = &simulation.status_registry;
+        let mut all_acted = true;
+        let mut all_idle = true;
+
+        for (_, (entity, player, living)) in simulation
             .entities
-            .query_mut::<(&Entity, &mut Player)>()
-            .into_iter()
-            .any(|(_, (entity, player))| {
-                if entity.deleted {
-                    return false;
-                }
+            .query_mut::<(&Entity, &Player, &Living)>()
+        {
+            if entity.deleted {
+                continue;
+            }
 
-                let input = &simulation.inputs[player.index];
+            if living.status_director.input_locked_out(status_registry)
+                && !self.acted_players.contains(&player.index)
+            {
+                // players who can't act shouldn't hold up the turn
+                self.acted_players.push(player.index);
+            }
 
-                // todo: create a new input for ending the turn?
-                input.was_just_pressed(Input::ShoulderL) || input.was_just_pressed(Input::ShoulderR)
-            });
+            let acting = player.card_use_requested
+                || entity.move_action.is_some()
+                || entity.card_action_index.is_some()
+                || card_actions
+                    .iter()
+                    .any(|(_, action)| action.entity == entity.id);
+
+            if acting && !self.acted_players.contains(&player.index) {
+                self.acted_players.push(player.index);
+            }
+
+            all_acted &= self.acted_players.contains(&player.index);
+            all_idle &= !acting;
+        }
+
+        if self.resolving_turn {
+            // let actions play out, then wait for the next round of input
+            if all_idle {
+                self.resolving_turn = false;
+                self.acted_players.clear();
+            }
+
+            return false;
+        }
+
+        if all_acted {
+            self.resolving_turn = true;
+            return false;
+        }
+
+        true
     }
 
     fn detect_success_or_failure(&mut self, simulation: &mut BattleSimulation) {
@@ -241,13 +353,16 @@ impl BattleState {
         // todo: score screen
         const SUCCESS_MESSAGE: &str = "<_SUCCESS_>";
 
-        let enemies_alive = simulation
-            .entities
-            .query_mut::<(&Entity, &Character)>()
-            .into_iter()
-            .any(|(_, (entity, _))| entity.team != local_team);
+        let mut remaining_teams = vec![local_team];
 
-        if !enemies_alive {
+        for (_, (entity, _)) in simulation.entities.query_mut::<(&Entity, &Character)>() {
+            if !remaining_teams.contains(&entity.team) {
+                remaining_teams.push(entity.team);
+            }
+        }
+
+        // success once every other team has been cleared
+        if remaining_teams.len() == 1 {
             self.message = Some((SUCCESS_MESSAGE, simulation.time));
         }
     }
@@ -286,10 +401,7 @@ impl BattleState {
         }
 
         // update fade color
-        const FADE_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.3);
-
-        let fade_alpha = time_freeze_tracker.fade_alpha();
-        let fade_color = FADE_COLOR.multiply_alpha(fade_alpha);
+        let fade_color = Color::BLACK.multiply_alpha(time_freeze_tracker.dim_alpha());
         simulation.fade_sprite.set_color(fade_color);
 
         // detect freeze start
@@ -622,8 +734,9 @@ impl BattleState {
                 .field
                 .tile_at_mut((attack_box.x, attack_box.y))
                 .unwrap();
-            tile.attempt_wash(attack_box.props.element);
-            tile.attempt_wash(attack_box.props.secondary_element);
+            let element_chart = &simulation.element_chart;
+            tile.attempt_wash(element_chart, attack_box.props.element);
+            tile.attempt_wash(element_chart, attack_box.props.secondary_element);
 
             if attack_box.highlight {
                 tile.set_highlight(TileHighlight::Solid);
@@ -856,6 +969,7 @@ impl BattleState {
                 break;
             }
 
+            let status_registry = &simulation.status_registry;
             let entities = &mut simulation.entities;
             let (entity, player, living, character) = entities
                 .query_one_mut::<(&mut Entity, &mut Player, &Living, &mut Character)>(id)
@@ -869,7 +983,7 @@ impl BattleState {
                 .get_mut(player.charge_sprite_index)
                 .unwrap();
 
-            if entity.deleted || living.status_director.input_locked_out() {
+            if entity.deleted || living.status_director.input_locked_out(status_registry) {
                 player.charging_time = 0;
             }
 
@@ -877,7 +991,7 @@ impl BattleState {
                 charge_sprite_node.set_visible(false);
             }
 
-            if entity.deleted || living.status_director.input_locked_out() {
+            if entity.deleted || living.status_director.input_locked_out(status_registry) {
                 continue;
             }
 
@@ -978,7 +1092,19 @@ impl BattleState {
             .unwrap();
 
         let namespace = character.namespace;
-        let card_props = character.cards.pop().unwrap();
+        let mut card_props = character.cards.pop().unwrap();
+
+        // emotions can boost the next card
+        if let Ok(player) = (simulation.entities).query_one_mut::<&Player>(entity_id.into()) {
+            let multiplier = player.emotion_damage_multiplier();
+
+            if multiplier != 1 && card_props.can_boost && card_props.damage > 0 {
+                card_props.damage *= multiplier;
+
+                Player::set_emotion(simulation, entity_id, Emotion::Normal as usize);
+                simulation.call_pending_callbacks(game_io, vms);
+            }
+        }
 
         let callback = BattleCallback::new(move |game_io, simulation, vms, _: ()| {
             let package_id = &card_props.package_id;
@@ -1068,7 +1194,11 @@ impl BattleState {
             entities.query_mut::<(&mut Entity, &Living, &mut Player)>()
         {
             // can't move if there's a blocking card action or immoble
-            if entity.card_action_index.is_some() || living.status_director.is_immobile() {
+            if entity.card_action_index.is_some()
+                || living
+                    .status_director
+                    .is_immobile(&simulation.status_registry)
+            {
                 continue;
             }
 
@@ -1085,7 +1215,7 @@ impl BattleState {
             let mut x_offset =
                 input.is_down(Input::Right) as i32 - input.is_down(Input::Left) as i32;
 
-            if entity.team == Team::Blue {
+            if player.perspective_flipped {
                 // flipped perspective
                 x_offset = -x_offset;
             }
@@ -1180,13 +1310,16 @@ impl BattleState {
         }
     }
 
+    /// Skips entity and component update callbacks when `update_entities` is false
     fn update_living(
         &mut self,
         game_io: &GameIO<Globals>,
         simulation: &mut BattleSimulation,
         vms: &[RollbackVM],
+        update_entities: bool,
     ) {
         let mut callbacks = Vec::new();
+        let status_registry = &simulation.status_registry;
 
         for (id, (entity, living)) in simulation
             .entities
@@ -1196,7 +1329,7 @@ impl BattleState {
                 continue;
             }
 
-            if !living.status_director.is_inactionable() {
+            if update_entities && !living.status_director.is_inactionable(status_registry) {
                 callbacks.push(entity.update_callback.clone());
 
                 for index in entity.local_components.iter().cloned() {
@@ -1215,7 +1348,9 @@ impl BattleState {
 
             // process statuses as long as the entity isn't being dragged
             if !living.status_director.is_dragged() {
-                living.status_director.update(&simulation.inputs);
+                living
+                    .status_director
+                    .update(status_registry, &simulation.inputs);
 
                 // status callbacks
                 for hit_flag in living.status_director.take_new_statuses() {
@@ -1244,6 +1379,17 @@ impl BattleState {
                         callbacks.extend(status_callbacks.iter().cloned());
                     }
                 }
+
+                // per frame callbacks for statuses registered by packages
+                for hit_flag in living.status_director.active_statuses() {
+                    let status = status_registry.status(hit_flag);
+
+                    if let Some(callback) = status.and_then(|s| s.update_callback.clone()) {
+                        callbacks.push(BattleCallback::new(move |game_io, simulation, vms, _| {
+                            callback.call(game_io, simulation, vms, id.into());
+                        }));
+                    }
+                }
             }
 
             // update intangibility
@@ -1283,7 +1429,10 @@ impl BattleState {
                 entity.spawned && !entity.deleted && entity.time_frozen_count == 0;
 
             if let Some(living) = simulation.entities.query_one::<&Living>(id).unwrap().get() {
-                if living.status_director.is_immobile() {
+                if living
+                    .status_director
+                    .is_immobile(&simulation.status_registry)
+                {
                     update_progress = false;
                 }
             }
@@ -1709,7 +1858,12 @@ impl BattleState {
                 root_node.set_color_mode(SpriteColorMode::Add);
             }
 
-            status_director.update_status_sprites(game_io, shared_assets, entity);
+            status_director.update_status_sprites(
+                game_io,
+                shared_assets,
+                &simulation.status_registry,
+                entity,
+            );
         }
     }
 }
@@ -2,16 +2,19 @@ use super::State;
 use crate::battle::*;
 use crate::bindable::*;
 use crate::ease::inverse_lerp;
+use crate::packages::PackageNamespace;
 use crate::render::ui::*;
 use crate::render::*;
 use crate::resources::*;
 use crate::saves::Card;
 use framework::prelude::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 const FORM_LIST_ANIMATION_TIME: FrameTime = 9;
 const FORM_FADE_DELAY: FrameTime = 10;
 const FORM_FADE_TIME: FrameTime = 20;
+const EMOTION_WINDOW_OFFSET: Vec2 = Vec2::new(121.0, 1.0);
 
 #[derive(Clone, Default)]
 struct Selection {
@@ -83,6 +86,12 @@ impl State for CardSelectState {
         simulation: &mut BattleSimulation,
         _vms: &[RollbackVM],
     ) {
+        if simulation.turn_gauge.mode() == TurnMode::RealTime {
+            // cards are drawn as the turn gauge fills instead
+            self.completed = true;
+            return;
+        }
+
         if self.time == 0 {
             simulation.statistics.turns += 1;
             simulation.turn_gauge.set_time(0);
@@ -170,6 +179,11 @@ impl State for CardSelectState {
         // draw sprite tree
         self.sprites.draw(sprite_queue);
 
+        // draw emotion window to the right of the card select
+        let emotion_window = &player.emotion_window;
+        let emotion_window_position = self.sprites.root().offset() + EMOTION_WINDOW_OFFSET;
+        emotion_window.draw(sprite_queue, player, emotion_window_position);
+
         let mut recycled_sprite = Sprite::new(self.texture.clone(), default_sampler);
         // drawing hand
 
@@ -219,7 +233,9 @@ impl State for CardSelectState {
         self.animator.set_state("SELECTED_FRAME");
         self.animator.apply(&mut recycled_sprite);
 
-        for (card, position) in self.selected_icon_render_iter(player, selection) {
+        let selected_cards = resolve_selected_cards(&simulation.card_combos, player, selection);
+
+        for (card, position) in self.selected_icon_render_iter(&selected_cards) {
             recycled_sprite.set_position(position);
             sprite_queue.draw_sprite(&recycled_sprite);
 
@@ -332,7 +348,8 @@ impl State for CardSelectState {
             match selected_item {
                 SelectedItem::Card(i) => {
                     let card = &player.cards[i];
-                    card.draw_preview(game_io, sprite_queue, preview_point, 1.0);
+                    let element_chart = &simulation.element_chart;
+                    card.draw_preview(game_io, sprite_queue, element_chart, preview_point, 1.0);
                     card.draw_preview_title(game_io, sprite_queue, preview_point);
                 }
                 SelectedItem::Confirm => {
@@ -736,6 +753,7 @@ impl CardSelectState {
 
     fn complete(&mut self, game_io: &GameIO<Globals>, simulation: &mut BattleSimulation) {
         let card_packages = &game_io.globals().card_packages;
+        let card_combos = &simulation.card_combos;
 
         for (_, (player, character)) in
             (simulation.entities).query_mut::<(&mut Player, &mut Character)>()
@@ -754,12 +772,13 @@ impl CardSelectState {
 
             character.cards.clear();
 
+            // combos replace the cards they're made of
+            let selected_cards = resolve_selected_cards(card_combos, player, selection);
+
             // load cards in reverse as we'll pop them off in battle state (first item must be last)
-            for i in selection.selected_card_indices.iter().rev() {
+            for card in selected_cards.iter().rev() {
                 let namespace = player.namespace();
 
-                let card = &player.cards[*i];
-
                 if let Some(package) =
                     card_packages.package_or_fallback(namespace, &card.package_id)
                 {
@@ -895,8 +914,7 @@ impl CardSelectState {
 
     fn selected_icon_render_iter<'a>(
         &self,
-        player: &'a Player,
-        selection: &'a Selection,
+        selected_cards: &'a [Card],
     ) -> impl Iterator<Item = (&'a Card, Vec2)> {
         const VERTICAL_OFFSET: f32 = 16.0;
 
@@ -904,17 +922,11 @@ impl CardSelectState {
         start.x += self.sprites.root().offset().x;
 
         // draw icons
-        selection
-            .selected_card_indices
-            .iter()
-            .enumerate()
-            .map(move |(i, card_index)| {
-                let card = &player.cards[*card_index];
-
-                let position = Vec2::new(start.x, start.y + VERTICAL_OFFSET * i as f32);
+        selected_cards.iter().enumerate().map(move |(i, card)| {
+            let position = Vec2::new(start.x, start.y + VERTICAL_OFFSET * i as f32);
 
-                (card, position)
-            })
+            (card, position)
+        })
     }
 }
 
@@ -945,6 +957,25 @@ fn resolve_selected_item(player: &Player, selection: &Selection) -> SelectedItem
     SelectedItem::None
 }
 
+fn resolve_selected_cards(
+    card_combos: &HashMap<PackageNamespace, Vec<CardCombo>>,
+    player: &Player,
+    selection: &Selection,
+) -> Vec<Card> {
+    let selected_cards: Vec<Card> = (selection.selected_card_indices)
+        .iter()
+        .map(|i| player.cards[*i].clone())
+        .collect();
+
+    // combos are only available to the player with the package defining them
+    let card_combos = card_combos
+        .get(&player.namespace())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    CardCombo::resolve(card_combos, &selected_cards)
+}
+
 fn can_player_select(player: &Player, selection: &Selection, index: usize) -> bool {
     if selection.selected_card_indices.len() >= 5 {
         return false;
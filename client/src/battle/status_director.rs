@@ -1,37 +1,12 @@
-use super::{Entity, PlayerInput, SharedBattleAssets};
+use super::{Entity, PlayerInput, SharedBattleAssets, StatusRegistry};
 use crate::bindable::{HitFlag, HitFlags, SpriteColorMode};
-use crate::render::{AnimatorLoopMode, FrameTime, SpriteNode, TreeIndex};
+use crate::render::{Animator, AnimatorLoopMode, FrameTime, SpriteNode, TreeIndex};
 use crate::resources::{
-    Globals, BATTLE_INPUTS, DEFAULT_INTANGIBILITY_DURATION, DEFAULT_STATUS_DURATION, DRAG_LOCKOUT,
+    AssetManager, Globals, BATTLE_INPUTS, DEFAULT_INTANGIBILITY_DURATION, DEFAULT_STATUS_DURATION,
+    DRAG_LOCKOUT,
 };
-use framework::prelude::GameIO;
-
-struct StatusBlocker {
-    blocking_flag: HitFlags, // The flag that prevents the other from going through.
-    blocked_flag: HitFlags,  // The flag that is being prevented.
-}
-
-// statuses that cancel other status effects
-const STATUS_BLOCKERS: [StatusBlocker; 4] = [
-    StatusBlocker {
-        blocking_flag: HitFlag::FREEZE,
-        blocked_flag: HitFlag::PARALYZE,
-    },
-    StatusBlocker {
-        blocking_flag: HitFlag::PARALYZE,
-        blocked_flag: HitFlag::FREEZE,
-    },
-    StatusBlocker {
-        blocking_flag: HitFlag::BUBBLE,
-        blocked_flag: HitFlag::FREEZE,
-    },
-    StatusBlocker {
-        blocking_flag: HitFlag::CONFUSE,
-        blocked_flag: HitFlag::FREEZE,
-    },
-];
-
-const MASHABLE_STATUSES: [HitFlags; 3] = [HitFlag::PARALYZE, HitFlag::FREEZE, HitFlag::BUBBLE];
+use framework::prelude::{GameIO, Texture};
+use std::sync::Arc;
 
 #[derive(Clone)]
 struct AppliedStatus {
@@ -78,8 +53,10 @@ impl StatusDirector {
         self.input_index = Some(input_index);
     }
 
-    pub fn apply_hit_flags(&mut self, hit_flags: HitFlags) {
-        for hit_flag in HitFlag::LIST {
+    pub fn apply_hit_flags(&mut self, status_registry: &StatusRegistry, hit_flags: HitFlags) {
+        let custom_flags = status_registry.custom_flags();
+
+        for hit_flag in HitFlag::LIST.into_iter().chain(custom_flags) {
             if hit_flags & hit_flag == HitFlag::NONE {
                 continue;
             }
@@ -88,8 +65,8 @@ impl StatusDirector {
                 self.dragged = true;
             }
 
-            let duration = if HitFlag::STATUS_LIST.contains(&hit_flag) {
-                DEFAULT_STATUS_DURATION
+            let duration = if let Some(status) = status_registry.status(hit_flag) {
+                status.duration
             } else if hit_flag == HitFlag::FLASH {
                 DEFAULT_INTANGIBILITY_DURATION
             } else {
@@ -121,8 +98,8 @@ impl StatusDirector {
         }
     }
 
-    pub fn input_locked_out(&self) -> bool {
-        self.dragged || self.remaining_drag_lockout > 0 || self.is_inactionable()
+    pub fn input_locked_out(&self, status_registry: &StatusRegistry) -> bool {
+        self.dragged || self.remaining_drag_lockout > 0 || self.is_inactionable(status_registry)
     }
 
     pub fn is_dragged(&self) -> bool {
@@ -136,18 +113,17 @@ impl StatusDirector {
         self.remaining_drag_lockout = DRAG_LOCKOUT + 1;
     }
 
-    pub fn is_inactionable(&self) -> bool {
-        self.remaining_status_time(HitFlag::PARALYZE) > 0
-            || self.remaining_status_time(HitFlag::BUBBLE) > 0
-            || self.remaining_status_time(HitFlag::FREEZE) > 0
+    pub fn is_inactionable(&self, status_registry: &StatusRegistry) -> bool {
+        self.statuses.iter().any(|status| {
+            status.remaining_time > 0 && status_registry.is_inactionable(status.status_flag)
+        })
     }
 
-    pub fn is_immobile(&self) -> bool {
+    pub fn is_immobile(&self, status_registry: &StatusRegistry) -> bool {
         self.remaining_drag_lockout > 0
-            || self.remaining_status_time(HitFlag::PARALYZE) > 0
-            || self.remaining_status_time(HitFlag::BUBBLE) > 0
-            || self.remaining_status_time(HitFlag::ROOT) > 0
-            || self.remaining_status_time(HitFlag::FREEZE) > 0
+            || self.statuses.iter().any(|status| {
+                status.remaining_time > 0 && status_registry.is_immobile(status.status_flag)
+            })
     }
 
     pub fn is_shaking(&self) -> bool {
@@ -168,19 +144,46 @@ impl StatusDirector {
         &mut self,
         game_io: &GameIO<Globals>,
         shared_assets: &mut SharedBattleAssets,
+        status_registry: &StatusRegistry,
         entity: &mut Entity,
     ) {
-        self.update_status_sprite(game_io, shared_assets, entity, HitFlag::FREEZE);
-        self.update_status_sprite(game_io, shared_assets, entity, HitFlag::BLIND);
-        self.update_status_sprite(game_io, shared_assets, entity, HitFlag::CONFUSE);
+        for status_flag in [HitFlag::FREEZE, HitFlag::BLIND, HitFlag::CONFUSE] {
+            let texture = shared_assets.statuses_texture.clone();
+            let state = HitFlag::status_animation_state(status_flag, entity.height);
+            let animator = &mut shared_assets.statuses_animator;
+
+            self.update_status_sprite(game_io, entity, status_flag, texture, animator, state);
+        }
+
+        let assets = &game_io.globals().assets;
+
+        for status in status_registry.custom_statuses() {
+            let Some(sprite) = &status.sprite else {
+                continue;
+            };
+
+            let texture = assets.texture(game_io, &sprite.texture_path);
+            let animator = shared_assets.status_animator(game_io, &sprite.animation_path);
+
+            self.update_status_sprite(
+                game_io,
+                entity,
+                status.flag,
+                texture,
+                animator,
+                &sprite.state,
+            );
+        }
     }
 
     fn update_status_sprite(
         &mut self,
         game_io: &GameIO<Globals>,
-        shared_assets: &mut SharedBattleAssets,
         entity: &mut Entity,
         status_flag: HitFlags,
+        texture: Arc<Texture>,
+        animator: &mut Animator,
+        state: &str,
     ) {
         let sprite_tree = &mut entity.sprite_tree;
 
@@ -196,7 +199,6 @@ impl StatusDirector {
 
         let index = existing_index.unwrap_or_else(|| {
             let mut sprite_node = SpriteNode::new(game_io, SpriteColorMode::Add);
-            let texture = shared_assets.statuses_texture.clone();
             sprite_node.set_texture_direct(texture);
 
             let index = sprite_tree.insert_root_child(sprite_node);
@@ -207,8 +209,6 @@ impl StatusDirector {
         let alpha = sprite_tree.root().color().a;
 
         let sprite_node = &mut sprite_tree[index];
-        let animator = &mut shared_assets.statuses_animator;
-        let state = HitFlag::status_animation_state(status_flag, entity.height);
 
         if animator.current_state() != Some(state) {
             animator.set_state(state);
@@ -216,7 +216,7 @@ impl StatusDirector {
         }
 
         animator.sync_time(lifetime);
-        sprite_node.apply_animation(&animator);
+        sprite_node.apply_animation(animator);
         sprite_node.set_offset(HitFlag::status_sprite_position(status_flag, entity.height));
         sprite_node.set_alpha(alpha);
     }
@@ -306,7 +306,14 @@ impl StatusDirector {
             .collect()
     }
 
-    fn apply_new_statuses(&mut self) {
+    pub fn active_statuses(&self) -> impl Iterator<Item = HitFlags> + '_ {
+        self.statuses
+            .iter()
+            .filter(|status| status.remaining_time > 0)
+            .map(|status| status.status_flag)
+    }
+
+    fn apply_new_statuses(&mut self, status_registry: &StatusRegistry) {
         let mut already_existing = Vec::new();
 
         for status in &self.new_statuses {
@@ -334,13 +341,13 @@ impl StatusDirector {
             self.new_statuses.remove(i);
         }
 
-        self.detect_cancelled_statuses();
+        self.detect_cancelled_statuses(status_registry);
     }
 
-    fn detect_cancelled_statuses(&mut self) {
+    fn detect_cancelled_statuses(&mut self, status_registry: &StatusRegistry) {
         let mut cancelled_statuses = Vec::new();
 
-        for blocker in &STATUS_BLOCKERS {
+        for blocker in status_registry.blockers() {
             if self.remaining_status_time(blocker.blocking_flag) > 0 {
                 cancelled_statuses.push(blocker.blocked_flag);
             }
@@ -351,7 +358,7 @@ impl StatusDirector {
         }
     }
 
-    pub fn update(&mut self, inputs: &[PlayerInput]) {
+    pub fn update(&mut self, status_registry: &StatusRegistry, inputs: &[PlayerInput]) {
         // detect mashing
         let mashed = if let Some(index) = self.input_index {
             let player_input = &inputs[index];
@@ -365,7 +372,7 @@ impl StatusDirector {
 
         // update remaining time
         for status in &mut self.statuses {
-            if mashed && MASHABLE_STATUSES.contains(&status.status_flag) {
+            if mashed && status_registry.is_mashable(status.status_flag) {
                 status.remaining_time -= 1;
             }
 
@@ -378,7 +385,7 @@ impl StatusDirector {
             }
         }
 
-        self.apply_new_statuses();
+        self.apply_new_statuses(status_registry);
 
         if self.remaining_drag_lockout > 0 {
             self.remaining_drag_lockout -= 1;
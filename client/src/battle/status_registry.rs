@@ -0,0 +1,189 @@
+use super::BattleCallback;
+use crate::bindable::{EntityID, HitFlag, HitFlags};
+use crate::render::FrameTime;
+use crate::resources::DEFAULT_STATUS_DURATION;
+
+// bits after PIERCE_GROUND are free for statuses registered by packages
+const FIRST_CUSTOM_FLAG: HitFlags = HitFlag::PIERCE_GROUND << 1;
+
+#[derive(Clone)]
+pub struct StatusBlocker {
+    pub blocking_flag: HitFlags, // The flag that prevents the other from going through.
+    pub blocked_flag: HitFlags,  // The flag that is being prevented.
+}
+
+#[derive(Clone)]
+pub struct StatusSprite {
+    pub texture_path: String,
+    pub animation_path: String,
+    pub state: String,
+}
+
+#[derive(Clone)]
+pub struct StatusDefinition {
+    pub name: String,
+    pub flag: HitFlags,
+    pub duration: FrameTime,
+    pub mashable: bool,
+    pub inactionable: bool,
+    pub immobile: bool,
+    pub sprite: Option<StatusSprite>,
+    pub update_callback: Option<BattleCallback<EntityID>>,
+}
+
+impl StatusDefinition {
+    pub fn new(name: &str, flag: HitFlags) -> Self {
+        Self {
+            name: name.to_string(),
+            flag,
+            duration: DEFAULT_STATUS_DURATION,
+            mashable: false,
+            inactionable: false,
+            immobile: false,
+            sprite: None,
+            update_callback: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct StatusRegistry {
+    statuses: Vec<StatusDefinition>,
+    blockers: Vec<StatusBlocker>,
+    next_flag: HitFlags,
+}
+
+impl Default for StatusRegistry {
+    fn default() -> Self {
+        let statuses = vec![
+            StatusDefinition {
+                mashable: true,
+                inactionable: true,
+                immobile: true,
+                ..StatusDefinition::new("Freeze", HitFlag::FREEZE)
+            },
+            StatusDefinition {
+                mashable: true,
+                inactionable: true,
+                immobile: true,
+                ..StatusDefinition::new("Paralyze", HitFlag::PARALYZE)
+            },
+            StatusDefinition {
+                mashable: true,
+                inactionable: true,
+                immobile: true,
+                ..StatusDefinition::new("Bubble", HitFlag::BUBBLE)
+            },
+            StatusDefinition {
+                immobile: true,
+                ..StatusDefinition::new("Root", HitFlag::ROOT)
+            },
+            StatusDefinition::new("Blind", HitFlag::BLIND),
+            StatusDefinition::new("Confuse", HitFlag::CONFUSE),
+        ];
+
+        // statuses that cancel other status effects
+        let blockers = vec![
+            StatusBlocker {
+                blocking_flag: HitFlag::FREEZE,
+                blocked_flag: HitFlag::PARALYZE,
+            },
+            StatusBlocker {
+                blocking_flag: HitFlag::PARALYZE,
+                blocked_flag: HitFlag::FREEZE,
+            },
+            StatusBlocker {
+                blocking_flag: HitFlag::BUBBLE,
+                blocked_flag: HitFlag::FREEZE,
+            },
+            StatusBlocker {
+                blocking_flag: HitFlag::CONFUSE,
+                blocked_flag: HitFlag::FREEZE,
+            },
+        ];
+
+        Self {
+            statuses,
+            blockers,
+            next_flag: FIRST_CUSTOM_FLAG,
+        }
+    }
+}
+
+impl StatusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns None if every flag is already in use
+    pub fn register(&mut self, mut definition: StatusDefinition) -> Option<HitFlags> {
+        if let Some(status) = self.status_by_name(&definition.name) {
+            return Some(status.flag);
+        }
+
+        if self.next_flag == HitFlag::NONE {
+            return None;
+        }
+
+        definition.flag = self.next_flag;
+        self.next_flag = self.next_flag.checked_shl(1).unwrap_or(HitFlag::NONE);
+
+        let flag = definition.flag;
+        self.statuses.push(definition);
+
+        Some(flag)
+    }
+
+    pub fn add_blocker(&mut self, blocking_flag: HitFlags, blocked_flag: HitFlags) {
+        let exists = self.blockers.iter().any(|blocker| {
+            blocker.blocking_flag == blocking_flag && blocker.blocked_flag == blocked_flag
+        });
+
+        if !exists {
+            self.blockers.push(StatusBlocker {
+                blocking_flag,
+                blocked_flag,
+            });
+        }
+    }
+
+    pub fn blockers(&self) -> &[StatusBlocker] {
+        &self.blockers
+    }
+
+    pub fn status(&self, flag: HitFlags) -> Option<&StatusDefinition> {
+        self.statuses.iter().find(|status| status.flag == flag)
+    }
+
+    pub fn status_by_name(&self, name: &str) -> Option<&StatusDefinition> {
+        self.statuses.iter().find(|status| status.name == name)
+    }
+
+    pub fn custom_statuses(&self) -> impl Iterator<Item = &StatusDefinition> {
+        self.statuses
+            .iter()
+            .filter(|status| status.flag >= FIRST_CUSTOM_FLAG)
+    }
+
+    pub fn custom_flags(&self) -> impl Iterator<Item = HitFlags> + '_ {
+        self.custom_statuses().map(|status| status.flag)
+    }
+
+    pub fn is_mashable(&self, flag: HitFlags) -> bool {
+        self.status(flag)
+            .map(|status| status.mashable)
+            .unwrap_or_default()
+    }
+
+    pub fn is_inactionable(&self, flag: HitFlags) -> bool {
+        self.status(flag)
+            .map(|status| status.inactionable)
+            .unwrap_or_default()
+    }
+
+    pub fn is_immobile(&self, flag: HitFlags) -> bool {
+        self.status(flag)
+            .map(|status| status.immobile)
+            .unwrap_or_default()
+    }
+}
@@ -1,4 +1,4 @@
-use super::{CardAction, Entity};
+use super::{CardAction, ElementChart, Entity};
 use crate::bindable::*;
 use crate::render::FrameTime;
 use crate::resources::{TEMP_TEAM_DURATION, TILE_FLICKER_DURATION};
@@ -30,6 +30,12 @@ impl Tile {
         }
     }
 
+    /// Moves the tile's state to a new position, used when the field is resized
+    pub fn relocate(&mut self, position: (i32, i32), immutable_team: bool) {
+        self.position = position;
+        self.immutable_team = immutable_team;
+    }
+
     pub fn state(&self) -> TileState {
         self.state
     }
@@ -82,6 +88,13 @@ impl Tile {
         }
     }
 
+    /// Permanently assigns the tile to a team, ignoring reservations and stolen columns
+    pub fn set_original_team(&mut self, team: Team) {
+        self.original_team = team;
+        self.team = team;
+        self.team_revert_timer = 0;
+    }
+
     pub fn team_revert_timer(&self) -> FrameTime {
         self.team_revert_timer
     }
@@ -132,13 +145,8 @@ impl Tile {
         }
     }
 
-    pub fn attempt_wash(&mut self, element: Element) {
-        self.washed = matches!(
-            (self.state, element),
-            (TileState::Sand, Element::Wind)
-                | (TileState::Grass, Element::Fire)
-                | (TileState::Volcano, Element::Aqua)
-        );
+    pub fn attempt_wash(&mut self, element_chart: &ElementChart, element: Element) {
+        self.washed = element_chart.washes(self.state, element);
     }
 
     pub fn ignoring_attacker(&self, id: EntityID) -> bool {
@@ -236,7 +244,7 @@ impl Tile {
         }
     }
 
-    pub fn apply_bonus_damage(&self, props: &HitProperties) -> bool {
+    pub fn apply_bonus_damage(&self, element_chart: &ElementChart, props: &HitProperties) -> bool {
         let element = match self.state {
             TileState::Grass => Element::Wood,
             TileState::Lava => Element::Fire,
@@ -244,7 +252,7 @@ impl Tile {
             _ => Element::None,
         };
 
-        props.is_super_effective(element)
+        props.is_super_effective(element_chart, element)
     }
 
     pub fn reset_highlight(&mut self) {
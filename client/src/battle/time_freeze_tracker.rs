@@ -1,5 +1,5 @@
-use super::{BattleAnimator, BattleSimulation, Entity, StatusDirector};
-use crate::bindable::{EntityID, Team};
+use super::{BattleAnimator, BattleCallback, BattleSimulation, Entity, StatusDirector};
+use crate::bindable::{CardProperties, EntityID, Team};
 use crate::ease::inverse_lerp;
 use crate::render::ui::{FontStyle, TextStyle};
 use crate::render::{FrameTime, SpriteColorQueue};
@@ -8,8 +8,9 @@ use framework::prelude::{Color, GameIO, Vec2};
 
 const FADE_DURATION: FrameTime = 10;
 const DECROSS_DURATION: FrameTime = 30;
-const COUNTER_DURATION: FrameTime = 60;
-const MAX_ACTION_DURATION: FrameTime = 60 * 15;
+const DEFAULT_COUNTER_DURATION: FrameTime = 60;
+const DEFAULT_MAX_ACTION_DURATION: FrameTime = 60 * 15;
+const DEFAULT_DIM_ALPHA: f32 = 0.3;
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 enum TimeFreezeState {
@@ -22,9 +23,20 @@ enum TimeFreezeState {
     Decross,
 }
 
-#[derive(Default, Clone)]
+#[derive(Clone, Copy)]
+pub struct TimeFreezeChainEntry {
+    pub team: Team,
+    pub action_index: generational_arena::Index,
+    counter_duration: FrameTime,
+}
+
+#[derive(Clone)]
 pub struct TimeFreezeTracker {
-    chain: Vec<(Team, generational_arena::Index)>,
+    chain: Vec<TimeFreezeChainEntry>,
+    counter_duration: FrameTime,
+    max_action_duration: FrameTime,
+    dim_alpha: f32,
+    counter_callbacks: Vec<BattleCallback<(EntityID, EntityID)>>,
     active_time: FrameTime,
     state_start_time: FrameTime,
     state: TimeFreezeState,
@@ -38,11 +50,55 @@ pub struct TimeFreezeTracker {
     )>,
 }
 
+impl Default for TimeFreezeTracker {
+    fn default() -> Self {
+        Self {
+            chain: Vec::new(),
+            counter_duration: DEFAULT_COUNTER_DURATION,
+            max_action_duration: DEFAULT_MAX_ACTION_DURATION,
+            dim_alpha: DEFAULT_DIM_ALPHA,
+            counter_callbacks: Vec::new(),
+            active_time: 0,
+            state_start_time: 0,
+            state: TimeFreezeState::default(),
+            revert_state: Default::default(),
+            should_defrost: false,
+            character_backup: None,
+        }
+    }
+}
+
 impl TimeFreezeTracker {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sets the counter window used by cards that don't specify their own
+    pub fn set_counter_duration(&mut self, duration: FrameTime) {
+        self.counter_duration = duration;
+    }
+
+    pub fn set_max_action_duration(&mut self, duration: FrameTime) {
+        self.max_action_duration = duration;
+    }
+
+    pub fn chain(&self) -> &[TimeFreezeChainEntry] {
+        &self.chain
+    }
+
+    pub fn add_counter_callback(&mut self, callback: BattleCallback<(EntityID, EntityID)>) {
+        self.counter_callbacks.push(callback);
+    }
+
+    pub fn counter_callbacks(&self) -> &[BattleCallback<(EntityID, EntityID)>] {
+        &self.counter_callbacks
+    }
+
+    /// The background dim, from the most recent card in the chain
+    pub fn dim_alpha(&self) -> f32 {
+        self.dim_alpha * self.fade_alpha()
+    }
+
     pub fn time_is_frozen(&self) -> bool {
         self.state != TimeFreezeState::Thawed
     }
@@ -63,8 +119,13 @@ impl TimeFreezeTracker {
         }
     }
 
-    pub fn set_team_action(&mut self, team: Team, action_index: generational_arena::Index) {
-        if let Some(index) = self.chain.iter().position(|(t, _)| *t == team) {
+    pub fn set_team_action(
+        &mut self,
+        team: Team,
+        action_index: generational_arena::Index,
+        card_properties: &CardProperties,
+    ) {
+        if let Some(index) = self.chain.iter().position(|entry| entry.team == team) {
             self.chain.remove(index);
         }
 
@@ -76,7 +137,15 @@ impl TimeFreezeTracker {
         // set the state start time to allow other players to counter, as well as initialize
         self.state_start_time = self.active_time;
 
-        self.chain.push((team, action_index));
+        self.dim_alpha = card_properties.time_freeze_dim.unwrap_or(DEFAULT_DIM_ALPHA);
+
+        self.chain.push(TimeFreezeChainEntry {
+            team,
+            action_index,
+            counter_duration: card_properties
+                .time_freeze_counter_window
+                .unwrap_or(self.counter_duration),
+        });
     }
 
     pub fn start_decross(&mut self) {
@@ -108,7 +177,7 @@ impl TimeFreezeTracker {
                 }
             }
             TimeFreezeState::Counterable => {
-                if state_elapsed_time >= COUNTER_DURATION {
+                if state_elapsed_time >= self.current_counter_duration() {
                     self.state = TimeFreezeState::Action;
                     self.state_start_time = self.active_time;
                 }
@@ -131,13 +200,34 @@ impl TimeFreezeTracker {
     }
 
     pub fn last_team(&self) -> Option<Team> {
-        self.chain.last().map(|(team, _)| team).cloned()
+        self.chain.last().map(|entry| entry.team)
+    }
+
+    pub fn last_action(&self) -> Option<generational_arena::Index> {
+        self.chain.last().map(|entry| entry.action_index)
     }
 
     pub fn can_counter(&self) -> bool {
         self.state == TimeFreezeState::Counterable
     }
 
+    fn current_counter_duration(&self) -> FrameTime {
+        self.chain
+            .last()
+            .map(|entry| entry.counter_duration)
+            .unwrap_or(self.counter_duration)
+    }
+
+    pub fn counter_time_remaining(&self) -> FrameTime {
+        if !self.can_counter() {
+            return 0;
+        }
+
+        let elapsed_time = self.active_time - self.state_start_time;
+
+        (self.current_counter_duration() - elapsed_time).max(0)
+    }
+
     pub fn should_freeze(&self) -> bool {
         matches!(
             self.state,
@@ -158,11 +248,11 @@ impl TimeFreezeTracker {
             return None;
         }
 
-        self.chain.last().map(|(_, index)| index).cloned()
+        self.last_action()
     }
 
     pub fn end_action(&mut self) {
-        self.active_time = self.state_start_time + MAX_ACTION_DURATION;
+        self.active_time = self.state_start_time + self.max_action_duration;
     }
 
     pub fn advance_action(&mut self) {
@@ -179,7 +269,7 @@ impl TimeFreezeTracker {
 
     pub fn action_out_of_time(&self) -> bool {
         self.state == TimeFreezeState::Action
-            && self.active_time - self.state_start_time >= MAX_ACTION_DURATION
+            && self.active_time - self.state_start_time >= self.max_action_duration
     }
 
     pub fn back_up_character(
@@ -216,9 +306,11 @@ impl TimeFreezeTracker {
             return;
         }
 
-        let (team, index) = self.chain.last().cloned().unwrap();
+        let TimeFreezeChainEntry {
+            team, action_index, ..
+        } = *self.chain.last().unwrap();
 
-        let Some(action) = simulation.card_actions.get(index) else {
+        let Some(action) = simulation.card_actions.get(action_index) else {
             return;
         };
 
@@ -242,15 +334,27 @@ impl TimeFreezeTracker {
         };
 
         let card_props = &action.properties;
-        card_props.draw_summary(game_io, sprite_queue, position, true);
+        let text_style = TextStyle::new(game_io, FontStyle::Thick);
+
+        match card_props.time_freeze_banner.as_deref() {
+            Some(banner) => {
+                // custom banner, centered like the summary
+                let mut banner_style = text_style.clone();
+                banner_style.shadow_color = Color::BLACK;
+                banner_style.bounds.set_position(position);
+                banner_style.bounds.x -= banner_style.measure(banner).size.x * 0.5;
+                banner_style.draw(game_io, sprite_queue, banner);
+            }
+            None => card_props.draw_summary(game_io, sprite_queue, position, true),
+        }
 
         // drawing bar
-        let text_style = TextStyle::new(game_io, FontStyle::Thick);
         position.x -= BAR_WIDTH * 0.5;
         position.y += text_style.line_height() + text_style.line_spacing;
 
         let elapsed_time = self.active_time - self.state_start_time;
-        let width_multiplier = inverse_lerp!(COUNTER_DURATION, 0, elapsed_time);
+        let counter_duration = self.current_counter_duration();
+        let width_multiplier = inverse_lerp!(counter_duration, 0, elapsed_time);
 
         let assets = &game_io.globals().assets;
         let mut sprite = assets.new_sprite(game_io, ResourcePaths::WHITE_PIXEL);
@@ -1,3 +1,4 @@
+use crate::bindable::TurnMode;
 use crate::render::*;
 use crate::resources::{AssetManager, Globals, ResourcePaths, RESOLUTION_F};
 use framework::prelude::{GameIO, Sprite, Vec2};
@@ -6,6 +7,7 @@ use framework::prelude::{GameIO, Sprite, Vec2};
 pub struct TurnGauge {
     time: FrameTime,
     max_time: FrameTime,
+    mode: TurnMode,
     animator: Animator,
     container_sprite: Sprite,
     sprite: Sprite,
@@ -28,6 +30,7 @@ impl TurnGauge {
         Self {
             time: 0,
             max_time: Self::DEFAULT_MAX_TIME,
+            mode: TurnMode::default(),
             animator,
             sprite,
             container_sprite,
@@ -67,6 +70,14 @@ impl TurnGauge {
         self.max_time = time
     }
 
+    pub fn mode(&self) -> TurnMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: TurnMode) {
+        self.mode = mode
+    }
+
     fn resolve_state(&self) -> &'static str {
         if self.is_complete() {
             "READY"
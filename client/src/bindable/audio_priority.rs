@@ -0,0 +1,49 @@
+use num_derive::FromPrimitive;
+
+/// Decides which sounds are dropped once every voice is in use
+#[repr(u8)]
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, FromPrimitive, PartialOrd, Ord)]
+pub enum AudioPriority {
+    /// Skipped if the same sound is already playing
+    Lowest,
+    #[default]
+    Low,
+    High,
+    /// Always plays
+    Highest,
+}
+
+impl<'lua> rollback_mlua::FromLua<'lua> for AudioPriority {
+    fn from_lua(
+        lua_value: rollback_mlua::Value<'lua>,
+        _lua: &'lua rollback_mlua::Lua,
+    ) -> rollback_mlua::Result<Self> {
+        use num_traits::FromPrimitive;
+
+        let number = match lua_value {
+            rollback_mlua::Value::Number(number) => number,
+            _ => {
+                return Err(rollback_mlua::Error::FromLuaConversionError {
+                    from: lua_value.type_name(),
+                    to: "AudioPriority",
+                    message: None,
+                })
+            }
+        };
+
+        AudioPriority::from_u8(number as u8).ok_or(rollback_mlua::Error::FromLuaConversionError {
+            from: lua_value.type_name(),
+            to: "AudioPriority",
+            message: None,
+        })
+    }
+}
+
+impl<'lua> rollback_mlua::ToLua<'lua> for AudioPriority {
+    fn to_lua(
+        self,
+        _lua: &'lua rollback_mlua::Lua,
+    ) -> rollback_mlua::Result<rollback_mlua::Value<'lua>> {
+        Ok(rollback_mlua::Value::Number(self as u8 as f64))
+    }
+}
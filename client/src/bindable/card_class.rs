@@ -1,4 +1,5 @@
 use num_derive::FromPrimitive;
+use packets::structures::FolderRules;
 
 #[repr(u8)]
 #[derive(Default, Copy, Clone, PartialEq, Eq, FromPrimitive)]
@@ -10,6 +11,34 @@ pub enum CardClass {
     Dark,
 }
 
+impl CardClass {
+    pub const LIST: [CardClass; 4] = [
+        CardClass::Standard,
+        CardClass::Mega,
+        CardClass::Giga,
+        CardClass::Dark,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CardClass::Standard => "Standard",
+            CardClass::Mega => "Mega",
+            CardClass::Giga => "Giga",
+            CardClass::Dark => "Dark",
+        }
+    }
+
+    /// None if the folder can hold any amount of this class
+    pub fn folder_limit(self, rules: &FolderRules) -> Option<usize> {
+        match self {
+            CardClass::Standard => None,
+            CardClass::Mega => Some(rules.mega_limit),
+            CardClass::Giga => Some(rules.giga_limit),
+            CardClass::Dark => Some(rules.dark_limit),
+        }
+    }
+}
+
 impl<'lua> rollback_mlua::FromLua<'lua> for CardClass {
     fn from_lua(
         lua_value: rollback_mlua::Value<'lua>,
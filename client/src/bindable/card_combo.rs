@@ -0,0 +1,154 @@
+use crate::saves::Card;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct CardCombo {
+    pub name: String,
+    pub cards: Vec<Card>,
+    pub result: Card,
+}
+
+impl CardCombo {
+    pub fn matches(&self, cards: &[Card]) -> bool {
+        if self.cards.len() != cards.len() {
+            return false;
+        }
+
+        self.cards.iter().zip(cards).all(|(required, card)| {
+            required.package_id == card.package_id
+                && (required.code == card.code || required.code == "*" || card.code == "*")
+        })
+    }
+
+    /// Replaces every ordered sequence matching a combo with the combo's result, longest combos first
+    pub fn resolve(combos: &[CardCombo], cards: &[Card]) -> Vec<Card> {
+        let mut sorted_combos: Vec<_> = combos.iter().collect();
+        sorted_combos.sort_by_key(|combo| std::cmp::Reverse(combo.cards.len()));
+
+        let mut resolved = Vec::with_capacity(cards.len());
+        let mut i = 0;
+
+        while i < cards.len() {
+            let remaining = &cards[i..];
+
+            let combo = sorted_combos.iter().find(|combo| {
+                !combo.cards.is_empty()
+                    && combo.cards.len() <= remaining.len()
+                    && combo.matches(&remaining[..combo.cards.len()])
+            });
+
+            if let Some(combo) = combo {
+                resolved.push(combo.result.clone());
+                i += combo.cards.len();
+            } else {
+                resolved.push(cards[i].clone());
+                i += 1;
+            }
+        }
+
+        resolved
+    }
+}
+
+impl<'lua> rollback_mlua::FromLua<'lua> for CardCombo {
+    fn from_lua(
+        lua_value: rollback_mlua::Value<'lua>,
+        _lua: &'lua rollback_mlua::Lua,
+    ) -> rollback_mlua::Result<Self> {
+        let table = match lua_value {
+            rollback_mlua::Value::Table(table) => table,
+            _ => {
+                return Err(rollback_mlua::Error::FromLuaConversionError {
+                    from: lua_value.type_name(),
+                    to: "CardCombo",
+                    message: None,
+                })
+            }
+        };
+
+        let card_tables: Vec<rollback_mlua::Table> = table.get("cards")?;
+        let mut cards = Vec::with_capacity(card_tables.len());
+
+        for card_table in card_tables {
+            cards.push(card_from_table(card_table)?);
+        }
+
+        let result = match table.get::<_, Option<rollback_mlua::Table>>("result")? {
+            Some(result_table) => card_from_table(result_table)?,
+            // the package defining the combo fills this in
+            None => Card {
+                package_id: String::new(),
+                code: String::from("*"),
+            },
+        };
+
+        Ok(CardCombo {
+            name: table.get("name").unwrap_or_default(),
+            cards,
+            result,
+        })
+    }
+}
+
+fn card_from_table(table: rollback_mlua::Table) -> rollback_mlua::Result<Card> {
+    Ok(Card {
+        package_id: table.get("package_id")?,
+        code: table
+            .get::<_, Option<String>>("code")?
+            .unwrap_or_else(|| String::from("*")),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(package_id: &str, code: &str) -> Card {
+        Card {
+            package_id: package_id.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let combos = [
+            CardCombo {
+                name: String::from("Short"),
+                cards: vec![card("a", "A"), card("b", "*")],
+                result: card("ab", "*"),
+            },
+            CardCombo {
+                name: String::from("Long"),
+                cards: vec![card("a", "A"), card("b", "B"), card("c", "C")],
+                result: card("abc", "*"),
+            },
+        ];
+
+        let hand = [
+            card("x", "X"),
+            card("a", "A"),
+            card("b", "B"),
+            card("c", "C"),
+        ];
+        let expected = [card("x", "X"), card("abc", "*")];
+        assert!(
+            CardCombo::resolve(&combos, &hand) == expected,
+            "longest combo wins"
+        );
+
+        let hand = [
+            card("a", "A"),
+            card("b", "Q"),
+            card("a", "*"),
+            card("b", "B"),
+        ];
+        let expected = [card("ab", "*"), card("ab", "*")];
+        assert!(
+            CardCombo::resolve(&combos, &hand) == expected,
+            "wildcard codes"
+        );
+
+        let hand = [card("b", "B"), card("a", "A")];
+        assert!(CardCombo::resolve(&combos, &hand) == hand, "order matters");
+    }
+}
@@ -1,6 +1,6 @@
 use super::{CardClass, Element, HitFlags};
 use crate::render::ui::{FontStyle, TextStyle};
-use crate::render::SpriteColorQueue;
+use crate::render::{FrameTime, SpriteColorQueue};
 use crate::resources::Globals;
 use framework::prelude::{Color, GameIO, Vec2};
 
@@ -21,6 +21,12 @@ pub struct CardProperties {
     pub counterable: bool,
     pub time_freeze: bool,
     pub skip_time_freeze_intro: bool,
+    /// Overrides the battle's counter window while this card is at the end of the chain
+    pub time_freeze_counter_window: Option<FrameTime>,
+    /// Replaces the name and damage shown while the card can be countered, an empty string hides it
+    pub time_freeze_banner: Option<String>,
+    /// Alpha for the background dim
+    pub time_freeze_dim: Option<f32>,
     pub meta_classes: Vec<String>,
 }
 
@@ -41,6 +47,9 @@ impl Default for CardProperties {
             can_boost: true,
             counterable: true,
             skip_time_freeze_intro: false,
+            time_freeze_counter_window: None,
+            time_freeze_banner: None,
+            time_freeze_dim: None,
             meta_classes: Vec::new(),
         }
     }
@@ -120,6 +129,9 @@ impl<'lua> rollback_mlua::FromLua<'lua> for CardProperties {
             counterable: table.get("counterable").unwrap_or_default(),
             time_freeze: table.get("time_freeze").unwrap_or_default(),
             skip_time_freeze_intro: table.get("skip_time_freeze_intro").unwrap_or_default(),
+            time_freeze_counter_window: table.get("time_freeze_counter_window").unwrap_or_default(),
+            time_freeze_banner: table.get("time_freeze_banner").unwrap_or_default(),
+            time_freeze_dim: table.get("time_freeze_dim").unwrap_or_default(),
             meta_classes: table.get("meta_classes").unwrap_or_default(),
         })
     }
@@ -144,6 +156,12 @@ impl<'lua> rollback_mlua::ToLua<'lua> for &CardProperties {
         table.set("counterable", self.counterable)?;
         table.set("time_freeze", self.time_freeze)?;
         table.set("skip_time_freeze_intro", self.skip_time_freeze_intro)?;
+        table.set(
+            "time_freeze_counter_window",
+            self.time_freeze_counter_window,
+        )?;
+        table.set("time_freeze_banner", self.time_freeze_banner.as_deref())?;
+        table.set("time_freeze_dim", self.time_freeze_dim)?;
         table.set("meta_classes", self.meta_classes.clone())?;
 
         Ok(rollback_mlua::Value::Table(table))
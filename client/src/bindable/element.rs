@@ -1,36 +1,67 @@
-use num_derive::FromPrimitive;
-use strum::Display;
+use std::fmt::Display;
 
-#[repr(u8)]
-#[derive(PartialEq, Eq, Default, Clone, Copy, FromPrimitive, Display)]
-pub enum Element {
-    #[default]
-    None,
-    Fire,
-    Aqua,
-    Elec,
-    Wood,
-    Sword,
-    Wind,
-    Cursor,
-    Summon,
-    Plus,
-    Break,
-}
+/// Ids after `Element::Break` are assigned to elements registered by packages
+#[derive(PartialEq, Eq, Default, Clone, Copy)]
+pub struct Element(pub u8);
 
+#[allow(non_upper_case_globals)]
 impl Element {
-    pub fn is_weak_to(self, other: Element) -> bool {
-        matches!(
-            (self, other),
-            (Element::Aqua, Element::Elec)
-                | (Element::Fire, Element::Aqua)
-                | (Element::Wood, Element::Fire)
-                | (Element::Elec, Element::Wood)
-                | (Element::Sword, Element::Break)
-                | (Element::Wind, Element::Sword)
-                | (Element::Cursor, Element::Wind)
-                | (Element::Break, Element::Cursor)
-        )
+    pub const None: Element = Element(0);
+    pub const Fire: Element = Element(1);
+    pub const Aqua: Element = Element(2);
+    pub const Elec: Element = Element(3);
+    pub const Wood: Element = Element(4);
+    pub const Sword: Element = Element(5);
+    pub const Wind: Element = Element(6);
+    pub const Cursor: Element = Element(7);
+    pub const Summon: Element = Element(8);
+    pub const Plus: Element = Element(9);
+    pub const Break: Element = Element(10);
+
+    pub const BUILT_IN: [Element; 11] = [
+        Element::None,
+        Element::Fire,
+        Element::Aqua,
+        Element::Elec,
+        Element::Wood,
+        Element::Sword,
+        Element::Wind,
+        Element::Cursor,
+        Element::Summon,
+        Element::Plus,
+        Element::Break,
+    ];
+
+    pub fn is_built_in(self) -> bool {
+        self.0 <= Element::Break.0
+    }
+
+    pub fn built_in_name(self) -> Option<&'static str> {
+        let name = match self {
+            Element::None => "None",
+            Element::Fire => "Fire",
+            Element::Aqua => "Aqua",
+            Element::Elec => "Elec",
+            Element::Wood => "Wood",
+            Element::Sword => "Sword",
+            Element::Wind => "Wind",
+            Element::Cursor => "Cursor",
+            Element::Summon => "Summon",
+            Element::Plus => "Plus",
+            Element::Break => "Break",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.built_in_name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "Element{}", self.0),
+        }
     }
 }
 
@@ -39,10 +70,9 @@ impl<'lua> rollback_mlua::FromLua<'lua> for Element {
         lua_value: rollback_mlua::Value<'lua>,
         _lua: &'lua rollback_mlua::Lua,
     ) -> rollback_mlua::Result<Self> {
-        use num_traits::FromPrimitive;
-
         let number = match lua_value {
             rollback_mlua::Value::Number(number) => number,
+            rollback_mlua::Value::Integer(number) => number as f64,
             _ => {
                 return Err(rollback_mlua::Error::FromLuaConversionError {
                     from: lua_value.type_name(),
@@ -52,11 +82,16 @@ impl<'lua> rollback_mlua::FromLua<'lua> for Element {
             }
         };
 
-        Element::from_u8(number as u8).ok_or(rollback_mlua::Error::FromLuaConversionError {
-            from: lua_value.type_name(),
-            to: "Element",
-            message: None,
-        })
+        // registration is checked against the element chart by the battle api
+        if number.fract() != 0.0 || number < 0.0 || number > u8::MAX as f64 {
+            return Err(rollback_mlua::Error::FromLuaConversionError {
+                from: "number",
+                to: "Element",
+                message: Some(format!("{number} is not a valid element id")),
+            });
+        }
+
+        Ok(Element(number as u8))
     }
 }
 
@@ -65,6 +100,6 @@ impl<'lua> rollback_mlua::ToLua<'lua> for Element {
         self,
         _lua: &'lua rollback_mlua::Lua,
     ) -> rollback_mlua::Result<rollback_mlua::Value<'lua>> {
-        Ok(rollback_mlua::Value::Number(self as u8 as f64))
+        Ok(rollback_mlua::Value::Number(self.0 as f64))
     }
 }
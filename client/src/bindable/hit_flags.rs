@@ -66,7 +66,8 @@ pub mod HitFlag {
             CONFUSE => Vec2::new(0.0, -height),
             BLIND => Vec2::new(0.0, -height),
             FREEZE => Vec2::new(0.0, -height * 0.5),
-            _ => Vec2::ZERO,
+            // statuses registered by packages display above the entity
+            _ => Vec2::new(0.0, -height),
         }
     }
 }
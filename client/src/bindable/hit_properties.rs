@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::*;
 
 #[derive(Clone, Copy)]
@@ -44,8 +45,9 @@ impl HitProperties {
             && self.flags & HitFlag::DRAG != HitFlag::NONE
     }
 
-    pub fn is_super_effective(&self, element: Element) -> bool {
-        element.is_weak_to(self.element) || element.is_weak_to(self.secondary_element)
+    pub fn is_super_effective(&self, element_chart: &ElementChart, element: Element) -> bool {
+        element_chart.is_weak_to(element, self.element)
+            || element_chart.is_weak_to(element, self.secondary_element)
     }
 }
 
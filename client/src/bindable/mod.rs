@@ -1,7 +1,9 @@
 mod action_lockout;
 mod animator_playback_mode;
+mod audio_priority;
 mod block_color;
 mod card_class;
+mod card_combo;
 mod card_properties;
 mod character_rank;
 mod component_lifetime;
@@ -24,11 +26,14 @@ mod sprite_color_mode;
 mod team;
 mod tile_highlight;
 mod tile_state;
+mod turn_mode;
 
 pub use action_lockout::*;
 pub use animator_playback_mode::*;
+pub use audio_priority::*;
 pub use block_color::*;
 pub use card_class::*;
+pub use card_combo::*;
 pub use card_properties::*;
 pub use character_rank::*;
 pub use component_lifetime::*;
@@ -51,3 +56,4 @@ pub use sprite_color_mode::*;
 pub use team::*;
 pub use tile_highlight::*;
 pub use tile_state::*;
+pub use turn_mode::*;
@@ -1,13 +1,56 @@
-use num_derive::FromPrimitive;
-
-#[repr(u8)]
-#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, FromPrimitive)]
+#[derive(Debug, PartialEq, Eq, Default, Clone, Copy, Hash)]
 pub enum Team {
     #[default]
     Unset,
     Other,
     Red,
     Blue,
+    /// Additional teams created by battle packages, numbered from 1
+    Custom(u8),
+}
+
+impl Team {
+    const CUSTOM_OFFSET: u8 = 3;
+
+    /// Returns None for 0 or numbers too large to be stored
+    pub fn custom(number: u8) -> Option<Self> {
+        if number == 0 || number > u8::MAX - Self::CUSTOM_OFFSET {
+            None
+        } else {
+            Some(Team::Custom(number))
+        }
+    }
+
+    /// Resolves teams sent by the server for netplay, 0 leaves the team to the battle package,
+    /// 1 and 2 are Red and Blue, and anything higher is a custom team
+    pub fn from_netplay_number(number: u8) -> Self {
+        match number {
+            0 => Team::Unset,
+            1 => Team::Red,
+            2 => Team::Blue,
+            _ => Team::custom(number - 2).unwrap_or_default(),
+        }
+    }
+
+    fn from_id(id: u8) -> Self {
+        match id {
+            0 => Team::Unset,
+            1 => Team::Other,
+            2 => Team::Red,
+            3 => Team::Blue,
+            _ => Team::Custom(id - Self::CUSTOM_OFFSET),
+        }
+    }
+
+    fn id(self) -> u8 {
+        match self {
+            Team::Unset => 0,
+            Team::Other => 1,
+            Team::Red => 2,
+            Team::Blue => 3,
+            Team::Custom(number) => number + Self::CUSTOM_OFFSET,
+        }
+    }
 }
 
 impl<'lua> rollback_mlua::FromLua<'lua> for Team {
@@ -15,10 +58,9 @@ impl<'lua> rollback_mlua::FromLua<'lua> for Team {
         lua_value: rollback_mlua::Value<'lua>,
         _lua: &'lua rollback_mlua::Lua,
     ) -> rollback_mlua::Result<Self> {
-        use num_traits::FromPrimitive;
-
         let number = match lua_value {
             rollback_mlua::Value::Number(number) => number,
+            rollback_mlua::Value::Integer(number) => number as f64,
             _ => {
                 return Err(rollback_mlua::Error::FromLuaConversionError {
                     from: lua_value.type_name(),
@@ -28,11 +70,15 @@ impl<'lua> rollback_mlua::FromLua<'lua> for Team {
             }
         };
 
-        Team::from_u8(number as u8).ok_or(rollback_mlua::Error::FromLuaConversionError {
-            from: lua_value.type_name(),
-            to: "Team",
-            message: None,
-        })
+        if number.fract() != 0.0 || !(0.0..=u8::MAX as f64).contains(&number) {
+            return Err(rollback_mlua::Error::FromLuaConversionError {
+                from: lua_value.type_name(),
+                to: "Team",
+                message: Some(format!("{number} is not a valid team")),
+            });
+        }
+
+        Ok(Team::from_id(number as u8))
     }
 }
 
@@ -41,6 +87,6 @@ impl<'lua> rollback_mlua::ToLua<'lua> for Team {
         self,
         _lua: &'lua rollback_mlua::Lua,
     ) -> rollback_mlua::Result<rollback_mlua::Value<'lua>> {
-        Ok(rollback_mlua::Value::Number(self as u8 as f64))
+        Ok(rollback_mlua::Value::Number(self.id() as f64))
     }
 }
@@ -0,0 +1,50 @@
+use num_derive::FromPrimitive;
+
+#[repr(u8)]
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, FromPrimitive)]
+pub enum TurnMode {
+    /// Players open custom with the end turn input once the gauge is full
+    #[default]
+    Classic,
+    /// Cards are drawn into the hand every time the gauge fills, custom is never opened
+    RealTime,
+    /// Custom opens automatically every time the gauge fills
+    AutoOpen,
+    /// Time stops until every player acts, custom is opened like the classic mode
+    TurnBased,
+}
+
+impl<'lua> rollback_mlua::FromLua<'lua> for TurnMode {
+    fn from_lua(
+        lua_value: rollback_mlua::Value<'lua>,
+        _lua: &'lua rollback_mlua::Lua,
+    ) -> rollback_mlua::Result<Self> {
+        use num_traits::FromPrimitive;
+
+        let number = match lua_value {
+            rollback_mlua::Value::Number(number) => number,
+            _ => {
+                return Err(rollback_mlua::Error::FromLuaConversionError {
+                    from: lua_value.type_name(),
+                    to: "TurnMode",
+                    message: None,
+                })
+            }
+        };
+
+        TurnMode::from_u8(number as u8).ok_or(rollback_mlua::Error::FromLuaConversionError {
+            from: lua_value.type_name(),
+            to: "TurnMode",
+            message: None,
+        })
+    }
+}
+
+impl<'lua> rollback_mlua::ToLua<'lua> for TurnMode {
+    fn to_lua(
+        self,
+        _lua: &'lua rollback_mlua::Lua,
+    ) -> rollback_mlua::Result<rollback_mlua::Value<'lua>> {
+        Ok(rollback_mlua::Value::Number(self as u8 as f64))
+    }
+}
@@ -1,3 +1,4 @@
+use super::errors::invalid_field_size;
 use super::field_api::get_field_table;
 use super::{create_entity_table, BattleLuaApi, BATTLE_INIT_TABLE, MUTATOR_TABLE, SPAWNER_TABLE};
 use crate::battle::{BattleScriptContext, Entity};
@@ -55,8 +56,8 @@ pub fn inject_battle_init_api(lua_api: &mut BattleLuaApi) {
             rollback_mlua::Table,
             [String; 3],
             String,
-            f32,
-            f32,
+            Option<f32>,
+            Option<f32>,
         ) = lua.unpack_multi(params)?;
 
         let [red_texture_path, blue_texture_path, other_texture_path] = texture_paths;
@@ -65,7 +66,9 @@ pub fn inject_battle_init_api(lua_api: &mut BattleLuaApi) {
         let blue_texture_path = absolute_path(lua, blue_texture_path)?;
         let other_texture_path = absolute_path(lua, other_texture_path)?;
         let animation_path = absolute_path(lua, animation_path)?;
-        let spacing = Vec2::new(spacing_x, spacing_y);
+
+        // spacing is read from the animation when unset
+        let spacing = spacing_x.zip(spacing_y).map(|(x, y)| Vec2::new(x, y));
 
         let api_ctx = &mut *api_ctx.borrow_mut();
         let game_io = api_ctx.game_io;
@@ -83,6 +86,24 @@ pub fn inject_battle_init_api(lua_api: &mut BattleLuaApi) {
         lua.pack_multi(())
     });
 
+    lua_api.add_dynamic_function(
+        BATTLE_INIT_TABLE,
+        "set_field_size",
+        |api_ctx, lua, params| {
+            let (_, cols, rows): (rollback_mlua::Table, usize, usize) = lua.unpack_multi(params)?;
+
+            // space for at least one tile per team, along with the hidden edge
+            if cols < 4 || rows < 3 {
+                return Err(invalid_field_size());
+            }
+
+            let mut api_ctx = api_ctx.borrow_mut();
+            api_ctx.simulation.resize_field(cols, rows);
+
+            lua.pack_multi(())
+        },
+    );
+
     lua_api.add_dynamic_function(
         BATTLE_INIT_TABLE,
         "set_background",
@@ -87,6 +87,10 @@ impl BattleLuaApi {
         super::sync_node_api::inject_sync_node_api(&mut lua_api);
         super::animation_api::inject_animation_api(&mut lua_api);
         super::defense_rule_api::inject_defense_rule_api(&mut lua_api);
+        super::status_api::inject_status_api(&mut lua_api);
+        super::element_api::inject_element_api(&mut lua_api);
+        super::time_freeze_api::inject_time_freeze_api(&mut lua_api);
+        super::scheduler_api::inject_scheduler_api(&mut lua_api);
         super::battle_init::inject_battle_init_api(&mut lua_api);
         super::built_in_api::inject_built_in_api(&mut lua_api);
 
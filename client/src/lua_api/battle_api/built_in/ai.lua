@@ -0,0 +1,271 @@
+-- nodes return true on success, false on failure, and nil while running
+-- state lives in the vm and randomness comes from math.random, so trees are safe to roll back
+
+local entity = ...
+
+local ai = {
+    entity = entity,
+}
+
+local function create_node(tick, reset)
+    return { tick = tick, reset = reset or function() end }
+end
+
+local function reset_nodes(nodes)
+    for _, node in ipairs(nodes) do
+        node.reset()
+    end
+end
+
+local function tile_position(other)
+    local tile = other:get_current_tile()
+    return tile:x(), tile:y()
+end
+
+local function distance(other)
+    local x, y = tile_position(entity)
+    local other_x, other_y = tile_position(other)
+
+    return math.abs(other_x - x) + math.abs(other_y - y)
+end
+
+-- runs children in order until one fails
+function ai:sequence(children)
+    local index = 1
+
+    local function reset()
+        index = 1
+        reset_nodes(children)
+    end
+
+    return create_node(function()
+        while index <= #children do
+            local status = children[index].tick()
+
+            if status == nil then
+                return nil
+            end
+
+            if not status then
+                reset()
+                return false
+            end
+
+            index = index + 1
+        end
+
+        reset()
+        return true
+    end, reset)
+end
+
+-- runs children in order until one succeeds
+function ai:selector(children)
+    local index = 1
+
+    local function reset()
+        index = 1
+        reset_nodes(children)
+    end
+
+    return create_node(function()
+        while index <= #children do
+            local status = children[index].tick()
+
+            if status == nil then
+                return nil
+            end
+
+            if status then
+                reset()
+                return true
+            end
+
+            index = index + 1
+        end
+
+        reset()
+        return false
+    end, reset)
+end
+
+function ai:condition(func)
+    return create_node(function()
+        return func(self) and true or false
+    end)
+end
+
+-- the function returns the status of the node
+function ai:action(func)
+    return create_node(function()
+        return func(self)
+    end)
+end
+
+-- frames can be a function to pick a new duration every time the wait starts
+function ai:wait(frames)
+    local elapsed = 0
+    local duration = 0
+
+    return create_node(function()
+        if elapsed == 0 then
+            duration = type(frames) == "function" and frames(self) or frames
+        end
+
+        elapsed = elapsed + 1
+
+        if elapsed < duration then
+            return nil
+        end
+
+        elapsed = 0
+        return true
+    end, function()
+        elapsed = 0
+    end)
+end
+
+-- returns the nearest character or player on another team
+function ai:find_target()
+    local field = entity:get_field()
+    local team = entity:get_team()
+
+    local function is_enemy(other)
+        return other:get_team() ~= team
+    end
+
+    local target = field:find_nearest_characters(entity, is_enemy)[1]
+    local player = field:find_nearest_players(entity, is_enemy)[1]
+
+    if not target or (player and distance(player) < distance(target)) then
+        target = player
+    end
+
+    return target
+end
+
+function ai:is_aligned(target)
+    local x, y = tile_position(entity)
+    local target_x, target_y = tile_position(target)
+
+    if target_y ~= y then
+        return false
+    end
+
+    if entity:get_facing() == Direction.Left then
+        return target_x < x
+    end
+
+    return target_x > x
+end
+
+function ai:face_nearest_enemy()
+    return create_node(function()
+        local target = self:find_target()
+
+        if not target then
+            return false
+        end
+
+        local x = tile_position(entity)
+        local target_x = tile_position(target)
+
+        if target_x < x then
+            entity:set_facing(Direction.Left)
+        elseif target_x > x then
+            entity:set_facing(Direction.Right)
+        end
+
+        return true
+    end)
+end
+
+-- options are passed to raw_move_event, along with an optional filter(tile) function
+function ai:move_to_random_tile(options)
+    options = options or {}
+
+    local moving = false
+
+    return create_node(function()
+        if moving then
+            if entity:is_moving() then
+                return nil
+            end
+
+            moving = false
+            return true
+        end
+
+        local x, y = tile_position(entity)
+
+        local tiles = entity:get_field():find_tiles(function(tile)
+            if tile:x() == x and tile:y() == y then
+                return false
+            end
+
+            if options.filter and not options.filter(tile) then
+                return false
+            end
+
+            return entity:can_move_to(tile)
+        end)
+
+        if #tiles == 0 then
+            return false
+        end
+
+        local movement = {
+            dest_tile = tiles[math.random(#tiles)],
+            delta_frames = options.delta_frames,
+            delay_frames = options.delay_frames,
+            endlag_frames = options.endlag_frames,
+            height = options.height,
+        }
+
+        if not entity:raw_move_event(movement) then
+            return false
+        end
+
+        moving = true
+        return nil
+    end, function()
+        moving = false
+    end)
+end
+
+-- fails when the nearest enemy is not in front of the entity, otherwise returns the status from func(ai, target)
+function ai:attack_when_aligned(func)
+    return create_node(function()
+        local target = self:find_target()
+
+        if not target or not self:is_aligned(target) then
+            return false
+        end
+
+        return func(self, target)
+    end)
+end
+
+function ai:set_tree(root)
+    if self.root then
+        self.root.reset()
+    end
+
+    self.root = root
+end
+
+-- call from the entity's update function
+function ai:update()
+    if not self.root or entity:is_deleted() then
+        return
+    end
+
+    local status = self.root.tick()
+
+    if status ~= nil then
+        -- start over once the tree completes
+        self.root.reset()
+    end
+end
+
+return ai
@@ -0,0 +1,117 @@
+local Test = {
+    _cases = {},
+    _steps = {},
+    _frame = 0,
+}
+
+local function describe(value)
+    if type(value) == "string" then
+        return "\"" .. value .. "\""
+    end
+
+    return tostring(value)
+end
+
+local function fail(message, default_message)
+    -- level 3 points at the line in the test that called the assertion
+    error(message or default_message, 3)
+end
+
+local function schedule(frame, func)
+    Test._steps[#Test._steps + 1] = { frame = frame, func = func }
+end
+
+local context = {}
+
+-- runs the function after the given amount of frames have been simulated
+function context:after(frames, func)
+    schedule(Test._frame + frames, func)
+end
+
+function context:frame()
+    return Test._frame
+end
+
+function Test.case(name, func)
+    Test._cases[#Test._cases + 1] = { name = name, func = func }
+end
+
+function Test.spawn_character(package_id, x, y, team, rank)
+    local character = Battle.Character.from_package(package_id, team or Team.Blue, rank or Rank.V1)
+    Test.field:spawn(character, x, y)
+    return character
+end
+
+function Test.assert(value, message)
+    if not value then
+        fail(message, "assertion failed")
+    end
+end
+
+function Test.assert_eq(actual, expected, message)
+    if actual ~= expected then
+        fail(message, "expected " .. describe(expected) .. ", got " .. describe(actual))
+    end
+end
+
+function Test.assert_health(entity, expected, message)
+    local health = entity:get_health()
+
+    if health ~= expected then
+        fail(message, "expected " .. expected .. " health, got " .. health)
+    end
+end
+
+function Test.assert_status(entity, status, message)
+    if not entity:has_status(status) then
+        fail(message, "expected status " .. describe(status))
+    end
+end
+
+function Test.assert_no_status(entity, status, message)
+    if entity:has_status(status) then
+        fail(message, "unexpected status " .. describe(status))
+    end
+end
+
+function Test.assert_tile_state(x, y, expected, message)
+    local tile = Test.field:tile_at(x, y)
+    local state = tile:get_state()
+
+    if state ~= expected then
+        fail(message, "expected tile state " .. describe(expected) .. " at (" .. x .. ", " .. y .. "), got " .. describe(state))
+    end
+end
+
+function Test._start(index)
+    Test._steps = {}
+    Test._frame = 0
+
+    schedule(0, Test._cases[index].func)
+end
+
+-- returns true once every step has run
+function Test._tick()
+    local due = {}
+    local remaining = {}
+
+    for _, step in ipairs(Test._steps) do
+        if step.frame <= Test._frame then
+            due[#due + 1] = step
+        else
+            remaining[#remaining + 1] = step
+        end
+    end
+
+    Test._steps = remaining
+
+    for _, step in ipairs(due) do
+        step.func(context)
+    end
+
+    Test._frame = Test._frame + 1
+
+    return #Test._steps == 0
+end
+
+return Test
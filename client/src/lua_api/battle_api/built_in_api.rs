@@ -5,7 +5,9 @@ use crate::{
     render::FrameTime,
 };
 
-use super::{BattleLuaApi, BUSTER_TABLE, HITBOX_TABLE, SHARED_HITBOX_TABLE, VIRUS_DEFENSE_TABLE};
+use super::{
+    BattleLuaApi, AI_TABLE, BUSTER_TABLE, HITBOX_TABLE, SHARED_HITBOX_TABLE, VIRUS_DEFENSE_TABLE,
+};
 
 macro_rules! built_in {
     ($lua_api:expr, $file_name:literal, $table_name:expr, $function_name:literal) => {{
@@ -33,6 +35,7 @@ pub fn inject_built_in_api(lua_api: &mut BattleLuaApi) {
     built_in!(lua_api, "buster", BUSTER_TABLE, "new");
     built_in!(lua_api, "defense_virus_body", VIRUS_DEFENSE_TABLE, "new");
     built_in!(lua_api, "hitbox", HITBOX_TABLE, "new");
+    built_in!(lua_api, "ai", AI_TABLE, "new");
 
     lua_api.add_dynamic_function(SHARED_HITBOX_TABLE, "new", |api_ctx, lua, params| {
         let (entity_table, lifetime): (rollback_mlua::Table, Option<FrameTime>) =
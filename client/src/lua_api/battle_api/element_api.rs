@@ -0,0 +1,165 @@
+use super::errors::{element_not_registered, too_many_elements};
+use super::{BattleLuaApi, ELEMENT_TABLE};
+use crate::battle::{ElementChart, ElementDefinition};
+use crate::bindable::{Element, TileState};
+use crate::lua_api::helpers::absolute_path;
+
+pub fn inject_element_api(lua_api: &mut BattleLuaApi) {
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "register", |api_ctx, lua, params| {
+        let (name, table): (String, Option<rollback_mlua::Table>) = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let element_chart = &mut api_ctx.simulation.element_chart;
+
+        // every vm registers its own copy, the first registration wins
+        if let Some(definition) = element_chart.definition_by_name(&name) {
+            return lua.pack_multi(definition.element);
+        }
+
+        let mut definition = ElementDefinition::new(&name, Element::None);
+        let mut weak_to: Vec<Element> = Vec::new();
+        let mut strong_against: Vec<Element> = Vec::new();
+
+        if let Some(table) = table {
+            if let Some(texture_path) = table.get::<_, Option<String>>("texture_path")? {
+                definition.icon_texture_path = Some(absolute_path(lua, texture_path)?);
+            }
+
+            if let Some(multiplier) = table.get::<_, Option<f32>>("super_effective_multiplier")? {
+                definition.super_effective_multiplier = multiplier;
+            }
+
+            weak_to = table.get("weak_to").unwrap_or_default();
+            strong_against = table.get("strong_against").unwrap_or_default();
+
+            validate_elements(element_chart, &weak_to)?;
+            validate_elements(element_chart, &strong_against)?;
+        }
+
+        let element = element_chart
+            .register(definition)
+            .ok_or_else(too_many_elements)?;
+
+        for other_element in weak_to {
+            element_chart.add_weakness(element, other_element);
+        }
+
+        for other_element in strong_against {
+            element_chart.add_weakness(other_element, element);
+        }
+
+        lua.pack_multi(element)
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "get", |api_ctx, lua, params| {
+        let name: String = lua.unpack_multi(params)?;
+
+        let api_ctx = &*api_ctx.borrow();
+        let element_chart = &api_ctx.simulation.element_chart;
+        let element = element_chart
+            .definition_by_name(&name)
+            .map(|definition| definition.element);
+
+        lua.pack_multi(element)
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "add_weakness", |api_ctx, lua, params| {
+        let (element, weak_to): (Element, Element) = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        let element_chart = &mut api_ctx.simulation.element_chart;
+
+        validate_elements(element_chart, &[element, weak_to])?;
+        element_chart.add_weakness(element, weak_to);
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "remove_weakness", |api_ctx, lua, params| {
+        let (element, weak_to): (Element, Element) = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        let element_chart = &mut api_ctx.simulation.element_chart;
+
+        validate_elements(element_chart, &[element, weak_to])?;
+        element_chart.remove_weakness(element, weak_to);
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "clear_weaknesses", |api_ctx, lua, _| {
+        let mut api_ctx = api_ctx.borrow_mut();
+        api_ctx.simulation.element_chart.clear_weaknesses();
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "is_weak_to", |api_ctx, lua, params| {
+        let (element, other_element): (Element, Element) = lua.unpack_multi(params)?;
+
+        let api_ctx = &*api_ctx.borrow();
+        let element_chart = &api_ctx.simulation.element_chart;
+
+        validate_elements(element_chart, &[element, other_element])?;
+
+        lua.pack_multi(element_chart.is_weak_to(element, other_element))
+    });
+
+    lua_api.add_dynamic_function(
+        ELEMENT_TABLE,
+        "set_super_effective_multiplier",
+        |api_ctx, lua, params| {
+            let (element, multiplier): (Element, f32) = lua.unpack_multi(params)?;
+
+            let mut api_ctx = api_ctx.borrow_mut();
+            let element_chart = &mut api_ctx.simulation.element_chart;
+
+            validate_elements(element_chart, &[element])?;
+
+            if let Some(definition) = element_chart.definition_mut(element) {
+                definition.super_effective_multiplier = multiplier;
+            }
+
+            lua.pack_multi(())
+        },
+    );
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "add_wash", |api_ctx, lua, params| {
+        let (state, element): (TileState, Element) = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        let element_chart = &mut api_ctx.simulation.element_chart;
+
+        validate_elements(element_chart, &[element])?;
+        element_chart.add_wash(state, element);
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ELEMENT_TABLE, "remove_wash", |api_ctx, lua, params| {
+        let (state, element): (TileState, Element) = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        let element_chart = &mut api_ctx.simulation.element_chart;
+
+        validate_elements(element_chart, &[element])?;
+        element_chart.remove_wash(state, element);
+
+        lua.pack_multi(())
+    });
+}
+
+/// Element ids from scripts are only valid once registered in this battle's chart
+pub(super) fn validate_elements(
+    element_chart: &ElementChart,
+    elements: &[Element],
+) -> rollback_mlua::Result<()> {
+    if elements
+        .iter()
+        .all(|element| element_chart.is_registered(*element))
+    {
+        Ok(())
+    } else {
+        Err(element_not_registered())
+    }
+}
@@ -1,8 +1,10 @@
 use super::{BattleLuaApi, ENGINE_TABLE};
 use crate::battle::TurnGauge;
+use crate::bindable::{AudioPriority, TurnMode};
 use crate::lua_api::helpers::absolute_path;
 use crate::render::*;
-use crate::resources::AssetManager;
+use crate::resources::{AssetManager, LoopPoints, SoundOptions};
+use std::time::Duration;
 
 pub fn inject_engine_api(lua_api: &mut BattleLuaApi) {
     lua_api.add_dynamic_function(ENGINE_TABLE, "load_texture", |api_ctx, lua, params| {
@@ -29,30 +31,59 @@ pub fn inject_engine_api(lua_api: &mut BattleLuaApi) {
     });
 
     lua_api.add_dynamic_function(ENGINE_TABLE, "play_audio", |api_ctx, lua, params| {
-        let path: String = lua.unpack_multi(params)?; // todo: (path, AudioPriority)?
+        let (path, priority, volume, pitch): (
+            String,
+            Option<AudioPriority>,
+            Option<f32>,
+            Option<f32>,
+        ) = lua.unpack_multi(params)?;
+
         let path = absolute_path(lua, path)?;
 
+        let options = SoundOptions {
+            priority: priority.unwrap_or_default(),
+            volume: volume.unwrap_or(1.0),
+            pitch: pitch.unwrap_or(1.0),
+        };
+
         let api_ctx = api_ctx.borrow();
         let game_io = &api_ctx.game_io;
         let simulation = &api_ctx.simulation;
 
         let sound_buffer = game_io.globals().assets.audio(&path);
-        simulation.play_sound(game_io, &sound_buffer);
+        simulation.play_sound_with_options(game_io, &sound_buffer, options);
 
         lua.pack_multi(())
     });
 
     lua_api.add_dynamic_function(ENGINE_TABLE, "stream_music", |api_ctx, lua, params| {
-        // todo: loop points
-        let (path, loops, _start_ms, _end_ms): (String, Option<bool>, Option<u64>, Option<u64>) =
-            lua.unpack_multi(params)?;
+        let (path, loops, start_ms, end_ms, fade_ms): (
+            String,
+            Option<bool>,
+            Option<u64>,
+            Option<u64>,
+            Option<u64>,
+        ) = lua.unpack_multi(params)?;
 
-        let loops = loops.unwrap_or(true);
+        let loop_points = loops.unwrap_or(true).then(|| LoopPoints {
+            start_ms: start_ms.unwrap_or_default(),
+            end_ms: end_ms.unwrap_or_default(),
+        });
+
+        let fade_duration = Duration::from_millis(fade_ms.unwrap_or_default());
 
         let api_ctx = api_ctx.borrow();
         let globals = api_ctx.game_io.globals();
         let sound_buffer = globals.assets.audio(&path);
-        globals.audio.play_music(sound_buffer, loops);
+        let audio = &globals.audio;
+        audio.crossfade_music(sound_buffer, loop_points, fade_duration);
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ENGINE_TABLE, "stop_music", |api_ctx, lua, _| {
+        let api_ctx = api_ctx.borrow();
+        api_ctx.game_io.globals().audio.stop_music();
 
         lua.pack_multi(())
     });
@@ -116,4 +147,17 @@ pub fn inject_engine_api(lua_api: &mut BattleLuaApi) {
             lua.pack_multi(())
         },
     );
+
+    lua_api.add_dynamic_function(ENGINE_TABLE, "get_turn_mode", |api_ctx, lua, _| {
+        lua.pack_multi(api_ctx.borrow().simulation.turn_gauge.mode())
+    });
+
+    lua_api.add_dynamic_function(ENGINE_TABLE, "set_turn_mode", |api_ctx, lua, params| {
+        let mode: TurnMode = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        api_ctx.simulation.turn_gauge.set_mode(mode);
+
+        lua.pack_multi(())
+    });
 }
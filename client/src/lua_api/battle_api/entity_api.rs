@@ -1,7 +1,9 @@
 use super::animation_api::create_animation_table;
+use super::element_api::validate_elements;
 use super::errors::action_aready_used;
 use super::errors::card_action_not_found;
 use super::errors::entity_not_found;
+use super::errors::invalid_emotion;
 use super::errors::invalid_sync_node;
 use super::errors::mismatched_entity;
 use super::errors::too_many_forms;
@@ -62,9 +64,24 @@ pub fn inject_entity_api(lua_api: &mut BattleLuaApi) {
     getter(lua_api, "get_element", |entity: &Entity, lua, _: ()| {
         lua.pack_multi(entity.element)
     });
-    setter(lua_api, "set_element", |entity: &mut Entity, _, element| {
+    lua_api.add_dynamic_function(ENTITY_TABLE, "set_element", |api_ctx, lua, params| {
+        let (table, element): (rollback_mlua::Table, Element) = lua.unpack_multi(params)?;
+
+        let id: EntityID = table.raw_get("#id")?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let simulation = &mut api_ctx.simulation;
+
+        validate_elements(&simulation.element_chart, &[element])?;
+
+        let entity = simulation
+            .entities
+            .query_one_mut::<&mut Entity>(id.into())
+            .map_err(|_| entity_not_found())?;
+
         entity.element = element;
-        Ok(())
+
+        lua.pack_multi(())
     });
 
     lua_api.add_dynamic_function(ENTITY_TABLE, "get_tile", |api_ctx, lua, params| {
@@ -405,9 +422,30 @@ pub fn inject_entity_api(lua_api: &mut BattleLuaApi) {
         lua.pack_multi(())
     });
 
-    // no idea if people use these:
-    // todo: get_base_palette
-    // todo: store_base_palette why is this not just set_base_palette?
+    getter(
+        lua_api,
+        "get_base_palette",
+        |entity: &Entity, lua, _: ()| lua.pack_multi(entity.sprite_tree.root().base_palette_path()),
+    );
+
+    lua_api.add_dynamic_function(ENTITY_TABLE, "set_base_palette", |api_ctx, lua, params| {
+        let (table, path): (rollback_mlua::Table, String) = lua.unpack_multi(params)?;
+        let path = absolute_path(lua, path)?;
+
+        let id: EntityID = table.raw_get("#id")?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let entities = &mut api_ctx.simulation.entities;
+
+        let entity = entities
+            .query_one_mut::<&mut Entity>(id.into())
+            .map_err(|_| entity_not_found())?;
+
+        let sprite_node = entity.sprite_tree.root_mut();
+        sprite_node.set_base_palette_path(path);
+
+        lua.pack_multi(())
+    });
 
     lua_api.add_dynamic_function(ENTITY_TABLE, "get_animation", |api_ctx, lua, params| {
         let table: rollback_mlua::Table = lua.unpack_multi(params)?;
@@ -640,7 +678,32 @@ pub fn inject_entity_api(lua_api: &mut BattleLuaApi) {
         },
     );
 
-    // todo: has_status?
+    getter(
+        lua_api,
+        "has_status",
+        |living: &Living, lua, hit_flag: HitFlags| {
+            let remaining_time = living.status_director.remaining_status_time(hit_flag);
+            lua.pack_multi(remaining_time > 0)
+        },
+    );
+
+    setter(
+        lua_api,
+        "apply_status",
+        |living: &mut Living, _, (hit_flag, duration): (HitFlags, FrameTime)| {
+            living.status_director.apply_status(hit_flag, duration);
+            Ok(())
+        },
+    );
+
+    setter(
+        lua_api,
+        "remove_status",
+        |living: &mut Living, _, hit_flag: HitFlags| {
+            living.status_director.remove_status(hit_flag);
+            Ok(())
+        },
+    );
 
     lua_api.add_dynamic_function(ENTITY_TABLE, "create_component", |api_ctx, lua, params| {
         let (entity_table, lifetime): (rollback_mlua::Table, ComponentLifetime) =
@@ -770,7 +833,14 @@ fn inject_character_api(lua_api: &mut BattleLuaApi) {
         lua.pack_multi(create_entity_table(lua, id))
     });
 
-    // todo: get_held_card_props
+    getter(
+        lua_api,
+        "get_held_card_props",
+        |character: &Character, lua, _: ()| {
+            // cards are used from the end of the list
+            lua.pack_multi(character.cards.last())
+        },
+    );
 
     // todo: can_attack, maybe better inverted and described as is_idle?
     // nothing is stoping a card action from being queued other than another card action
@@ -881,7 +951,36 @@ fn inject_living_api(lua_api: &mut BattleLuaApi) {
             Ok(())
         },
     );
-    // todo: set_counter_frame_range
+    lua_api.add_dynamic_function(
+        ENTITY_TABLE,
+        "set_counter_frame_range",
+        |api_ctx, lua, params| {
+            let (table, start_frame, end_frame): (rollback_mlua::Table, usize, usize) =
+                lua.unpack_multi(params)?;
+
+            let id: EntityID = table.raw_get("#id")?;
+
+            let api_ctx = &mut *api_ctx.borrow_mut();
+            let simulation = &mut api_ctx.simulation;
+            let entities = &mut simulation.entities;
+
+            let entity = entities
+                .query_one_mut::<&Entity>(id.into())
+                .map_err(|_| entity_not_found())?;
+
+            // the window only lasts for the current animation state
+            let animator = &mut simulation.animators[entity.animator_index];
+            let enable_callback = counter_toggle_callback(id, true);
+            let disable_callback = counter_toggle_callback(id, false);
+
+            animator.on_frame(start_frame.max(1) - 1, enable_callback, true);
+            animator.on_frame(end_frame.max(1), disable_callback.clone(), true);
+            animator.on_interrupt(disable_callback.clone());
+            animator.on_complete(disable_callback);
+
+            lua.pack_multi(())
+        },
+    );
 
     getter(lua_api, "is_intangible", |living: &Living, lua, _: ()| {
         lua.pack_multi(living.intangibility.is_enabled())
@@ -1016,8 +1115,134 @@ fn inject_player_api(lua_api: &mut BattleLuaApi) {
         },
     );
 
-    // todo: mod_max_health
-    // todo: get_max_health_mod
+    lua_api.add_dynamic_function(ENTITY_TABLE, "mod_max_health", |api_ctx, lua, params| {
+        let (table, amount): (rollback_mlua::Table, i32) = lua.unpack_multi(params)?;
+
+        let id: EntityID = table.raw_get("#id")?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let entities = &mut api_ctx.simulation.entities;
+
+        let (player, living) = entities
+            .query_one_mut::<(&mut Player, &mut Living)>(id.into())
+            .map_err(|_| entity_not_found())?;
+
+        let previous_max_health = living.max_health;
+        living.max_health = (living.max_health + amount).max(1);
+
+        let applied_amount = living.max_health - previous_max_health;
+        player.modded_hp += applied_amount;
+        living.set_health(living.health + applied_amount);
+
+        lua.pack_multi(())
+    });
+
+    getter(
+        lua_api,
+        "get_max_health_mod",
+        |player: &Player, lua, _: ()| lua.pack_multi(player.modded_hp),
+    );
+
+    getter(lua_api, "get_emotion", |player: &Player, lua, _: ()| {
+        lua.pack_multi(player.emotion_id())
+    });
+
+    lua_api.add_dynamic_function(ENTITY_TABLE, "set_emotion", |api_ctx, lua, params| {
+        let (table, emotion_id): (rollback_mlua::Table, usize) = lua.unpack_multi(params)?;
+
+        let id: EntityID = table.raw_get("#id")?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let simulation = &mut api_ctx.simulation;
+
+        (simulation.entities)
+            .query_one_mut::<&Player>(id.into())
+            .map_err(|_| entity_not_found())?;
+
+        if !Player::set_emotion(simulation, id, emotion_id) {
+            return Err(invalid_emotion());
+        }
+
+        lua.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(ENTITY_TABLE, "register_emotion", |api_ctx, lua, params| {
+        let (table, name, emotion_table): (
+            rollback_mlua::Table,
+            String,
+            Option<rollback_mlua::Table>,
+        ) = lua.unpack_multi(params)?;
+
+        let id: EntityID = table.raw_get("#id")?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let game_io = api_ctx.game_io;
+        let assets = &game_io.globals().assets;
+
+        let mut emotion = CustomEmotion::new(name);
+
+        if let Some(emotion_table) = emotion_table {
+            if let Some(base_emotion) = emotion_table.get::<_, Option<u8>>("base_emotion")? {
+                use num_traits::FromPrimitive;
+                let base_emotion = Emotion::from_u8(base_emotion).ok_or_else(invalid_emotion)?;
+                emotion.base_emotion = base_emotion;
+            }
+
+            if let Some(multiplier) = emotion_table.get::<_, Option<i32>>("damage_multiplier")? {
+                emotion.damage_multiplier = multiplier;
+            }
+
+            if let Some(path) = emotion_table.get::<_, Option<String>>("texture_path")? {
+                let path = absolute_path(lua, path)?;
+                emotion.texture = Some(assets.texture(game_io, &path));
+            }
+
+            if let Some(path) = emotion_table.get::<_, Option<String>>("mugshot_texture_path")? {
+                let path = absolute_path(lua, path)?;
+                emotion.mug_texture = Some(assets.texture(game_io, &path));
+            }
+
+            let create_callback = |callback| {
+                BattleCallback::new_transformed_lua_callback(
+                    lua,
+                    api_ctx.vm_index,
+                    callback,
+                    move |_, lua, _| lua.pack_multi(create_entity_table(lua, id)?),
+                )
+            };
+
+            if let Some(callback) = emotion_table.get("on_activate_func")? {
+                emotion.activate_callback = Some(create_callback(callback)?);
+            }
+
+            if let Some(callback) = emotion_table.get("on_deactivate_func")? {
+                emotion.deactivate_callback = Some(create_callback(callback)?);
+            }
+        }
+
+        let entities = &mut api_ctx.simulation.entities;
+        let player = entities
+            .query_one_mut::<&mut Player>(id.into())
+            .map_err(|_| entity_not_found())?;
+
+        // registering an existing name replaces the emotion
+        let existing_index = (player.custom_emotions)
+            .iter()
+            .position(|existing| existing.name == emotion.name);
+
+        let index = match existing_index {
+            Some(index) => {
+                player.custom_emotions[index] = emotion;
+                index
+            }
+            None => {
+                player.custom_emotions.push(emotion);
+                player.custom_emotions.len() - 1
+            }
+        };
+
+        lua.pack_multi(Player::CUSTOM_EMOTION_START + index)
+    });
 
     lua_api.add_dynamic_function(ENTITY_TABLE, "create_form", |api_ctx, lua, params| {
         let table: rollback_mlua::Table = lua.unpack_multi(params)?;
@@ -1310,6 +1535,14 @@ fn attempt_movement<'lua>(
     lua.pack_multi(true)
 }
 
+fn counter_toggle_callback(id: EntityID, counterable: bool) -> BattleCallback {
+    BattleCallback::new(move |_, simulation, _, _| {
+        if let Ok(living) = simulation.entities.query_one_mut::<&mut Living>(id.into()) {
+            living.counterable = counterable;
+        }
+    })
+}
+
 fn generate_constructor_fn<F>(lua_api: &mut BattleLuaApi, table_name: &str, constructor: F)
 where
     F: Fn(&mut BattleScriptContext) -> rollback_mlua::Result<EntityID> + 'static,
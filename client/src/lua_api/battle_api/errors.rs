@@ -10,6 +10,18 @@ pub fn too_many_forms() -> rollback_mlua::Error {
     rollback_mlua::Error::RuntimeError(String::from("too many forms"))
 }
 
+pub fn too_many_statuses() -> rollback_mlua::Error {
+    rollback_mlua::Error::RuntimeError(String::from("too many statuses"))
+}
+
+pub fn too_many_elements() -> rollback_mlua::Error {
+    rollback_mlua::Error::RuntimeError(String::from("too many elements"))
+}
+
+pub fn element_not_registered() -> rollback_mlua::Error {
+    rollback_mlua::Error::RuntimeError(String::from("element not registered"))
+}
+
 pub fn sprite_not_found() -> rollback_mlua::Error {
     rollback_mlua::Error::RuntimeError(String::from("sprite deleted"))
 }
@@ -49,3 +61,11 @@ pub fn invalid_tile() -> rollback_mlua::Error {
 pub fn mismatched_entity() -> rollback_mlua::Error {
     rollback_mlua::Error::RuntimeError(String::from("mismatched entity"))
 }
+
+pub fn invalid_emotion() -> rollback_mlua::Error {
+    rollback_mlua::Error::RuntimeError(String::from("invalid emotion"))
+}
+
+pub fn invalid_field_size() -> rollback_mlua::Error {
+    rollback_mlua::Error::RuntimeError(String::from("field size too small"))
+}
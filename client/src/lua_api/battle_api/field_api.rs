@@ -5,8 +5,9 @@ use super::tile_api::create_tile_table;
 use super::{create_entity_table, BattleLuaApi, FIELD_TABLE};
 use crate::battle::{
     BattleCallback, BattleScriptContext, Character, Entity, Living, Obstacle, Player, Spell,
+    TeamRegion,
 };
-use crate::bindable::EntityID;
+use crate::bindable::{Direction, EntityID, Team};
 use std::cell::RefCell;
 
 pub fn inject_field_api(lua_api: &mut BattleLuaApi) {
@@ -34,6 +35,30 @@ pub fn inject_field_api(lua_api: &mut BattleLuaApi) {
         lua.pack_multi(api_ctx.simulation.field.rows())
     });
 
+    lua_api.add_dynamic_function(FIELD_TABLE, "set_team_region", |api_ctx, lua, params| {
+        let (_, x, y, width, height, rest): (
+            rollback_mlua::Table,
+            i32,
+            i32,
+            i32,
+            i32,
+            rollback_mlua::MultiValue,
+        ) = lua.unpack_multi(params)?;
+
+        let (team, direction): (Team, Option<Direction>) = lua.unpack_multi(rest)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+
+        api_ctx.simulation.field.set_team_region(TeamRegion {
+            team,
+            position: (x, y),
+            size: (width, height),
+            direction,
+        });
+
+        lua.pack_multi(())
+    });
+
     lua_api.add_dynamic_function(FIELD_TABLE, "spawn", |api_ctx, lua, params| {
         let (_, entity_table, rest): (
             rollback_mlua::Table,
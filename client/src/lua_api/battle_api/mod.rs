@@ -5,6 +5,7 @@ mod built_in_api;
 mod card_action_api;
 mod component_api;
 mod defense_rule_api;
+mod element_api;
 mod engine_api;
 mod entity_api;
 mod errors;
@@ -12,13 +13,18 @@ mod field_api;
 mod include_api;
 mod math_api;
 mod player_form_api;
+mod scheduler_api;
 mod sprite_api;
+mod status_api;
 mod sync_node_api;
+mod test_api;
 mod tile_api;
+mod time_freeze_api;
 
 pub use battle_init::battle_init;
 pub use battle_lua_api::*;
 pub use entity_api::create_entity_table;
+pub use test_api::inject_test_api;
 
 // tables
 pub const GLOBAL_TABLE: &str = "_G";
@@ -32,6 +38,10 @@ pub const SPAWNER_TABLE: &str = "Battle.MobSpawner";
 pub const MUTATOR_TABLE: &str = "Battle.Mutator";
 pub const DEFENSE_RULE_TABLE: &str = "Battle.DefenseRule";
 pub const DEFENSE_JUDGE_TABLE: &str = "Battle.DefenseJudge";
+pub const STATUS_TABLE: &str = "Battle.Status";
+pub const ELEMENT_TABLE: &str = "Battle.Element";
+pub const TIME_FREEZE_TABLE: &str = "Battle.TimeFreeze";
+pub const SCHEDULER_TABLE: &str = "Battle.Scheduler";
 pub const CARD_PROPERTIES_TABLE: &str = "Battle.CardProperties";
 pub const CARD_ACTION_TABLE: &str = "Battle.CardAction";
 pub const STEP_TABLE: &str = "Battle.Step";
@@ -50,6 +60,7 @@ pub const EXPLOSION_TABLE: &str = "Battle.Explosion";
 pub const BUSTER_TABLE: &str = "Battle.Buster";
 pub const VIRUS_DEFENSE_TABLE: &str = "Battle.DefenseVirusBody";
 pub const HITBOX_TABLE: &str = "Battle.Hitbox";
+pub const AI_TABLE: &str = "Battle.AI";
 pub const SHARED_HITBOX_TABLE: &str = "Battle.SharedHitbox";
 
 // callbacks
@@ -0,0 +1,70 @@
+use super::{BattleLuaApi, SCHEDULER_TABLE};
+use crate::battle::BattleCallback;
+use crate::bindable::EntityID;
+use crate::render::FrameTime;
+
+pub fn inject_scheduler_api(lua_api: &mut BattleLuaApi) {
+    lua_api.add_dynamic_function(SCHEDULER_TABLE, "after", |api_ctx, lua, params| {
+        let (frames, callback, owner_table): (
+            FrameTime,
+            rollback_mlua::Function,
+            Option<rollback_mlua::Table>,
+        ) = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let owner = owner_id(owner_table)?;
+        let callback = BattleCallback::new_lua_callback(lua, api_ctx.vm_index, callback)?;
+
+        let scheduler = &mut api_ctx.simulation.scheduler;
+
+        lua.pack_multi(scheduler.after(owner, frames, callback))
+    });
+
+    lua_api.add_dynamic_function(SCHEDULER_TABLE, "every", |api_ctx, lua, params| {
+        let (frames, callback, owner_table): (
+            FrameTime,
+            rollback_mlua::Function,
+            Option<rollback_mlua::Table>,
+        ) = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let owner = owner_id(owner_table)?;
+        let callback = BattleCallback::new_lua_callback(lua, api_ctx.vm_index, callback)?;
+
+        let scheduler = &mut api_ctx.simulation.scheduler;
+
+        lua.pack_multi(scheduler.every(owner, frames, callback))
+    });
+
+    // `until` is reserved in lua
+    lua_api.add_dynamic_function(SCHEDULER_TABLE, "wait_until", |api_ctx, lua, params| {
+        let (condition, callback, owner_table): (
+            rollback_mlua::Function,
+            rollback_mlua::Function,
+            Option<rollback_mlua::Table>,
+        ) = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let vm_index = api_ctx.vm_index;
+        let owner = owner_id(owner_table)?;
+        let condition = BattleCallback::new_lua_callback(lua, vm_index, condition)?;
+        let callback = BattleCallback::new_lua_callback(lua, vm_index, callback)?;
+
+        let scheduler = &mut api_ctx.simulation.scheduler;
+
+        lua.pack_multi(scheduler.until(owner, condition, callback))
+    });
+
+    lua_api.add_dynamic_function(SCHEDULER_TABLE, "cancel", |api_ctx, lua, params| {
+        let id: usize = lua.unpack_multi(params)?;
+
+        let mut api_ctx = api_ctx.borrow_mut();
+        api_ctx.simulation.scheduler.cancel(id);
+
+        lua.pack_multi(())
+    });
+}
+
+fn owner_id(owner_table: Option<rollback_mlua::Table>) -> rollback_mlua::Result<Option<EntityID>> {
+    owner_table.map(|table| table.raw_get("#id")).transpose()
+}
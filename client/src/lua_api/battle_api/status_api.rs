@@ -0,0 +1,98 @@
+use super::create_entity_table;
+use super::errors::too_many_statuses;
+use super::{BattleLuaApi, STATUS_TABLE, UPDATE_FN};
+use crate::battle::{BattleCallback, StatusDefinition, StatusSprite};
+use crate::bindable::{EntityID, HitFlag, HitFlags};
+use crate::lua_api::helpers::absolute_path;
+use crate::render::FrameTime;
+
+pub fn inject_status_api(lua_api: &mut BattleLuaApi) {
+    lua_api.add_dynamic_function(STATUS_TABLE, "register", |api_ctx, lua, params| {
+        let (name, table): (String, rollback_mlua::Table) = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+        let status_registry = &mut api_ctx.simulation.status_registry;
+
+        // every vm registers its own copy, the first registration wins
+        if let Some(status) = status_registry.status_by_name(&name) {
+            return lua.pack_multi(status.flag);
+        }
+
+        let mut definition = StatusDefinition::new(&name, HitFlag::NONE);
+
+        if let Some(duration) = table.get::<_, Option<FrameTime>>("duration")? {
+            definition.duration = duration;
+        }
+
+        definition.mashable = table
+            .get::<_, Option<bool>>("mashable")?
+            .unwrap_or_default();
+        definition.inactionable = table
+            .get::<_, Option<bool>>("inactionable")?
+            .unwrap_or_default();
+        definition.immobile = table
+            .get::<_, Option<bool>>("immobile")?
+            .unwrap_or_default();
+
+        let blocks: HitFlags = table
+            .get::<_, Option<HitFlags>>("blocks")?
+            .unwrap_or_default();
+        let blocked_by: HitFlags = table
+            .get::<_, Option<HitFlags>>("blocked_by")?
+            .unwrap_or_default();
+
+        let texture_path: Option<String> = table.get("texture_path")?;
+        let animation_path: Option<String> = table.get("animation_path")?;
+
+        if let (Some(texture_path), Some(animation_path)) = (texture_path, animation_path) {
+            definition.sprite = Some(StatusSprite {
+                texture_path: absolute_path(lua, texture_path)?,
+                animation_path: absolute_path(lua, animation_path)?,
+                state: table
+                    .get::<_, Option<String>>("animation_state")?
+                    .unwrap_or_else(|| String::from("DEFAULT")),
+            });
+        }
+
+        if let Some(callback) = table.get::<_, Option<rollback_mlua::Function>>(UPDATE_FN)? {
+            definition.update_callback = Some(BattleCallback::new_transformed_lua_callback(
+                lua,
+                api_ctx.vm_index,
+                callback,
+                |_, lua, id: EntityID| lua.pack_multi(create_entity_table(lua, id)?),
+            )?);
+        }
+
+        let flag = status_registry
+            .register(definition)
+            .ok_or_else(too_many_statuses)?;
+
+        for other_flag in split_flags(blocks) {
+            status_registry.add_blocker(flag, other_flag);
+        }
+
+        for other_flag in split_flags(blocked_by) {
+            status_registry.add_blocker(other_flag, flag);
+        }
+
+        lua.pack_multi(flag)
+    });
+
+    lua_api.add_dynamic_function(STATUS_TABLE, "get", |api_ctx, lua, params| {
+        let name: String = lua.unpack_multi(params)?;
+
+        let api_ctx = &*api_ctx.borrow();
+        let status_registry = &api_ctx.simulation.status_registry;
+        let flag = status_registry
+            .status_by_name(&name)
+            .map(|status| status.flag);
+
+        lua.pack_multi(flag)
+    });
+}
+
+fn split_flags(flags: HitFlags) -> impl Iterator<Item = HitFlags> {
+    (0..HitFlags::BITS)
+        .map(|i| 1 << i)
+        .filter(move |flag| flags & flag != HitFlag::NONE)
+}
@@ -0,0 +1,16 @@
+use super::create_entity_table;
+use super::field_api::get_field_table;
+use crate::bindable::EntityID;
+
+/// Sets the `Test` global used by scripts in a package's `tests` folder
+pub fn inject_test_api(lua: &rollback_mlua::Lua, player_id: EntityID) -> rollback_mlua::Result<()> {
+    let test_table: rollback_mlua::Table = lua
+        .load(include_str!("built_in/test.lua"))
+        .set_name("built_in/test.lua")?
+        .eval()?;
+
+    test_table.set("field", get_field_table(lua)?)?;
+    test_table.set("player", create_entity_table(lua, player_id)?)?;
+
+    lua.globals().set("Test", test_table)
+}
@@ -0,0 +1,106 @@
+use super::create_entity_table;
+use super::{BattleLuaApi, TIME_FREEZE_TABLE};
+use crate::battle::BattleCallback;
+use crate::bindable::EntityID;
+use crate::render::FrameTime;
+
+pub fn inject_time_freeze_api(lua_api: &mut BattleLuaApi) {
+    lua_api.add_dynamic_function(TIME_FREEZE_TABLE, "is_active", |api_ctx, lua, _| {
+        let api_ctx = &*api_ctx.borrow();
+        let time_freeze_tracker = &api_ctx.simulation.time_freeze_tracker;
+
+        lua.pack_multi(time_freeze_tracker.is_action_freeze())
+    });
+
+    lua_api.add_dynamic_function(TIME_FREEZE_TABLE, "can_counter", |api_ctx, lua, _| {
+        let api_ctx = &*api_ctx.borrow();
+        let time_freeze_tracker = &api_ctx.simulation.time_freeze_tracker;
+
+        lua.pack_multi(time_freeze_tracker.can_counter())
+    });
+
+    lua_api.add_dynamic_function(
+        TIME_FREEZE_TABLE,
+        "counter_time_remaining",
+        |api_ctx, lua, _| {
+            let api_ctx = &*api_ctx.borrow();
+            let time_freeze_tracker = &api_ctx.simulation.time_freeze_tracker;
+
+            lua.pack_multi(time_freeze_tracker.counter_time_remaining())
+        },
+    );
+
+    lua_api.add_dynamic_function(TIME_FREEZE_TABLE, "get_chain", |api_ctx, lua, _| {
+        let api_ctx = &*api_ctx.borrow();
+        let simulation = &api_ctx.simulation;
+        let chain = simulation.time_freeze_tracker.chain();
+
+        let chain_table = lua.create_table()?;
+
+        for (i, entry) in chain.iter().enumerate() {
+            let entry_table = lua.create_table()?;
+            entry_table.set("team", entry.team)?;
+
+            if let Some(card_action) = simulation.card_actions.get(entry.action_index) {
+                entry_table.set("entity", create_entity_table(lua, card_action.entity)?)?;
+                entry_table.set("card_properties", &card_action.properties)?;
+            }
+
+            chain_table.raw_set(i + 1, entry_table)?;
+        }
+
+        lua.pack_multi(chain_table)
+    });
+
+    lua_api.add_dynamic_function(
+        TIME_FREEZE_TABLE,
+        "set_counter_window",
+        |api_ctx, lua, params| {
+            let duration: FrameTime = lua.unpack_multi(params)?;
+
+            let mut api_ctx = api_ctx.borrow_mut();
+            let time_freeze_tracker = &mut api_ctx.simulation.time_freeze_tracker;
+            time_freeze_tracker.set_counter_duration(duration.max(0));
+
+            lua.pack_multi(())
+        },
+    );
+
+    lua_api.add_dynamic_function(
+        TIME_FREEZE_TABLE,
+        "set_action_time_limit",
+        |api_ctx, lua, params| {
+            let duration: FrameTime = lua.unpack_multi(params)?;
+
+            let mut api_ctx = api_ctx.borrow_mut();
+            let time_freeze_tracker = &mut api_ctx.simulation.time_freeze_tracker;
+            time_freeze_tracker.set_max_action_duration(duration.max(1));
+
+            lua.pack_multi(())
+        },
+    );
+
+    lua_api.add_dynamic_function(TIME_FREEZE_TABLE, "on_counter", |api_ctx, lua, params| {
+        let callback: rollback_mlua::Function = lua.unpack_multi(params)?;
+
+        let api_ctx = &mut *api_ctx.borrow_mut();
+
+        // called with the countering entity, followed by the countered entity
+        let callback = BattleCallback::new_transformed_lua_callback(
+            lua,
+            api_ctx.vm_index,
+            callback,
+            |_, lua, (id, countered_id): (EntityID, EntityID)| {
+                lua.pack_multi((
+                    create_entity_table(lua, id)?,
+                    create_entity_table(lua, countered_id)?,
+                ))
+            },
+        )?;
+
+        let time_freeze_tracker = &mut api_ctx.simulation.time_freeze_tracker;
+        time_freeze_tracker.add_counter_callback(callback);
+
+        lua.pack_multi(())
+    });
+}
@@ -48,6 +48,21 @@ pub(super) fn inject_global_api(lua: &rollback_mlua::Lua) -> rollback_mlua::Resu
     element_table.set("Break", Element::Break)?;
     globals.set("Element", element_table)?;
 
+    use crate::bindable::Emotion;
+
+    let emotion_table = lua.create_table()?;
+    emotion_table.set("Normal", Emotion::Normal as u8)?;
+    emotion_table.set("FullSynchro", Emotion::FullSynchro as u8)?;
+    emotion_table.set("Angry", Emotion::Angry as u8)?;
+    emotion_table.set("Evil", Emotion::Evil as u8)?;
+    emotion_table.set("Anxious", Emotion::Anxious as u8)?;
+    emotion_table.set("Tired", Emotion::Tired as u8)?;
+    emotion_table.set("Exhausted", Emotion::Exhausted as u8)?;
+    emotion_table.set("Pinch", Emotion::Pinch as u8)?;
+    emotion_table.set("Focus", Emotion::Focus as u8)?;
+    emotion_table.set("Happy", Emotion::Happy as u8)?;
+    globals.set("Emotion", emotion_table)?;
+
     use crate::bindable::Drag;
 
     let drag_table = lua.create_table()?;
@@ -249,6 +264,14 @@ pub(super) fn inject_global_api(lua: &rollback_mlua::Lua) -> rollback_mlua::Resu
     team_table.set("Other", Team::Other)?;
     team_table.set("Red", Team::Red)?;
     team_table.set("Blue", Team::Blue)?;
+    team_table.set(
+        "custom",
+        lua.create_function(|_, number: u8| {
+            Team::custom(number).ok_or_else(|| {
+                rollback_mlua::Error::RuntimeError(format!("{number} is not a valid custom team"))
+            })
+        })?,
+    )?;
     globals.set("Team", team_table)?;
 
     use crate::bindable::CharacterRank;
@@ -327,8 +350,23 @@ pub(super) fn inject_global_api(lua: &rollback_mlua::Lua) -> rollback_mlua::Resu
         })?,
     )?;
 
-    // todo: AudioPriority, currently stubbed
-    globals.set("AudioPriority", lua.create_table()?)?;
+    use crate::bindable::AudioPriority;
+
+    let audio_priority_table = lua.create_table()?;
+    audio_priority_table.set("Lowest", AudioPriority::Lowest)?;
+    audio_priority_table.set("Low", AudioPriority::Low)?;
+    audio_priority_table.set("High", AudioPriority::High)?;
+    audio_priority_table.set("Highest", AudioPriority::Highest)?;
+    globals.set("AudioPriority", audio_priority_table)?;
+
+    use crate::bindable::TurnMode;
+
+    let turn_mode_table = lua.create_table()?;
+    turn_mode_table.set("Classic", TurnMode::Classic)?;
+    turn_mode_table.set("RealTime", TurnMode::RealTime)?;
+    turn_mode_table.set("AutoOpen", TurnMode::AutoOpen)?;
+    turn_mode_table.set("TurnBased", TurnMode::TurnBased)?;
+    globals.set("TurnMode", turn_mode_table)?;
 
     let shadow_table = lua.create_table()?;
     shadow_table.set("None", ResourcePaths::BLANK)?;
@@ -357,6 +395,7 @@ pub(super) fn inject_global_api(lua: &rollback_mlua::Lua) -> rollback_mlua::Resu
     held_table.set("Shoot", InputQuery::Held(Input::Shoot))?;
     held_table.set("LeftShoulder", InputQuery::Held(Input::ShoulderL))?;
     held_table.set("RightShoulder", InputQuery::Held(Input::ShoulderR))?;
+    held_table.set("EndTurn", InputQuery::Held(Input::EndTurn))?;
     input_table.set("Held", held_table)?;
 
     let pressed_table = lua.create_table()?;
@@ -369,6 +408,7 @@ pub(super) fn inject_global_api(lua: &rollback_mlua::Lua) -> rollback_mlua::Resu
     pressed_table.set("Shoot", InputQuery::JustPressed(Input::Shoot))?;
     pressed_table.set("LeftShoulder", InputQuery::JustPressed(Input::ShoulderL))?;
     pressed_table.set("RightShoulder", InputQuery::JustPressed(Input::ShoulderR))?;
+    pressed_table.set("EndTurn", InputQuery::JustPressed(Input::EndTurn))?;
     input_table.set("Pressed", pressed_table)?;
 
     globals.set("Input", input_table)?;
@@ -0,0 +1,130 @@
+use framework::prelude::{Vec2, Vec3};
+use packets::structures::{ActorProperty, Ease, KeyFrame};
+use std::collections::VecDeque;
+
+#[derive(Default, Clone, Copy)]
+pub struct AnimatedProperties {
+    /// Tile space
+    pub position: Vec3,
+    pub scale: Vec2,
+    pub rotation: f32,
+}
+
+/// Plays keyframes sent by the server
+#[derive(Default)]
+pub struct ActorPropertyAnimator {
+    keyframes: VecDeque<KeyFrame>,
+    elapsed: f32,
+    started: bool,
+    start_properties: AnimatedProperties,
+    rotation: f32,
+}
+
+impl ActorPropertyAnimator {
+    pub fn new(rotation: f32) -> Self {
+        Self {
+            rotation,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_keyframes(&mut self, keyframes: Vec<KeyFrame>) {
+        self.keyframes = keyframes.into();
+        self.elapsed = 0.0;
+        self.started = false;
+    }
+
+    pub fn is_animating(&self) -> bool {
+        !self.keyframes.is_empty()
+    }
+
+    pub fn in_keyframe(&self) -> bool {
+        self.started
+    }
+
+    /// The last rotation set by an animation, sprites don't expose their rotation
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Returns the keyframe if it's starting, non numeric properties should be applied immediately
+    pub fn start_keyframe(&mut self, properties: AnimatedProperties) -> Option<&KeyFrame> {
+        if self.started {
+            return None;
+        }
+
+        self.started = true;
+        self.start_properties = properties;
+        self.keyframes.front()
+    }
+
+    /// Steps the current keyframe forward, returns the interpolated properties
+    pub fn update(&mut self, delta: f32) -> AnimatedProperties {
+        let Some(keyframe) = self.keyframes.front() else {
+            return self.start_properties;
+        };
+
+        self.elapsed += delta;
+
+        let progress = if keyframe.duration > 0.0 {
+            (self.elapsed / keyframe.duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        let start = self.start_properties;
+        let mut properties = start;
+
+        for (property, ease) in &keyframe.property_steps {
+            let alpha = ease_progress(ease, progress);
+
+            match property {
+                ActorProperty::X(x) => properties.position.x = lerp(start.position.x, *x, alpha),
+                ActorProperty::Y(y) => properties.position.y = lerp(start.position.y, *y, alpha),
+                ActorProperty::Z(z) => properties.position.z = lerp(start.position.z, *z, alpha),
+                ActorProperty::ScaleX(x) => properties.scale.x = lerp(start.scale.x, *x, alpha),
+                ActorProperty::ScaleY(y) => properties.scale.y = lerp(start.scale.y, *y, alpha),
+                ActorProperty::Rotation(rotation) => {
+                    properties.rotation = lerp(start.rotation, *rotation, alpha)
+                }
+                _ => {}
+            }
+        }
+
+        self.rotation = properties.rotation;
+
+        if progress >= 1.0 {
+            self.elapsed -= keyframe.duration.max(0.0);
+            self.started = false;
+            self.keyframes.pop_front();
+        }
+
+        properties
+    }
+}
+
+fn lerp(start: f32, end: f32, alpha: f32) -> f32 {
+    start + (end - start) * alpha
+}
+
+fn ease_progress(ease: &Ease, progress: f32) -> f32 {
+    match ease {
+        Ease::Linear => progress,
+        Ease::In => progress * progress,
+        Ease::Out => progress * (2.0 - progress),
+        Ease::InOut => {
+            if progress < 0.5 {
+                2.0 * progress * progress
+            } else {
+                1.0 - (2.0 - 2.0 * progress).powi(2) * 0.5
+            }
+        }
+        Ease::Floor => {
+            if progress >= 1.0 {
+                1.0
+            } else {
+                0.0
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::render::FrameTime;
+
+pub struct ChatBubble {
+    pub text: String,
+    pub remaining_time: FrameTime,
+}
+
+impl ChatBubble {
+    const DURATION: FrameTime = 60 * 5;
+
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            remaining_time: Self::DURATION,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub struct IdentityHash(pub String);
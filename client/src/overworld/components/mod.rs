@@ -1,18 +1,26 @@
 mod actor_collider;
+mod actor_property_animator;
+mod chat_bubble;
 mod hidden_sprite;
+mod identity_hash;
 mod interactable_actor;
 mod movement_animator;
 mod movement_interpolator;
 mod name_label;
+mod path_npc;
 mod player_minimap_marker;
 mod warp_effect;
 
 pub use actor_collider::*;
+pub use actor_property_animator::*;
+pub use chat_bubble::*;
 pub use hidden_sprite::*;
+pub use identity_hash::*;
 pub use interactable_actor::*;
 pub use movement_animator::*;
 pub use movement_interpolator::*;
 pub use name_label::*;
+pub use path_npc::*;
 pub use player_minimap_marker::*;
 pub use warp_effect::*;
 
@@ -14,6 +14,8 @@ pub struct MovementAnimator {
     movement_enabled: bool,
     state: MovementState,
     direction_queue: VecDeque<Direction>,
+    forced_direction: Direction,
+    forced_speed: f32,
 }
 
 impl MovementAnimator {
@@ -27,6 +29,8 @@ impl MovementAnimator {
             movement_enabled: false,
             state: MovementState::Idle,
             direction_queue,
+            forced_direction: Direction::None,
+            forced_speed: 0.0,
         }
     }
 
@@ -64,4 +68,23 @@ impl MovementAnimator {
         self.direction_queue.push_back(Direction::None);
         self.direction_queue.pop_front().unwrap()
     }
+
+    /// Movement applied by tiles such as conveyors and ice, overrides input
+    pub fn forced_movement(&self) -> Option<(Direction, f32)> {
+        if self.forced_direction.is_none() {
+            None
+        } else {
+            Some((self.forced_direction, self.forced_speed))
+        }
+    }
+
+    pub fn set_forced_movement(&mut self, direction: Direction, speed: f32) {
+        self.forced_direction = direction;
+        self.forced_speed = speed;
+    }
+
+    pub fn clear_forced_movement(&mut self) {
+        self.forced_direction = Direction::None;
+        self.forced_speed = 0.0;
+    }
 }
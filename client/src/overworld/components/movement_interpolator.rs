@@ -62,6 +62,10 @@ impl MovementInterpolator {
         self.target_position = position;
     }
 
+    pub fn set_idle_direction(&mut self, direction: Direction) {
+        self.idle_direction = direction;
+    }
+
     pub fn update(&mut self, game_io: &GameIO<Globals>) -> (Vec3, Direction, MovementState) {
         let delta_instant = game_io.frame_start_instant() - self.last_push;
         let delta = self.target_position - self.last_position;
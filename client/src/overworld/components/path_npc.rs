@@ -0,0 +1,32 @@
+use framework::prelude::Vec2;
+
+/// Actors created by the client to patrol "NPC Path" objects
+pub struct PathNpc {
+    pub object_id: u32,
+    pub layer: i32,
+    pub points: Vec<Vec2>,
+    pub speed: f32,
+    pub target_index: usize,
+    pub reversed: bool,
+}
+
+impl PathNpc {
+    /// Walks back and forth along the path
+    pub fn advance(&mut self) {
+        if self.points.len() < 2 {
+            return;
+        }
+
+        if self.target_index == 0 {
+            self.reversed = false;
+        } else if self.target_index >= self.points.len() - 1 {
+            self.reversed = true;
+        }
+
+        if self.reversed {
+            self.target_index -= 1;
+        } else {
+            self.target_index += 1;
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub struct Map {
     tile_size: IVec2,
     name: String,
     song_path: String,
+    song_loop_points: LoopPoints,
+    minimap_texture_path: String,
     background_properties: BackgroundProperties,
     foreground_properties: BackgroundProperties,
     shadow_map: ShadowMap,
@@ -32,6 +34,8 @@ impl Map {
             tile_size: IVec2::new(tile_width, tile_height),
             name: String::new(),
             song_path: String::new(),
+            song_loop_points: LoopPoints::default(),
+            minimap_texture_path: String::new(),
             background_properties: BackgroundProperties::default(),
             foreground_properties: BackgroundProperties::default(),
             shadow_map: ShadowMap::new(cols as usize, rows as usize),
@@ -85,6 +89,22 @@ impl Map {
         self.song_path = path;
     }
 
+    pub fn song_loop_points(&self) -> LoopPoints {
+        self.song_loop_points
+    }
+
+    pub fn song_loop_points_mut(&mut self) -> &mut LoopPoints {
+        &mut self.song_loop_points
+    }
+
+    pub fn minimap_texture_path(&self) -> &str {
+        &self.minimap_texture_path
+    }
+
+    pub fn set_minimap_texture_path(&mut self, path: String) {
+        self.minimap_texture_path = path;
+    }
+
     pub fn cols(&self) -> u32 {
         self.cols
     }
@@ -449,6 +469,36 @@ impl Map {
         None
     }
 
+    /// Swaps the tile displayed by a tile object, flips are preserved
+    pub fn set_object_tile_gid(&mut self, entity: Entity, gid: u32) {
+        let Some(tile_meta) = self.tile_metas.get(gid as usize).and_then(|o| o.as_ref()) else {
+            return;
+        };
+
+        let Ok((tile, data, sprite)) = self
+            .object_entities
+            .query_one_mut::<(&mut Tile, &ObjectData, &mut Sprite)>(entity)
+        else {
+            return;
+        };
+
+        tile.gid = gid;
+
+        sprite.set_texture(tile_meta.tileset.texture.clone());
+        tile_meta.animator.apply(sprite);
+
+        let horizontal_multiplier = if tile.flipped_horizontal { -1.0 } else { 1.0 };
+        let vertical_multiplier = if tile.flipped_vertical { -1.0 } else { 1.0 };
+        let scale = data.size / sprite.size();
+
+        sprite.set_scale(Vec2::new(
+            horizontal_multiplier * scale.x,
+            vertical_multiplier * scale.y,
+        ));
+
+        sprite.set_origin(-tile_meta.alignment_offset - tile_meta.drawing_offset);
+    }
+
     pub fn update(&mut self, world_time: FrameTime) {
         // update tile animations
         for meta in self.tile_metas.iter_mut().filter_map(|meta| meta.as_mut()) {
@@ -0,0 +1,175 @@
+use crate::render::ui::*;
+use crate::render::*;
+use crate::resources::*;
+use framework::prelude::*;
+use std::collections::VecDeque;
+
+const MAX_HISTORY: usize = 32;
+const VISIBLE_MESSAGES: usize = 4;
+const OPEN_VISIBLE_MESSAGES: usize = 8;
+const MESSAGE_DURATION: FrameTime = 60 * 8;
+const CHARACTER_LIMIT: usize = 128;
+const MARGIN: f32 = 2.0;
+const INPUT_HEIGHT: f32 = 12.0;
+const LOG_WIDTH: f32 = 160.0;
+const BACKGROUND_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.5);
+
+struct ChatMessage {
+    text: String,
+    remaining_time: FrameTime,
+}
+
+pub struct ChatBox {
+    messages: VecDeque<ChatMessage>,
+    text_input: Option<TextInput>,
+    text_sender: flume::Sender<String>,
+    text_receiver: flume::Receiver<String>,
+    pending_message: Option<String>,
+    background_sprite: Sprite,
+}
+
+impl ChatBox {
+    pub fn new(game_io: &GameIO<Globals>) -> Self {
+        let assets = &game_io.globals().assets;
+
+        let mut background_sprite = assets.new_sprite(game_io, ResourcePaths::WHITE_PIXEL);
+        background_sprite.set_color(BACKGROUND_COLOR);
+
+        let (text_sender, text_receiver) = flume::unbounded();
+
+        Self {
+            messages: VecDeque::new(),
+            text_input: None,
+            text_sender,
+            text_receiver,
+            pending_message: None,
+            background_sprite,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.text_input.is_some()
+    }
+
+    pub fn open(&mut self, game_io: &GameIO<Globals>) {
+        let sender = self.text_sender.clone();
+
+        let text_input = TextInput::new(game_io, FontStyle::Thin)
+            .with_character_limit(CHARACTER_LIMIT)
+            .with_silent(true)
+            .with_active(true)
+            .on_change(move |value| sender.send(value.to_string()).unwrap());
+
+        self.text_input = Some(text_input);
+    }
+
+    /// Messages without a name are displayed as system messages
+    pub fn push_message(&mut self, name: Option<&str>, message: &str) {
+        let text = match name {
+            Some(name) => format!("{name}: {message}"),
+            None => message.to_string(),
+        };
+
+        self.messages.push_back(ChatMessage {
+            text,
+            remaining_time: MESSAGE_DURATION,
+        });
+
+        if self.messages.len() > MAX_HISTORY {
+            self.messages.pop_front();
+        }
+    }
+
+    /// Returns the message the player submitted
+    pub fn take_message(&mut self) -> Option<String> {
+        self.pending_message.take()
+    }
+
+    pub fn update(&mut self) {
+        for message in &mut self.messages {
+            message.remaining_time -= 1;
+        }
+    }
+
+    pub fn handle_input(&mut self, game_io: &mut GameIO<Globals>) {
+        let Some(text_input) = &mut self.text_input else {
+            return;
+        };
+
+        let cancelled = game_io.input().was_key_just_pressed(Key::Escape);
+
+        text_input.update(game_io, Self::input_bounds(), true);
+
+        if let Ok(text) = self.text_receiver.try_recv() {
+            self.text_input = None;
+
+            let text = text.trim();
+
+            if !cancelled && !text.is_empty() {
+                self.pending_message = Some(text.to_string());
+            }
+        }
+    }
+
+    pub fn draw(&mut self, game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue) {
+        let open = self.is_open();
+
+        // draw input
+        if let Some(text_input) = &mut self.text_input {
+            let bounds = Self::input_bounds();
+
+            self.background_sprite
+                .set_bounds(padded(bounds.position(), bounds.size()));
+            sprite_queue.draw_sprite(&self.background_sprite);
+
+            text_input.draw_bounded(game_io, sprite_queue, bounds);
+        }
+
+        // draw messages from the bottom up, recent messages are displayed while closed
+        let visible_count = if open {
+            OPEN_VISIBLE_MESSAGES
+        } else {
+            VISIBLE_MESSAGES
+        };
+
+        let mut text = Text::new(game_io, FontStyle::Thin);
+        text.style.bounds.width = LOG_WIDTH;
+
+        let mut bottom = RESOLUTION_F.y - INPUT_HEIGHT - MARGIN * 2.0;
+
+        let visible_messages = self
+            .messages
+            .iter()
+            .rev()
+            .take(visible_count)
+            .filter(|message| open || message.remaining_time > 0);
+
+        for message in visible_messages {
+            text.text.clone_from(&message.text);
+
+            let size = text.measure().size;
+            let position = Vec2::new(MARGIN, bottom - size.y);
+
+            self.background_sprite.set_bounds(padded(position, size));
+            sprite_queue.draw_sprite(&self.background_sprite);
+
+            text.style.bounds.set_position(position);
+            text.draw(game_io, sprite_queue);
+
+            bottom = position.y - MARGIN;
+        }
+    }
+
+    fn input_bounds() -> Rect {
+        Rect::new(
+            MARGIN,
+            RESOLUTION_F.y - INPUT_HEIGHT - MARGIN,
+            RESOLUTION_F.x - MARGIN * 2.0,
+            INPUT_HEIGHT,
+        )
+    }
+}
+
+fn padded(position: Vec2, size: Vec2) -> Rect {
+    Rect::from_corners(position - 1.0, position + size + 1.0)
+}
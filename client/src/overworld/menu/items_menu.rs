@@ -0,0 +1,133 @@
+use super::Menu;
+use crate::overworld::{Item, OverworldPlayerData};
+use crate::render::ui::*;
+use crate::render::*;
+use crate::resources::*;
+use framework::prelude::*;
+
+const LIST_START: Vec2 = Vec2::new(24.0, 28.0);
+const LIST_ROW_HEIGHT: f32 = 16.0;
+const LIST_VIEW_SIZE: usize = 4;
+const SCROLLBAR_X: f32 = 220.0;
+const DESCRIPTION_POSITION: Vec2 = Vec2::new(52.0, 108.0);
+const DESCRIPTION_SIZE: Vec2 = Vec2::new(172.0, 44.0);
+
+pub struct ItemsMenu {
+    open: bool,
+    items: Vec<Item>,
+    ui_input_tracker: UiInputTracker,
+    scroll_tracker: ScrollTracker,
+    bg_sprite: Sprite,
+    on_use: Box<dyn Fn(&str)>,
+}
+
+impl ItemsMenu {
+    pub fn new(game_io: &GameIO<Globals>, on_use: impl Fn(&str) + 'static) -> Self {
+        let globals = game_io.globals();
+        let assets = &globals.assets;
+
+        let mut scroll_tracker = ScrollTracker::new(game_io, LIST_VIEW_SIZE);
+        scroll_tracker.define_cursor(LIST_START + Vec2::new(-13.0, 3.0), LIST_ROW_HEIGHT);
+
+        let list_end = LIST_START.y + LIST_ROW_HEIGHT * LIST_VIEW_SIZE as f32;
+        scroll_tracker.define_scrollbar(
+            Vec2::new(SCROLLBAR_X, LIST_START.y),
+            Vec2::new(SCROLLBAR_X, list_end),
+        );
+
+        Self {
+            open: false,
+            items: Vec::new(),
+            ui_input_tracker: UiInputTracker::new(),
+            scroll_tracker,
+            bg_sprite: assets.new_sprite(game_io, ResourcePaths::OVERWORLD_ITEMS_BG),
+            on_use: Box::new(on_use),
+        }
+    }
+}
+
+impl Menu for ItemsMenu {
+    fn is_fullscreen(&self) -> bool {
+        true
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn open(&mut self) {
+        self.open = true;
+        self.scroll_tracker.set_selected_index(0);
+    }
+
+    fn update_player_data(&mut self, player_data: &OverworldPlayerData) {
+        self.items = player_data.items.clone();
+        self.scroll_tracker.set_total_items(self.items.len());
+    }
+
+    fn handle_input(&mut self, game_io: &mut GameIO<Globals>) {
+        let globals = game_io.globals();
+
+        // handle moving cursor
+        self.ui_input_tracker.update(game_io);
+
+        let old_index = self.scroll_tracker.selected_index();
+
+        self.scroll_tracker
+            .handle_vertical_input(&self.ui_input_tracker);
+
+        if old_index != self.scroll_tracker.selected_index() {
+            globals.audio.play_sound(&globals.cursor_move_sfx);
+        }
+
+        // handle selection
+        let input_util = InputUtil::new(game_io);
+
+        if input_util.was_just_pressed(Input::Confirm) {
+            if let Some(item) = self.items.get(self.scroll_tracker.selected_index()) {
+                globals.audio.play_sound(&globals.cursor_select_sfx);
+
+                // close to allow the server to respond with textboxes
+                self.open = false;
+                (self.on_use)(&item.id);
+            } else {
+                globals.audio.play_sound(&globals.cursor_error_sfx);
+            }
+        } else if input_util.was_just_pressed(Input::Cancel) {
+            globals.audio.play_sound(&globals.cursor_cancel_sfx);
+            self.open = false;
+        }
+    }
+
+    fn draw(&mut self, game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue) {
+        sprite_queue.draw_sprite(&self.bg_sprite);
+
+        // draw items
+        let mut text_style = TextStyle::new(game_io, FontStyle::Thick);
+
+        for (row, i) in self.scroll_tracker.view_range().enumerate() {
+            let y = LIST_START.y + row as f32 * LIST_ROW_HEIGHT + 4.0;
+
+            text_style.bounds.set_position(Vec2::new(LIST_START.x, y));
+            text_style.draw(game_io, sprite_queue, &self.items[i].name);
+        }
+
+        // draw cursor + scrollbar
+        if !self.items.is_empty() {
+            self.scroll_tracker.draw_cursor(sprite_queue);
+        }
+
+        self.scroll_tracker.draw_scrollbar(sprite_queue);
+
+        // draw description
+        if let Some(item) = self.items.get(self.scroll_tracker.selected_index()) {
+            let mut text_style = TextStyle::new(game_io, FontStyle::Thin);
+            text_style.color = Color::BLACK;
+            text_style.bounds = Rect::from_corners(
+                DESCRIPTION_POSITION,
+                DESCRIPTION_POSITION + DESCRIPTION_SIZE,
+            );
+            text_style.draw(game_io, sprite_queue, &item.description);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::BBS;
+use super::{ChatBox, Minimap, Shop, BBS};
 use crate::ease::inverse_lerp;
 use crate::overworld::OverworldPlayerData;
 use crate::render::ui::*;
@@ -19,6 +19,7 @@ pub trait Menu {
     fn is_fullscreen(&self) -> bool;
     fn is_open(&self) -> bool;
     fn open(&mut self);
+    fn update_player_data(&mut self, _player_data: &OverworldPlayerData) {}
     fn handle_input(&mut self, game_io: &mut GameIO<Globals>);
     fn draw(&mut self, game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue);
 }
@@ -29,12 +30,16 @@ pub struct MenuManager {
     bbs_replaced: bool,
     old_bbs: Option<BBS>,
     bbs: Option<BBS>,
+    shop: Option<Shop>,
+    minimap: Minimap,
+    chat: ChatBox,
     fade_time: FrameTime,
     max_fade_time: FrameTime,
     fade_sprite: Sprite,
     navigation_menu: NavigationMenu,
     menus: Vec<Box<dyn Menu>>,
     menu_bindings: Vec<(Input, usize)>,
+    navigation_bindings: Vec<(SceneOption, usize)>,
     active_menu: Option<usize>,
     event_receiver: flume::Receiver<Event>,
     event_sender: flume::Sender<Event>,
@@ -60,7 +65,8 @@ impl MenuManager {
                 SceneOption::Library,
                 SceneOption::Character,
                 // SceneOption::Email,
-                // SceneOption::KeyItems,
+                SceneOption::KeyItems,
+                SceneOption::Map,
                 SceneOption::BattleSelect,
                 SceneOption::Config,
             ],
@@ -73,12 +79,16 @@ impl MenuManager {
             bbs_replaced: false,
             old_bbs: None,
             bbs: None,
+            shop: None,
+            minimap: Minimap::new(game_io),
+            chat: ChatBox::new(game_io),
             fade_time: max_fade_time,
             max_fade_time,
             fade_sprite,
             navigation_menu,
             menus: Vec::new(),
             menu_bindings: Vec::new(),
+            navigation_bindings: Vec::new(),
             active_menu: None,
             event_sender,
             event_receiver,
@@ -89,6 +99,9 @@ impl MenuManager {
         self.active_menu.is_some()
             || self.textbox.is_open()
             || self.bbs.is_some()
+            || self.shop.is_some()
+            || self.minimap.is_open()
+            || self.chat.is_open()
             || self.navigation_menu.is_open()
     }
 
@@ -100,6 +113,28 @@ impl MenuManager {
         self.bbs.as_mut()
     }
 
+    pub fn shop_mut(&mut self) -> Option<&mut Shop> {
+        self.shop.as_mut()
+    }
+
+    pub fn minimap_mut(&mut self) -> &mut Minimap {
+        &mut self.minimap
+    }
+
+    pub fn chat_mut(&mut self) -> &mut ChatBox {
+        &mut self.chat
+    }
+
+    /// Replaces the open shop, closing the old one
+    pub fn open_shop(&mut self, mut shop: Shop) {
+        if let Some(old_shop) = &mut self.shop {
+            old_shop.close();
+        }
+
+        shop.open();
+        self.shop = Some(shop);
+    }
+
     pub fn register_menu(&mut self, menu: Box<dyn Menu>) -> usize {
         self.menus.push(menu);
         self.menus.len() - 1
@@ -110,6 +145,11 @@ impl MenuManager {
         self.menu_bindings.push((input, menu_index));
     }
 
+    /// Binds a navigation menu option to open a menu
+    pub fn bind_navigation_option(&mut self, option: SceneOption, menu_index: usize) {
+        self.navigation_bindings.push((option, menu_index));
+    }
+
     /// Opens a registered menu, forces other registered menus to close (built in menus such as Textbox + BBS are excluded)
     pub fn open_menu(&mut self, menu_index: usize) {
         self.active_menu = Some(menu_index);
@@ -188,6 +228,14 @@ impl MenuManager {
 
     pub fn update_player_data(&mut self, player_data: &OverworldPlayerData) {
         self.navigation_menu.update_info(player_data);
+
+        for menu in &mut self.menus {
+            menu.update_player_data(player_data);
+        }
+
+        if let Some(shop) = &mut self.shop {
+            shop.set_money(player_data.money);
+        }
     }
 
     pub fn update(&mut self, game_io: &mut GameIO<Globals>) -> NextScene<Globals> {
@@ -282,6 +330,42 @@ impl MenuManager {
             handle_input = false;
         }
 
+        // update shop
+        if let Some(shop) = &mut self.shop {
+            shop.update();
+
+            if handle_input {
+                shop.handle_input(game_io);
+            }
+
+            if !shop.is_open() {
+                self.shop = None;
+            }
+
+            // skip other input checks while a shop is open
+            handle_input = false;
+        }
+
+        // update minimap
+        if self.minimap.is_open() {
+            if handle_input {
+                self.minimap.handle_input(game_io);
+            }
+
+            // skip other input checks while the minimap is open
+            handle_input = false;
+        }
+
+        // update chat
+        self.chat.update();
+
+        if self.chat.is_open() {
+            self.chat.handle_input(game_io);
+
+            // skip other input checks while typing, the chat may have closed this frame
+            handle_input = false;
+        }
+
         // try opening a menu if there's no menu open
         if !self.is_open() {
             let input_util = InputUtil::new(game_io);
@@ -297,6 +381,18 @@ impl MenuManager {
                 }
             }
 
+            if handle_input && input_util.was_just_pressed(Input::Chat) {
+                self.chat.open(game_io);
+                handle_input = false;
+            }
+
+            if handle_input && input_util.was_just_pressed(Input::Minimap) {
+                let globals = game_io.globals();
+                globals.audio.play_sound(&globals.cursor_select_sfx);
+                self.minimap.open();
+                handle_input = false;
+            }
+
             if handle_input && input_util.was_just_pressed(Input::Pause) {
                 let globals = game_io.globals();
                 globals.audio.play_sound(&globals.card_select_open_sfx);
@@ -304,12 +400,37 @@ impl MenuManager {
             }
         }
 
-        self.navigation_menu.update(game_io)
+        let next_scene = self.navigation_menu.update(game_io);
+
+        if let Some(option) = self.navigation_menu.take_requested_option() {
+            if option == SceneOption::Map {
+                self.minimap.open();
+                return next_scene;
+            }
+
+            let binding = self
+                .navigation_bindings
+                .iter()
+                .find(|(bound_option, _)| *bound_option == option);
+
+            if let Some(&(_, index)) = binding {
+                self.active_menu = Some(index);
+                self.menus[index].open();
+            } else {
+                log::warn!("No menu bound to navigation option");
+            }
+        }
+
+        next_scene
     }
 
     pub fn draw(&mut self, game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue) {
         let fade_progress = inverse_lerp!(0, self.max_fade_time, self.fade_time);
 
+        if !self.is_blocking_hud() {
+            self.chat.draw(game_io, sprite_queue);
+        }
+
         if fade_progress < 0.5 {
             if let Some(bbs) = &mut self.old_bbs {
                 bbs.draw(game_io, sprite_queue);
@@ -318,6 +439,12 @@ impl MenuManager {
             bbs.draw(game_io, sprite_queue);
         }
 
+        if let Some(shop) = &mut self.shop {
+            shop.draw(game_io, sprite_queue);
+        }
+
+        self.minimap.draw(sprite_queue);
+
         self.textbox.draw(game_io, sprite_queue);
 
         if self.fade_time < self.max_fade_time {
@@ -0,0 +1,341 @@
+use crate::overworld::components::{HiddenSprite, PlayerMinimapMarker};
+use crate::overworld::{Map, ObjectData, ObjectType, TileClass};
+use crate::render::*;
+use crate::resources::*;
+use framework::prelude::*;
+
+const VIEW_POSITION: Vec2 = Vec2::new(4.0, 14.0);
+const VIEW_SIZE: Vec2 = Vec2::new(232.0, 142.0);
+const MIN_SCALE: f32 = 1.0 / 16.0;
+const MAX_SCALE: f32 = 1.0 / 4.0;
+const BACKGROUND_COLOR: Color = Color::new(0.06, 0.13, 0.28, 1.0);
+const WALKABLE_COLOR: Color = Color::new(0.16, 0.47, 0.78, 1.0);
+const BLOCKED_COLOR: Color = Color::new(0.09, 0.28, 0.47, 1.0);
+const STAIRS_COLOR: Color = Color::new(0.38, 0.69, 0.94, 1.0);
+const LAYER_BRIGHTNESS: f32 = 0.1;
+
+struct MinimapTile {
+    position: Vec2,
+    color: Color,
+}
+
+struct MinimapMarker {
+    position: Vec2,
+    state: String,
+    color: Color,
+}
+
+pub struct Minimap {
+    open: bool,
+    scale: Vec2,
+    origin: Vec2,
+    bounds_size: Vec2,
+    tile_size: Vec2,
+    tiles: Vec<MinimapTile>,
+    icons: Vec<MinimapMarker>,
+    actors: Vec<(Vec2, Color)>,
+    player_position: Vec2,
+    player_color: Color,
+    custom_sprite: Option<Sprite>,
+    overlay_sprite: Sprite,
+    pixel_sprite: Sprite,
+    marker_sprite: Sprite,
+    marker_animator: Animator,
+}
+
+impl Minimap {
+    pub fn new(game_io: &GameIO<Globals>) -> Self {
+        let globals = game_io.globals();
+        let assets = &globals.assets;
+
+        Self {
+            open: false,
+            scale: Vec2::ONE,
+            origin: Vec2::ZERO,
+            bounds_size: Vec2::ZERO,
+            tile_size: Vec2::ZERO,
+            tiles: Vec::new(),
+            icons: Vec::new(),
+            actors: Vec::new(),
+            player_position: Vec2::ZERO,
+            player_color: Color::WHITE,
+            custom_sprite: None,
+            overlay_sprite: assets.new_sprite(game_io, ResourcePaths::OVERWORLD_MINIMAP_OVERLAY),
+            pixel_sprite: assets.new_sprite(game_io, ResourcePaths::WHITE_PIXEL),
+            marker_sprite: assets.new_sprite(game_io, ResourcePaths::OVERWORLD_MINIMAP_MARKERS),
+            marker_animator: Animator::load_new(
+                assets,
+                ResourcePaths::OVERWORLD_MINIMAP_MARKERS_ANIMATION,
+            ),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Regenerates the minimap, should be called when the map changes
+    pub fn set_map(&mut self, game_io: &GameIO<Globals>, assets: &impl AssetManager, map: &Map) {
+        self.tiles.clear();
+        self.icons.clear();
+
+        // resolve screen space bounds
+        let tile_size = map.tile_size().as_vec2();
+        let layer_count = map.tile_layers().len().max(1) as f32;
+
+        let corners = [
+            Vec3::ZERO,
+            Vec3::new(map.cols() as f32, 0.0, 0.0),
+            Vec3::new(0.0, map.rows() as f32, 0.0),
+            Vec3::new(map.cols() as f32, map.rows() as f32, 0.0),
+        ]
+        .map(|corner| map.world_3d_to_screen(map.tile_3d_to_world(corner)));
+
+        let mut top_left = corners.into_iter().reduce(Vec2::min).unwrap_or_default();
+        let bottom_right = corners.into_iter().reduce(Vec2::max).unwrap_or_default();
+
+        // make room for elevated layers
+        top_left.y -= layer_count * tile_size.y * 0.5;
+
+        self.origin = top_left;
+        self.bounds_size = bottom_right - top_left;
+
+        // resolve scale
+        self.custom_sprite = None;
+
+        if !map.minimap_texture_path().is_empty() {
+            let sprite = assets.new_sprite(game_io, map.minimap_texture_path());
+
+            self.scale = sprite.size() / self.bounds_size.max(Vec2::ONE);
+            self.custom_sprite = Some(sprite);
+        } else {
+            let fit_scale = VIEW_SIZE / self.bounds_size.max(Vec2::ONE);
+            let scale = fit_scale.min_element().clamp(MIN_SCALE, MAX_SCALE);
+
+            self.scale = Vec2::splat(scale);
+        }
+
+        // tiles are drawn as half height rectangles to fill the isometric grid
+        self.tile_size = (tile_size * Vec2::new(1.0, 0.5) * self.scale).max(Vec2::ONE);
+
+        for (layer_index, layer) in map.tile_layers().iter().enumerate() {
+            if !layer.visible() {
+                continue;
+            }
+
+            let brightness = 1.0 + layer_index as f32 * LAYER_BRIGHTNESS;
+
+            for row in 0..map.rows() as i32 {
+                for col in 0..map.cols() as i32 {
+                    let tile = layer.tile_at(IVec2::new(col, row));
+
+                    let Some(tile_meta) = map.tile_meta_for_tile(tile.gid) else {
+                        continue;
+                    };
+
+                    let tile_position = Vec3::new(col as f32 + 0.5, row as f32 + 0.5, 0.0);
+                    let world_position =
+                        map.tile_3d_to_world(tile_position) + Vec3::Z * layer_index as f32;
+                    let position = self.to_minimap(map, world_position);
+
+                    let color = match tile_meta.tile_class {
+                        TileClass::Invisible => continue,
+                        TileClass::Stairs => STAIRS_COLOR,
+                        TileClass::Conveyor | TileClass::Arrow => {
+                            let state = if tile_meta.tile_class == TileClass::Arrow {
+                                "arrow"
+                            } else {
+                                "conveyor"
+                            };
+
+                            self.icons.push(MinimapMarker {
+                                position,
+                                state: state.to_string(),
+                                color: Color::WHITE,
+                            });
+
+                            WALKABLE_COLOR
+                        }
+                        _ if !tile_meta.collision_shapes.is_empty() => BLOCKED_COLOR,
+                        _ => WALKABLE_COLOR,
+                    };
+
+                    let color = Color::new(
+                        color.r * brightness,
+                        color.g * brightness,
+                        color.b * brightness,
+                        color.a,
+                    );
+
+                    self.tiles.push(MinimapTile { position, color });
+                }
+            }
+        }
+
+        // objects
+        let objects = map.object_entities();
+
+        for (_, (data, position)) in objects.query::<(&ObjectData, Option<&Vec3>)>().iter() {
+            let custom_state = data.custom_properties.get("minimap marker");
+
+            let state = if !custom_state.is_empty() {
+                custom_state
+            } else {
+                match data.object_type {
+                    ObjectType::HomeWarp => "home",
+                    ObjectType::Board => "board",
+                    ObjectType::Shop => "shop",
+                    ObjectType::Door => "warp",
+                    object_type if object_type.is_warp() => "warp",
+                    _ => continue,
+                }
+            };
+
+            let color =
+                parse_color(data.custom_properties.get("minimap color")).unwrap_or(Color::WHITE);
+
+            let world_position = position
+                .cloned()
+                .unwrap_or_else(|| data.position.extend(0.0));
+
+            self.icons.push(MinimapMarker {
+                position: self.to_minimap(map, world_position),
+                state: state.to_string(),
+                color,
+            });
+        }
+    }
+
+    /// Tracks actor positions while the minimap is open
+    pub fn update_markers(
+        &mut self,
+        map: &Map,
+        entities: &hecs::World,
+        player_entity: hecs::Entity,
+    ) {
+        if !self.open {
+            return;
+        }
+
+        self.actors.clear();
+
+        let mut query =
+            entities.query::<hecs::Without<(&Vec3, &PlayerMinimapMarker), &HiddenSprite>>();
+
+        for (entity, (position, marker)) in query.iter() {
+            let position = self.to_minimap(map, *position);
+
+            if entity == player_entity {
+                self.player_position = position;
+                self.player_color = marker.color;
+            } else if marker.color.a > 0.0 {
+                self.actors.push((position, marker.color));
+            }
+        }
+    }
+
+    pub fn handle_input(&mut self, game_io: &GameIO<Globals>) {
+        let input_util = InputUtil::new(game_io);
+
+        let requesting_close = input_util.was_just_pressed(Input::Cancel)
+            || input_util.was_just_pressed(Input::Minimap)
+            || input_util.was_just_pressed(Input::Pause);
+
+        if requesting_close {
+            let globals = game_io.globals();
+            globals.audio.play_sound(&globals.cursor_cancel_sfx);
+            self.close();
+        }
+    }
+
+    pub fn draw(&mut self, sprite_queue: &mut SpriteColorQueue) {
+        if !self.open {
+            return;
+        }
+
+        // center the map, or follow the player if the map is too large
+        let map_size = self.bounds_size * self.scale;
+
+        let center = if map_size.x > VIEW_SIZE.x || map_size.y > VIEW_SIZE.y {
+            self.player_position
+        } else {
+            map_size * 0.5
+        };
+
+        let offset = VIEW_POSITION + VIEW_SIZE * 0.5 - center;
+
+        // draw background
+        self.pixel_sprite
+            .set_bounds(Rect::from_corners(Vec2::ZERO, RESOLUTION_F));
+        self.pixel_sprite.set_color(BACKGROUND_COLOR);
+        sprite_queue.draw_sprite(&self.pixel_sprite);
+
+        // draw map
+        if let Some(sprite) = &mut self.custom_sprite {
+            sprite.set_position(offset);
+            sprite_queue.draw_sprite(sprite);
+        } else {
+            self.pixel_sprite.set_size(self.tile_size);
+
+            for tile in &self.tiles {
+                let position = offset + tile.position - self.tile_size * 0.5;
+
+                self.pixel_sprite.set_position(position.floor());
+                self.pixel_sprite.set_color(tile.color);
+                sprite_queue.draw_sprite(&self.pixel_sprite);
+            }
+        }
+
+        // draw icons
+        for icon in &self.icons {
+            self.marker_animator.set_state(&icon.state);
+            self.marker_animator.apply(&mut self.marker_sprite);
+
+            self.marker_sprite
+                .set_position((offset + icon.position).floor());
+            self.marker_sprite.set_color(icon.color);
+            sprite_queue.draw_sprite(&self.marker_sprite);
+        }
+
+        // draw actors
+        self.marker_animator.set_state("actor");
+        self.marker_animator.apply(&mut self.marker_sprite);
+
+        let actors = self.actors.iter().cloned();
+        let player = std::iter::once((self.player_position, self.player_color));
+
+        for (position, color) in actors.chain(player) {
+            self.marker_sprite.set_position((offset + position).floor());
+            self.marker_sprite.set_color(color);
+            sprite_queue.draw_sprite(&self.marker_sprite);
+        }
+
+        sprite_queue.draw_sprite(&self.overlay_sprite);
+    }
+
+    fn to_minimap(&self, map: &Map, world_position: Vec3) -> Vec2 {
+        (map.world_3d_to_screen(world_position) - self.origin) * self.scale
+    }
+}
+
+/// Parses Tiled colors, #AARRGGBB or #RRGGBB
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+    let value = u32::from_str_radix(hex, 16).ok()?;
+
+    let [a, r, g, b] = match hex.len() {
+        8 => value.to_be_bytes(),
+        6 => (value | 0xFF000000).to_be_bytes(),
+        _ => return None,
+    };
+
+    Some(Color::from((r, g, b, a)))
+}
@@ -1,5 +1,13 @@
 mod bbs;
+mod chat_box;
+mod items_menu;
 mod menu_manager;
+mod minimap;
+mod shop;
 
 pub use bbs::*;
+pub use chat_box::*;
+pub use items_menu::*;
 pub use menu_manager::*;
+pub use minimap::*;
+pub use shop::*;
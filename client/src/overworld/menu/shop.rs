@@ -0,0 +1,280 @@
+use super::Menu;
+use crate::render::ui::*;
+use crate::render::*;
+use crate::resources::*;
+use framework::prelude::*;
+use packets::structures::ShopItem;
+
+const MONEY_POSITION: Vec2 = Vec2::new(2.0, 4.0);
+const LIST_POSITION: Vec2 = Vec2::new(72.0, 0.0);
+const LIST_ROWS_START: Vec2 = Vec2::new(7.0, 8.0);
+const LIST_ROW_HEIGHT: f32 = 16.0;
+const LIST_VIEW_SIZE: usize = 5;
+const BG_SPEED: f32 = 0.5;
+
+pub struct Shop {
+    open: bool,
+    confirming: bool,
+    items: Vec<ShopItem>,
+    money: u32,
+    textbox: Textbox,
+    ui_input_tracker: UiInputTracker,
+    scroll_tracker: ScrollTracker,
+    bg_sprite: Sprite,
+    bg_offset: f32,
+    money_sprite: Sprite,
+    list_sprite: Sprite,
+    purchase_sender: flume::Sender<String>,
+    purchase_receiver: flume::Receiver<String>,
+    on_purchase: Box<dyn Fn(&str)>,
+    on_close: Box<dyn Fn()>,
+}
+
+impl Shop {
+    pub fn new(
+        game_io: &GameIO<Globals>,
+        assets: &impl AssetManager,
+        mug_texture_path: &str,
+        mug_animation_path: &str,
+        on_purchase: impl Fn(&str) + 'static,
+        on_close: impl Fn() + 'static,
+    ) -> Self {
+        let globals = game_io.globals();
+        let global_assets = &globals.assets;
+
+        let mut textbox = Textbox::new_overworld(game_io);
+        textbox.set_next_avatar(game_io, assets, mug_texture_path, mug_animation_path);
+
+        let mut scroll_tracker = ScrollTracker::new(game_io, LIST_VIEW_SIZE);
+
+        let list_start = LIST_POSITION + LIST_ROWS_START;
+        scroll_tracker.define_cursor(list_start + Vec2::new(-13.0, 3.0), LIST_ROW_HEIGHT);
+
+        let mut list_sprite = global_assets.new_sprite(game_io, ResourcePaths::OVERWORLD_SHOP_LIST);
+        list_sprite.set_position(LIST_POSITION);
+
+        let scroll_x = LIST_POSITION.x + list_sprite.size().x - 3.0;
+        let list_end = list_start.y + LIST_ROW_HEIGHT * LIST_VIEW_SIZE as f32;
+        scroll_tracker.define_scrollbar(
+            Vec2::new(scroll_x, list_start.y),
+            Vec2::new(scroll_x, list_end),
+        );
+
+        let mut money_sprite =
+            global_assets.new_sprite(game_io, ResourcePaths::OVERWORLD_SHOP_MONEY);
+        money_sprite.set_position(MONEY_POSITION);
+
+        let (purchase_sender, purchase_receiver) = flume::unbounded();
+
+        Self {
+            open: false,
+            confirming: false,
+            items: Vec::new(),
+            money: 0,
+            textbox,
+            ui_input_tracker: UiInputTracker::new(),
+            scroll_tracker,
+            bg_sprite: global_assets.new_sprite(game_io, ResourcePaths::OVERWORLD_SHOP_BG),
+            bg_offset: 0.0,
+            money_sprite,
+            list_sprite,
+            purchase_sender,
+            purchase_receiver,
+            on_purchase: Box::new(on_purchase),
+            on_close: Box::new(on_close),
+        }
+    }
+
+    /// Replaces the inventory, the server may update it while the shop is open
+    pub fn set_items(&mut self, game_io: &GameIO<Globals>, items: Vec<ShopItem>) {
+        self.items = items;
+        self.scroll_tracker.set_total_items(self.items.len());
+
+        if self.open && !self.confirming {
+            self.describe_selection(game_io);
+        }
+    }
+
+    pub fn set_money(&mut self, money: u32) {
+        self.money = money;
+    }
+
+    pub fn close(&mut self) {
+        if !self.open {
+            return;
+        }
+
+        self.open = false;
+        (self.on_close)();
+    }
+
+    pub fn update(&mut self) {
+        self.bg_offset = (self.bg_offset + BG_SPEED) % self.bg_sprite.size().x;
+    }
+
+    fn description_interface(&self) -> TextboxMessage {
+        let description = self
+            .items
+            .get(self.scroll_tracker.selected_index())
+            .map(|item| item.description.clone())
+            .unwrap_or_default();
+
+        TextboxMessage::new(description).with_completable(false)
+    }
+
+    fn describe_selection(&mut self, game_io: &GameIO<Globals>) {
+        if !self.textbox.is_complete() {
+            self.textbox.advance_interface(game_io);
+        }
+
+        let interface = self.description_interface();
+        self.textbox.push_interface(interface);
+    }
+
+    fn confirm_purchase(&mut self, game_io: &GameIO<Globals>) {
+        let Some(item) = self.items.get(self.scroll_tracker.selected_index()) else {
+            return;
+        };
+
+        let item_name = item.name.clone();
+        let message = format!("Buy {} for {}$?", item.name, item.price);
+        let purchase_sender = self.purchase_sender.clone();
+
+        let interface = TextboxQuestion::new(message, move |yes| {
+            if yes {
+                let _ = purchase_sender.send(item_name);
+            }
+        });
+
+        self.confirming = true;
+        self.textbox.advance_interface(game_io);
+        self.textbox.push_interface(interface);
+    }
+}
+
+impl Menu for Shop {
+    fn is_fullscreen(&self) -> bool {
+        true
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn open(&mut self) {
+        self.open = true;
+        self.confirming = false;
+
+        if self.textbox.is_complete() {
+            let interface = self.description_interface();
+            self.textbox.push_interface(interface);
+        }
+
+        self.textbox.open();
+    }
+
+    fn handle_input(&mut self, game_io: &mut GameIO<Globals>) {
+        self.textbox.update(game_io);
+
+        while let Ok(item_name) = self.purchase_receiver.try_recv() {
+            (self.on_purchase)(&item_name);
+        }
+
+        if self.confirming {
+            // wait for the question to be answered
+            if self.textbox.is_complete() {
+                self.confirming = false;
+                self.describe_selection(game_io);
+            }
+
+            return;
+        }
+
+        let globals = game_io.globals();
+
+        // handle moving cursor
+        self.ui_input_tracker.update(game_io);
+
+        let old_index = self.scroll_tracker.selected_index();
+
+        self.scroll_tracker
+            .handle_vertical_input(&self.ui_input_tracker);
+
+        if old_index != self.scroll_tracker.selected_index() {
+            globals.audio.play_sound(&globals.cursor_move_sfx);
+            self.describe_selection(game_io);
+        }
+
+        // handle selection
+        let input_util = InputUtil::new(game_io);
+
+        if !self.items.is_empty() && input_util.was_just_pressed(Input::Confirm) {
+            globals.audio.play_sound(&globals.cursor_select_sfx);
+            self.confirm_purchase(game_io);
+        } else if input_util.was_just_pressed(Input::Cancel) {
+            globals.audio.play_sound(&globals.cursor_cancel_sfx);
+            self.close();
+        }
+    }
+
+    fn draw(&mut self, game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue) {
+        // draw scrolling bg
+        let bg_size = self.bg_sprite.size();
+        let columns = (RESOLUTION_F.x / bg_size.x) as i32 + 1;
+        let rows = (RESOLUTION_F.y / bg_size.y) as i32 + 1;
+
+        for row in -1..rows {
+            for col in -1..columns {
+                let position = Vec2::new(col as f32, row as f32) * bg_size + self.bg_offset;
+
+                self.bg_sprite.set_position(position);
+                sprite_queue.draw_sprite(&self.bg_sprite);
+            }
+        }
+
+        // draw money
+        sprite_queue.draw_sprite(&self.money_sprite);
+
+        let mut text_style = TextStyle::new_monospace(game_io, FontStyle::Thin);
+        text_style.color = Color::from((74, 65, 74));
+
+        let money_text = format!("{}$", self.money);
+        let money_size = text_style.measure(&money_text).size;
+        let money_right = MONEY_POSITION.x + self.money_sprite.size().x - 5.0;
+
+        text_style.bounds.set_position(Vec2::new(
+            money_right - money_size.x,
+            MONEY_POSITION.y + 18.0,
+        ));
+        text_style.draw(game_io, sprite_queue, &money_text);
+
+        // draw items
+        sprite_queue.draw_sprite(&self.list_sprite);
+
+        let list_start = LIST_POSITION + LIST_ROWS_START;
+        let list_right = LIST_POSITION.x + self.list_sprite.size().x - 8.0;
+
+        for (row, i) in self.scroll_tracker.view_range().enumerate() {
+            let item = &self.items[i];
+            let y = list_start.y + row as f32 * LIST_ROW_HEIGHT + 4.0;
+
+            text_style.bounds.set_position(Vec2::new(list_start.x, y));
+            text_style.draw(game_io, sprite_queue, &item.name);
+
+            let price_text = format!("{}$", item.price);
+            let price_width = text_style.measure(&price_text).size.x;
+
+            text_style.bounds.x = list_right - price_width;
+            text_style.draw(game_io, sprite_queue, &price_text);
+        }
+
+        // draw cursor + scrollbar
+        if !self.items.is_empty() {
+            self.scroll_tracker.draw_cursor(sprite_queue);
+        }
+
+        self.scroll_tracker.draw_scrollbar(sprite_queue);
+
+        self.textbox.draw(game_io, sprite_queue);
+    }
+}
@@ -1,9 +1,11 @@
 mod object_data;
+mod object_path;
 mod object_type;
 mod shape_object;
 mod tile_object;
 
 pub use object_data::*;
+pub use object_path::*;
 pub use object_type::*;
 pub use shape_object::*;
 pub use tile_object::*;
@@ -0,0 +1,38 @@
+use crate::parse_util::parse_or_default;
+use framework::prelude::Vec2;
+
+/// Points from a polyline object, in world space
+pub struct ObjectPath {
+    pub layer: i32,
+    pub points: Vec<Vec2>,
+}
+
+impl ObjectPath {
+    pub fn new(element: roxmltree::Node, layer: i32) -> ObjectPath {
+        let origin = Vec2::new(
+            parse_or_default::<f32>(element.attribute("x")),
+            parse_or_default::<f32>(element.attribute("y")),
+        );
+
+        let points_str = element
+            .children()
+            .find(|child| child.tag_name().name() == "polyline")
+            .and_then(|polyline_element| polyline_element.attribute("points"))
+            .unwrap_or_default();
+
+        let points = points_str
+            .split_whitespace()
+            .filter_map(|point_str| point_str.split_once(','))
+            .map(|(x, y)| {
+                let offset = Vec2::new(
+                    x.parse::<f32>().unwrap_or_default(),
+                    y.parse::<f32>().unwrap_or_default(),
+                );
+
+                origin + offset
+            })
+            .collect();
+
+        ObjectPath { layer, points }
+    }
+}
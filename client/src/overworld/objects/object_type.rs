@@ -7,12 +7,16 @@ pub enum ObjectType {
     HomeWarp,
     Board,
     Shop,
+    Door,
+    Sign,
+    Chest,
+    NpcPath,
     Undefined,
 }
 
 impl ObjectType {
     pub fn is_solid(self) -> bool {
-        !self.is_warp()
+        !self.is_warp() && !matches!(self, ObjectType::NpcPath)
     }
 
     pub fn is_warp(self) -> bool {
@@ -41,6 +45,10 @@ impl From<&str> for ObjectType {
             "home warp" => ObjectType::HomeWarp,
             "board" => ObjectType::Board,
             "shop" => ObjectType::Shop,
+            "door" => ObjectType::Door,
+            "sign" => ObjectType::Sign,
+            "chest" => ObjectType::Chest,
+            "npc path" => ObjectType::NpcPath,
             _ => ObjectType::Undefined,
         }
     }
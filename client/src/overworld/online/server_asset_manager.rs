@@ -67,12 +67,7 @@ pub struct ServerAssetManager {
 
 impl ServerAssetManager {
     pub fn new(game_io: &GameIO<Globals>, address: &str) -> Self {
-        let address = packets::address_parsing::strip_data(address).replace(':', "_p");
-        let address = uri_encode(&address);
-
-        const SEP: char = std::path::MAIN_SEPARATOR;
-        let path_prefix =
-            ResourcePaths::clean_folder(&format!("{}{}", ResourcePaths::CACHE_FOLDER, address));
+        let path_prefix = Self::path_prefix_for(address);
 
         // find stored assets
         let assets = Self::find_stored_assets(&path_prefix);
@@ -103,6 +98,18 @@ impl ServerAssetManager {
         }
     }
 
+    fn path_prefix_for(address: &str) -> String {
+        let address = packets::address_parsing::strip_data(address).replace(':', "_p");
+        let address = uri_encode(&address);
+
+        ResourcePaths::clean_folder(&format!("{}{}", ResourcePaths::CACHE_FOLDER, address))
+    }
+
+    /// True if the server at the address shares this cache, such as when only the data differs
+    pub fn shares_cache_with(&self, address: &str) -> bool {
+        self.path_prefix == Self::path_prefix_for(address)
+    }
+
     fn find_stored_assets(path: &str) -> HashMap<String, CachedServerAsset> {
         let mut assets = HashMap::new();
 
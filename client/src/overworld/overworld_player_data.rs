@@ -1,6 +1,16 @@
 use crate::bindable::Emotion;
+use crate::render::FrameTime;
 use framework::prelude::Vec3;
 
+/// An object waiting on the server to accept the player's interaction
+#[derive(Clone, Copy)]
+pub struct PendingObjectInteraction {
+    pub entity: hecs::Entity,
+    pub object_id: u32,
+    pub start_time: FrameTime,
+}
+
+#[derive(Clone)]
 pub struct Item {
     pub id: String,
     pub name: String,
@@ -17,6 +27,10 @@ pub struct OverworldPlayerData {
     pub actor_interaction: Option<String>,
     pub object_interaction: Option<u32>,
     pub tile_interaction: Option<Vec3>,
+    /// Chest waiting on the server to mark it as opened
+    pub opening_chest: Option<PendingObjectInteraction>,
+    /// Door waiting on the server to start a transfer
+    pub opening_door: Option<PendingObjectInteraction>,
 }
 
 impl OverworldPlayerData {
@@ -31,6 +45,8 @@ impl OverworldPlayerData {
             actor_interaction: None,
             object_interaction: None,
             tile_interaction: None,
+            opening_chest: None,
+            opening_door: None,
         }
     }
 }
@@ -0,0 +1,84 @@
+use crate::overworld::components::*;
+use crate::render::AnimatorLoopMode;
+use crate::resources::{AssetManager, Globals};
+use crate::scenes::OverworldSceneBase;
+use framework::prelude::*;
+use packets::structures::ActorProperty;
+
+pub fn system_actor_property_animation(
+    game_io: &GameIO<Globals>,
+    scene: &mut OverworldSceneBase,
+    assets: &impl AssetManager,
+) {
+    let map = &scene.map;
+    let entities = &mut scene.entities;
+    let audio = &game_io.globals().audio;
+
+    let query = entities.query_mut::<(
+        &mut ActorPropertyAnimator,
+        &mut Vec3,
+        &mut Direction,
+        &mut Sprite,
+        &mut Animator,
+        &mut MovementAnimator,
+        Option<&mut MovementInterpolator>,
+    )>();
+
+    for (
+        _,
+        (property_animator, position, direction, sprite, animator, movement_animator, interpolator),
+    ) in query
+    {
+        if !property_animator.is_animating() {
+            continue;
+        }
+
+        let start_position = *position;
+        let mut delta = game_io.target_duration().as_secs_f32();
+
+        while property_animator.is_animating() {
+            let current_properties = AnimatedProperties {
+                position: map.world_3d_to_tile_space(*position),
+                scale: sprite.scale(),
+                rotation: property_animator.rotation(),
+            };
+
+            if let Some(keyframe) = property_animator.start_keyframe(current_properties) {
+                for (property, _) in &keyframe.property_steps {
+                    match property {
+                        ActorProperty::Animation(state) => {
+                            animator.set_state(state);
+                            animator.set_loop_mode(AnimatorLoopMode::Loop);
+                            movement_animator.set_enabled(false);
+                        }
+                        ActorProperty::Direction(new_direction) => *direction = *new_direction,
+                        ActorProperty::SoundEffect(path) => audio.play_sound(&assets.audio(path)),
+                        _ => {}
+                    }
+                }
+            }
+
+            let properties = property_animator.update(delta);
+            delta = 0.0;
+
+            *position = map.tile_3d_to_world(properties.position);
+            sprite.set_scale(properties.scale);
+            sprite.set_rotation(properties.rotation);
+
+            if property_animator.in_keyframe() {
+                break;
+            }
+        }
+
+        if let Some(interpolator) = interpolator {
+            interpolator.force_position(*position);
+            interpolator.set_idle_direction(*direction);
+        }
+
+        if *position != start_position {
+            movement_animator.set_state(MovementState::Walking);
+        } else {
+            movement_animator.set_state(MovementState::Idle);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::overworld::components::*;
+use crate::scenes::OverworldSceneBase;
+
+pub fn system_chat_bubbles(scene: &mut OverworldSceneBase) {
+    let entities = &mut scene.entities;
+    let mut expired = Vec::new();
+
+    for (entity, bubble) in entities.query_mut::<&mut ChatBubble>() {
+        bubble.remaining_time -= 1;
+
+        if bubble.remaining_time <= 0 {
+            expired.push(entity);
+        }
+    }
+
+    for entity in expired {
+        let _ = entities.remove_one::<ChatBubble>(entity);
+    }
+}
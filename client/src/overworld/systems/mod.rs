@@ -1,17 +1,25 @@
+mod actor_property_animation_system;
 mod animate_system;
+mod chat_bubble_system;
 mod movement_animation_system;
 mod movement_interpolation_system;
 mod movement_system;
+mod object_interaction_system;
+mod path_npc_system;
 mod player_interaction_system;
 mod player_movement_system;
 mod position_system;
 mod warp_effect_system;
 mod warp_system;
 
+pub use actor_property_animation_system::*;
 pub use animate_system::*;
+pub use chat_bubble_system::*;
 pub use movement_animation_system::*;
 pub use movement_interpolation_system::*;
 pub use movement_system::*;
+pub use object_interaction_system::*;
+pub use path_npc_system::*;
 pub use player_interaction_system::*;
 pub use player_movement_system::*;
 pub use position_system::*;
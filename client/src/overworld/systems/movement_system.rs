@@ -1,10 +1,15 @@
-use crate::overworld::{components::*, Map};
+use crate::overworld::{components::*, Map, Tile, TileClass, TileMeta};
 use crate::resources::{OVERWORLD_RUN_SPEED, OVERWORLD_WALK_SPEED};
 use crate::scenes::OverworldSceneBase;
 use framework::prelude::{Vec2, Vec3Swizzles};
 
 const COLLISION_RADIUS: f32 = 4.0;
 
+// tiles per second
+const DEFAULT_CONVEYOR_SPEED: f32 = 6.0;
+const DEFAULT_ICE_SPEED: f32 = 6.0;
+const DEFAULT_TREADMILL_SPEED: f32 = 1.875;
+
 pub fn system_movement(scene: &mut OverworldSceneBase) {
     let entities = &mut scene.entities;
     let map = &mut scene.map;
@@ -17,31 +22,72 @@ pub fn system_movement(scene: &mut OverworldSceneBase) {
             continue;
         }
 
-        let movement_direction = movement_animator.advance_direction();
+        let input_direction = movement_animator.advance_direction();
+        let original_position = *position;
 
-        let speed = match movement_animator.state() {
-            MovementState::Idle => 0.0,
-            MovementState::Walking => {
-                if position.z.fract() != 0.0 {
-                    OVERWORLD_RUN_SPEED
-                } else {
-                    OVERWORLD_WALK_SPEED
+        update_forced_movement(map, movement_animator, original_position, input_direction);
+
+        let (mut final_position, final_direction) = match movement_animator.forced_movement() {
+            Some((direction, speed)) => {
+                // no sliding around corners, stop when blocked
+                let offset = offset_with_direction(map, direction, speed);
+                let (moved, final_position) = try_move_to(
+                    actor_entity,
+                    entities,
+                    map,
+                    original_position,
+                    offset,
+                    speed,
+                );
+
+                if !moved {
+                    movement_animator.clear_forced_movement();
                 }
+
+                (final_position, Direction::None)
+            }
+            None => {
+                let speed = match movement_animator.state() {
+                    MovementState::Idle => 0.0,
+                    MovementState::Walking => {
+                        if position.z.fract() != 0.0 {
+                            OVERWORLD_RUN_SPEED
+                        } else {
+                            OVERWORLD_WALK_SPEED
+                        }
+                    }
+                    MovementState::Running => OVERWORLD_RUN_SPEED,
+                };
+
+                let final_position = find_final_position(
+                    actor_entity,
+                    entities,
+                    map,
+                    original_position,
+                    input_direction,
+                    speed,
+                );
+
+                (final_position, input_direction)
             }
-            MovementState::Running => OVERWORLD_RUN_SPEED,
         };
 
-        let original_position = *position;
-        let final_position = find_final_position(
-            actor_entity,
-            entities,
-            map,
-            original_position,
-            movement_direction,
-            speed,
-        );
+        // treadmills push while still allowing movement
+        if let Some((tile, tile_meta)) = tile_at(map, original_position) {
+            if tile_meta.tile_class == TileClass::Treadmill {
+                let speed = tile_speed(map, tile_meta, DEFAULT_TREADMILL_SPEED);
+                let offset = offset_with_direction(map, tile.get_direction(tile_meta), speed);
+
+                let (moved, pushed_position) =
+                    try_move_to(actor_entity, entities, map, final_position, offset, speed);
+
+                if moved {
+                    final_position = pushed_position;
+                }
+            }
+        }
 
-        updates.push((actor_entity, final_position, movement_direction));
+        updates.push((actor_entity, final_position, final_direction));
     }
 
     std::mem::drop(query);
@@ -59,6 +105,74 @@ pub fn system_movement(scene: &mut OverworldSceneBase) {
     }
 }
 
+fn update_forced_movement(
+    map: &Map,
+    movement_animator: &mut MovementAnimator,
+    position: Vec3,
+    input_direction: Direction,
+) {
+    let Some((tile, tile_meta)) = tile_at(map, position) else {
+        movement_animator.clear_forced_movement();
+        return;
+    };
+
+    match tile_meta.tile_class {
+        TileClass::Conveyor => {
+            let speed = tile_speed(map, tile_meta, DEFAULT_CONVEYOR_SPEED);
+            movement_animator.set_forced_movement(tile.get_direction(tile_meta), speed);
+        }
+        TileClass::Ice => {
+            // keep sliding in the same direction until blocked
+            let direction = match movement_animator.forced_movement() {
+                Some((direction, _)) => direction,
+                None => input_direction,
+            };
+
+            let speed = tile_speed(map, tile_meta, DEFAULT_ICE_SPEED);
+            movement_animator.set_forced_movement(direction, speed);
+        }
+        _ => movement_animator.clear_forced_movement(),
+    }
+}
+
+fn tile_at(map: &Map, position: Vec3) -> Option<(Tile, &TileMeta)> {
+    if position.z < 0.0 {
+        return None;
+    }
+
+    let layer = map.tile_layer(position.z as usize)?;
+    let tile = *layer.tile_at_f32(map.world_to_tile_space(position.xy()));
+    let tile_meta = map.tile_meta_for_tile(tile.gid)?;
+
+    Some((tile, tile_meta))
+}
+
+/// Converts the tile's speed property from tiles per second to world units per frame
+fn tile_speed(map: &Map, tile_meta: &TileMeta, default_speed: f32) -> f32 {
+    let speed = if tile_meta.custom_properties.has_property("speed") {
+        tile_meta.custom_properties.get_f32("speed")
+    } else {
+        default_speed
+    };
+
+    speed * map.tile_size().y as f32 / 60.0
+}
+
+/// Arrows can only be crossed in the direction they point
+fn blocked_by_arrow(map: &Map, position: Vec3, offset: Vec2) -> bool {
+    let Some((tile, tile_meta)) = tile_at(map, position) else {
+        return false;
+    };
+
+    if tile_meta.tile_class != TileClass::Arrow {
+        return false;
+    }
+
+    let arrow_offset = offset_with_direction(map, tile.get_direction(tile_meta), 1.0);
+
+    arrow_offset.dot(offset) < 0.0
+}
+
 fn find_final_position(
     actor_entity: hecs::Entity,
     entities: &hecs::World,
@@ -188,6 +302,10 @@ fn try_move_to(
         if !can_move_to_edge || (target_pos.z - edge_elevation).abs() > max_elevation_diff {
             return (false, current_pos);
         }
+
+        if blocked_by_arrow(map, current_pos, offset) || blocked_by_arrow(map, target_pos, offset) {
+            return (false, current_pos);
+        }
     }
 
     // if we can move forward, check neighboring actors
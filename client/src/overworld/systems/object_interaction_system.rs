@@ -0,0 +1,169 @@
+use crate::overworld::components::*;
+use crate::overworld::*;
+use crate::render::ui::TextboxMessage;
+use crate::render::FrameTime;
+use crate::resources::*;
+use crate::scenes::OverworldSceneBase;
+use framework::prelude::*;
+
+/// Frames to wait on the server to accept an interaction before giving up on it
+const PENDING_INTERACTION_TIMEOUT: FrameTime = 60 * 5;
+
+/// Handles objects understood by the client, returns false if the server doesn't need to know
+pub fn system_object_interaction(
+    game_io: &GameIO<Globals>,
+    scene: &mut OverworldSceneBase,
+    object_id: u32,
+) -> bool {
+    let world_time = scene.world_time();
+    let map = &mut scene.map;
+
+    let mut object = None;
+
+    for (entity, data) in map.object_entities().query::<&ObjectData>().iter() {
+        if data.id == object_id {
+            let properties = data.custom_properties.clone();
+            object = Some((entity, data.object_type, properties));
+            break;
+        }
+    }
+
+    let Some((entity, object_type, properties)) = object else {
+        return true;
+    };
+
+    match object_type {
+        ObjectType::Sign => {
+            let text = properties.get("text").to_string();
+
+            let menu_manager = &mut scene.menu_manager;
+            menu_manager.set_next_avatar(game_io, &game_io.globals().assets, "", "");
+            menu_manager.push_textbox_interface(TextboxMessage::new(text));
+
+            false
+        }
+        ObjectType::Door => {
+            let pending = PendingObjectInteraction {
+                entity,
+                object_id,
+                start_time: world_time,
+            };
+
+            if properties.has_property("area") {
+                // the server may reject the interaction, wait for it to start the transfer
+                scene.player_data.opening_door = Some(pending);
+            } else if properties.has_property("x") {
+                // doors without an area warp within the current area
+                let closed_gid = map
+                    .object_entities()
+                    .get::<&Tile>(entity)
+                    .map(|tile| tile.gid)
+                    .unwrap_or_default();
+
+                let tile_position = Vec3::new(
+                    properties.get_f32("x"),
+                    properties.get_f32("y"),
+                    properties.get_f32("z"),
+                );
+                let position = map.tile_3d_to_world(tile_position);
+                let direction = Direction::from(properties.get("direction"));
+                let player_entity = scene.player_data.entity;
+
+                scene.player_data.opening_door = Some(pending);
+                system_open_pending_door(scene);
+
+                WarpEffect::warp_full(
+                    game_io,
+                    scene,
+                    player_entity,
+                    position,
+                    direction,
+                    move |_, scene| scene.map.set_object_tile_gid(entity, closed_gid),
+                );
+            }
+
+            true
+        }
+        ObjectType::Chest => {
+            if properties.get("opened") != "true" {
+                // the server may reject the interaction, wait for it to open the chest
+                scene.player_data.opening_chest = Some(PendingObjectInteraction {
+                    entity,
+                    object_id,
+                    start_time: world_time,
+                });
+            }
+
+            true
+        }
+        _ => true,
+    }
+}
+
+/// Displays the text for the chest the player is opening once the server marks it as opened
+pub fn system_opened_chest(game_io: &GameIO<Globals>, scene: &mut OverworldSceneBase) {
+    let Some(PendingObjectInteraction { object_id, .. }) = scene.player_data.opening_chest else {
+        return;
+    };
+
+    let map = &scene.map;
+    let mut opened_text = None;
+
+    for (_, data) in map.object_entities().query::<&ObjectData>().iter() {
+        if data.id == object_id && data.custom_properties.get("opened") == "true" {
+            opened_text = Some(data.custom_properties.get("text").to_string());
+            break;
+        }
+    }
+
+    let Some(text) = opened_text else {
+        return;
+    };
+
+    scene.player_data.opening_chest = None;
+
+    if !text.is_empty() {
+        let menu_manager = &mut scene.menu_manager;
+        menu_manager.set_next_avatar(game_io, &game_io.globals().assets, "", "");
+        menu_manager.push_textbox_interface(TextboxMessage::new(text));
+    }
+}
+
+/// Displays the open tile for the door the player is entering, once the warp or transfer starts
+pub fn system_open_pending_door(scene: &mut OverworldSceneBase) {
+    let Some(PendingObjectInteraction { entity, .. }) = scene.player_data.opening_door.take()
+    else {
+        return;
+    };
+
+    let map = &mut scene.map;
+
+    let open_gid = map
+        .object_entities()
+        .get::<&ObjectData>(entity)
+        .ok()
+        .filter(|data| data.custom_properties.has_property("open tile"))
+        .map(|data| data.custom_properties.get_i32("open tile") as u32);
+
+    if let Some(open_gid) = open_gid {
+        map.set_object_tile_gid(entity, open_gid);
+    }
+}
+
+/// Forgets interactions the server never accepted
+pub fn system_expire_pending_interactions(scene: &mut OverworldSceneBase) {
+    let world_time = scene.world_time();
+    let player_data = &mut scene.player_data;
+
+    for pending in [
+        &mut player_data.opening_chest,
+        &mut player_data.opening_door,
+    ] {
+        let expired = pending
+            .is_some_and(|pending| world_time - pending.start_time > PENDING_INTERACTION_TIMEOUT);
+
+        if expired {
+            *pending = None;
+        }
+    }
+}
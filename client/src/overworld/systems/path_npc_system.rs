@@ -0,0 +1,42 @@
+use crate::overworld::components::*;
+use crate::scenes::OverworldSceneBase;
+use framework::prelude::*;
+
+pub fn system_path_npcs(scene: &mut OverworldSceneBase) {
+    let map = &scene.map;
+    let entities = &mut scene.entities;
+
+    let query = entities.query_mut::<(
+        &mut Vec3,
+        &mut Direction,
+        &mut MovementAnimator,
+        &mut PathNpc,
+    )>();
+
+    for (_, (position, direction, movement_animator, npc)) in query {
+        let Some(target) = npc.points.get(npc.target_index).cloned() else {
+            continue;
+        };
+
+        let offset = target - position.xy();
+        let distance = offset.length();
+
+        if distance <= npc.speed {
+            *position = target.extend(position.z);
+            npc.advance();
+        } else {
+            *position += (offset / distance * npc.speed).extend(0.0);
+        }
+
+        if distance > 0.0 {
+            let world_direction = Direction::from_offset(offset.into());
+            *direction = map.world_direction_to_screen(world_direction);
+            movement_animator.set_state(MovementState::Walking);
+        } else {
+            movement_animator.set_state(MovementState::Idle);
+        }
+
+        let tile_position = map.world_to_tile_space(position.xy());
+        position.z = map.elevation_at(tile_position, npc.layer);
+    }
+}
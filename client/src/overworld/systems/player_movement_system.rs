@@ -19,6 +19,13 @@ pub fn system_player_movement(game_io: &mut GameIO<Globals>, scene: &mut Overwor
         .query_one_mut::<&mut MovementAnimator>(player_data.entity)
         .unwrap();
 
+    if movement_animator.forced_movement().is_some() {
+        // tiles are in control
+        movement_animator.set_state(MovementState::Idle);
+        movement_animator.queue_direction(Direction::None);
+        return;
+    }
+
     if input_direction == Direction::None {
         // a little inaccurate, immediate stop, allows for better diagonal stops on keyboard
         movement_animator.set_state(MovementState::Idle);
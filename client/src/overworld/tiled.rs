@@ -96,6 +96,17 @@ pub fn load_map<A: AssetManager>(game_io: &GameIO<Globals>, assets: &A, data: &s
                 "song" => {
                     map.set_song_path(property_value.to_string());
                 }
+                "song loop start" => {
+                    let start_ms = property_value.parse().unwrap_or_default();
+                    map.song_loop_points_mut().start_ms = start_ms;
+                }
+                "song loop end" => {
+                    let end_ms = property_value.parse().unwrap_or_default();
+                    map.song_loop_points_mut().end_ms = end_ms;
+                }
+                "minimap texture" => {
+                    map.set_minimap_texture_path(property_value.to_string());
+                }
                 _ => {
                     // println!("{}", property_name.to_lowercase());
                 }
@@ -179,7 +190,18 @@ pub fn load_map<A: AssetManager>(game_io: &GameIO<Globals>, assets: &A, data: &s
                 }
 
                 if child.attribute("gid").is_some() {
-                    let tile_object = TileObject::from(child);
+                    let mut tile_object = TileObject::from(child);
+
+                    if let ObjectType::Chest = tile_object.data.object_type {
+                        let properties = &tile_object.data.custom_properties;
+
+                        if properties.get("opened") == "true"
+                            && properties.has_property("open tile")
+                        {
+                            tile_object.tile.gid = properties.get_i32("open tile") as u32;
+                        }
+                    }
+
                     let position = tile_object.data.position;
                     let tile_position = map.world_to_tile_space(position);
                     let elevation = map.elevation_at(tile_position, i as i32);
@@ -201,8 +223,14 @@ pub fn load_map<A: AssetManager>(game_io: &GameIO<Globals>, assets: &A, data: &s
                 } else {
                     let shape_object = ShapeObject::from(child);
 
-                    map.object_entities_mut()
-                        .spawn((shape_object.data, shape_object.shape));
+                    let mut entity = EntityBuilder::new();
+                    entity.add_bundle((shape_object.data, shape_object.shape));
+
+                    if child.children().any(|c| c.tag_name().name() == "polyline") {
+                        entity.add(ObjectPath::new(child, i as i32));
+                    }
+
+                    map.object_entities_mut().spawn(entity.build());
                 }
             }
         }
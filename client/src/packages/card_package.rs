@@ -1,7 +1,8 @@
 use super::*;
+use crate::bindable::{CardCombo, CardProperties};
 use crate::lua_api::create_analytical_vm;
 use crate::resources::LocalAssetManager;
-use crate::{bindable::CardProperties, resources::ResourcePaths};
+use crate::resources::ResourcePaths;
 use rollback_mlua::{FromLua, ToLua};
 use std::cell::RefCell;
 
@@ -12,6 +13,7 @@ pub struct CardPackage {
     pub preview_texture_path: String,
     pub card_properties: CardProperties,
     pub default_codes: Vec<String>,
+    pub combos: Vec<CardCombo>,
 }
 
 impl Package for CardPackage {
@@ -87,6 +89,14 @@ impl Package for CardPackage {
                 })?,
             )?;
 
+            package_table.set(
+                "add_combo",
+                scope.create_function(|_, (_, combo): (rollback_mlua::Table, CardCombo)| {
+                    package.borrow_mut().combos.push(combo);
+                    Ok(())
+                })?,
+            )?;
+
             package_table.set(
                 "get_card_props",
                 scope.create_function(|_, _: ()| Ok(table.clone()))?,
@@ -97,7 +107,16 @@ impl Package for CardPackage {
             let mut card_properties = CardProperties::from_lua(table.clone(), &lua)?;
             card_properties.package_id = package.borrow().package_info.id.clone();
 
-            package.borrow_mut().card_properties = card_properties;
+            let mut package = package.borrow_mut();
+
+            // combos without a result produce this card
+            for combo in &mut package.combos {
+                if combo.result.package_id.is_empty() {
+                    combo.result.package_id = card_properties.package_id.clone();
+                }
+            }
+
+            package.card_properties = card_properties;
             Ok(())
         });
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::bindable::CardCombo;
 use crate::lua_api::create_analytical_vm;
 use crate::resources::LocalAssetManager;
 use std::cell::RefCell;
@@ -6,6 +7,7 @@ use std::cell::RefCell;
 #[derive(Default, Clone)]
 pub struct LibraryPackage {
     pub package_info: PackageInfo,
+    pub combos: Vec<CardCombo>,
 }
 
 impl Package for LibraryPackage {
@@ -20,7 +22,10 @@ impl Package for LibraryPackage {
     fn load_new(assets: &LocalAssetManager, package_info: PackageInfo) -> Self {
         let lua = create_analytical_vm(assets, &package_info);
 
-        let package = RefCell::new(Self { package_info });
+        let package = RefCell::new(Self {
+            package_info,
+            combos: Vec::new(),
+        });
 
         let result = lua.scope(|scope| {
             crate::lua_api::analytical_api::inject_analytical_api(&lua, scope, assets, &package)?;
@@ -38,6 +43,20 @@ impl Package for LibraryPackage {
                 })?,
             )?;
 
+            package_table.set(
+                "add_combo",
+                scope.create_function(|_, (_, combo): (rollback_mlua::Table, CardCombo)| {
+                    // library packages have no card to fall back on
+                    if combo.result.package_id.is_empty() {
+                        log::error!("combo {:?} is missing a result", combo.name);
+                        return Ok(());
+                    }
+
+                    package.borrow_mut().combos.push(combo);
+                    Ok(())
+                })?,
+            )?;
+
             package_init.call(package_table)?;
 
             Ok(())
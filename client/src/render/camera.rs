@@ -66,6 +66,15 @@ impl Camera {
         self.internal_camera.bounds()
     }
 
+    /// Changes the amount of the world in view, larger sizes zoom out
+    pub fn resize(&mut self, game_io: &GameIO<Globals>, size: Vec2) {
+        let position = self.internal_camera.position();
+
+        self.internal_camera = OrthoCamera::new(game_io, size);
+        self.internal_camera.invert_y(true);
+        self.internal_camera.set_position(position);
+    }
+
     pub fn update(&mut self, game_io: &GameIO<Globals>) {
         let last_frame_secs = (game_io.frame_duration() + game_io.sleep_duration()).as_secs_f32();
         self.slide_progress += last_frame_secs;
@@ -163,7 +172,7 @@ impl Camera {
     }
 
     pub fn clone(&self, game_io: &GameIO<Globals>) -> Self {
-        let mut internal_camera = OrthoCamera::new(game_io, RESOLUTION_F);
+        let mut internal_camera = OrthoCamera::new(game_io, self.size());
         internal_camera.invert_y(true);
 
         Self {
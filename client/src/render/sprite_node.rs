@@ -146,6 +146,18 @@ impl SpriteNode {
         Some(&self.palette_path)
     }
 
+    pub fn base_palette_path(&self) -> Option<&str> {
+        if self.base_palette_path.is_empty() {
+            return None;
+        }
+
+        Some(&self.base_palette_path)
+    }
+
+    pub fn set_base_palette_path(&mut self, path: String) {
+        self.base_palette_path = path;
+    }
+
     pub fn palette(&self) -> Option<&Arc<Texture>> {
         self.palette.as_ref()
     }
@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::Element;
 use crate::resources::*;
 use framework::prelude::*;
@@ -6,14 +7,37 @@ pub struct ElementSprite;
 
 impl ElementSprite {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(game_io: &GameIO<Globals>, element: Element) -> Sprite {
+    fn new(game_io: &GameIO<Globals>, element: Element) -> Sprite {
         let globals = game_io.globals();
         let assets = &globals.assets;
 
         let mut sprite = assets.new_sprite(game_io, ResourcePaths::ELEMENTS);
 
-        sprite.set_frame(Rect::new((element as u8 as f32) * 14.0, 0.0, 14.0, 14.0));
+        // elements registered by packages need a chart to find their icon
+        let element = if element.is_built_in() {
+            element
+        } else {
+            Element::None
+        };
+
+        sprite.set_frame(Rect::new((element.0 as f32) * 14.0, 0.0, 14.0, 14.0));
 
         sprite
     }
+
+    /// Uses the icon registered with the element, falling back to the shared element sheet
+    pub fn new_from_chart(
+        game_io: &GameIO<Globals>,
+        element_chart: &ElementChart,
+        element: Element,
+    ) -> Sprite {
+        let icon_texture_path = element_chart
+            .definition(element)
+            .and_then(|definition| definition.icon_texture_path.as_deref());
+
+        match icon_texture_path {
+            Some(texture_path) => game_io.globals().assets.new_sprite(game_io, texture_path),
+            None => Self::new(game_io, element),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::CardClass;
 use crate::packages::*;
 use crate::render::ui::*;
@@ -114,7 +115,14 @@ impl FullCard {
         if let Some(card) = card {
             let scale = (0.5 - progress).abs() * 2.0;
 
-            card.draw_preview(game_io, sprite_queue, self.preview_position, scale);
+            let element_chart = ElementChart::built_in();
+            card.draw_preview(
+                game_io,
+                sprite_queue,
+                element_chart,
+                self.preview_position,
+                scale,
+            );
         }
 
         // draw description
@@ -7,12 +7,14 @@ use framework::prelude::*;
 
 use super::{FontStyle, TextStyle};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SceneOption {
     Servers,
     Folders,
     Library,
     Character,
+    KeyItems,
+    Map,
     BattleSelect,
     Config,
 }
@@ -43,6 +45,8 @@ impl SceneOption {
             SceneOption::Folders => "FOLDERS_LABEL",
             SceneOption::Library => "LIBRARY_LABEL",
             SceneOption::Character => "CHARACTER_LABEL",
+            SceneOption::KeyItems => "KEY_ITEMS_LABEL",
+            SceneOption::Map => "MAP_LABEL",
             SceneOption::BattleSelect => "BATTLE_SELECT_LABEL",
             SceneOption::Config => "CONFIG_LABEL",
         }
@@ -69,6 +73,7 @@ pub struct NavigationMenu {
     info_sprite: Sprite,
     fade_sprite: Sprite,
     items: Vec<NavigationItem>,
+    requested_option: Option<SceneOption>,
     ui_input_tracker: UiInputTracker,
 }
 
@@ -141,6 +146,7 @@ impl NavigationMenu {
             fade_sprite,
             ui_input_tracker: UiInputTracker::new(),
             items,
+            requested_option: None,
         }
     }
 
@@ -172,6 +178,11 @@ impl NavigationMenu {
         self.animation_time = 0;
     }
 
+    /// Options without a scene are handled by the owner of the menu, such as SceneOption::Map
+    pub fn take_requested_option(&mut self) -> Option<SceneOption> {
+        self.requested_option.take()
+    }
+
     pub fn update_info(&mut self, player_data: &OverworldPlayerData) {
         self.hp_text = format!("{:>4}/{:>4}", player_data.health, player_data.max_health);
         self.money_text = format!("{:>8}$", player_data.money);
@@ -239,12 +250,23 @@ impl NavigationMenu {
 
     fn select_item(&mut self, game_io: &mut GameIO<Globals>) -> NextScene<Globals> {
         let selection = self.scroll_tracker.selected_index();
+        let target_scene = self.items[selection].target_scene;
+
+        if matches!(target_scene, SceneOption::KeyItems | SceneOption::Map) {
+            let globals = game_io.globals();
+            globals.audio.play_sound(&globals.cursor_select_sfx);
+
+            self.requested_option = Some(target_scene);
+            self.close();
+            return NextScene::None;
+        }
 
-        let scene: Option<Box<dyn Scene<Globals>>> = match self.items[selection].target_scene {
+        let scene: Option<Box<dyn Scene<Globals>>> = match target_scene {
             SceneOption::Servers => Some(ServerListScene::new(game_io)),
             SceneOption::Folders => Some(FolderListScene::new(game_io)),
             SceneOption::Library => Some(LibraryScene::new(game_io)),
             SceneOption::Character => Some(CharacterScene::new(game_io)),
+            SceneOption::KeyItems | SceneOption::Map => None,
             SceneOption::BattleSelect => Some(BattleSelectScene::new(game_io)),
             SceneOption::Config => Some(ConfigScene::new(game_io)),
         };
@@ -1,11 +1,56 @@
 use super::SoundBuffer;
+use crate::bindable::AudioPriority;
+use rodio::decoder::DecoderError;
 use rodio::{OutputStream, Source};
 use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const MAX_VOICES: usize = 16;
+
+#[derive(Clone, Copy)]
+pub struct SoundOptions {
+    pub priority: AudioPriority,
+    pub volume: f32,
+    pub pitch: f32,
+}
+
+impl Default for SoundOptions {
+    fn default() -> Self {
+        Self {
+            priority: AudioPriority::default(),
+            volume: 1.0,
+            pitch: 1.0,
+        }
+    }
+}
+
+/// An `end_ms` of 0 loops at the end of the track
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopPoints {
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+struct Voice {
+    buffer: SoundBuffer,
+    priority: AudioPriority,
+    sink: rodio::Sink,
+}
+
+struct Crossfade {
+    previous_sink: rodio::Sink,
+    start: Instant,
+    duration: Duration,
+}
 
 pub struct AudioManager {
     stream: Option<rodio::OutputStream>,
     stream_handle: Option<rodio::OutputStreamHandle>,
     music_sink: RefCell<Option<rodio::Sink>>,
+    music_buffer: RefCell<Option<SoundBuffer>>,
+    crossfade: RefCell<Option<Crossfade>>,
+    voices: RefCell<Vec<Voice>>,
     music_volume: f32,
     sfx_volume: f32,
 }
@@ -24,6 +69,23 @@ impl AudioManager {
             stream,
             stream_handle,
             music_sink: RefCell::new(None),
+            music_buffer: RefCell::new(None),
+            crossfade: RefCell::new(None),
+            voices: RefCell::new(Vec::new()),
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+        }
+    }
+
+    /// Used when running without a window, every request is ignored
+    pub fn new_disabled() -> Self {
+        Self {
+            stream: None,
+            stream_handle: None,
+            music_sink: RefCell::new(None),
+            music_buffer: RefCell::new(None),
+            crossfade: RefCell::new(None),
+            voices: RefCell::new(Vec::new()),
             music_volume: 1.0,
             sfx_volume: 1.0,
         }
@@ -51,6 +113,30 @@ impl AudioManager {
         self.sfx_volume = volume;
     }
 
+    /// Updates crossfades, should be called every frame
+    pub fn tick(&self) {
+        let mut crossfade_ref = self.crossfade.borrow_mut();
+
+        let Some(crossfade) = crossfade_ref.as_ref() else {
+            return;
+        };
+
+        let progress = crossfade.start.elapsed().as_secs_f32() / crossfade.duration.as_secs_f32();
+        let progress = progress.min(1.0);
+
+        let previous_sink = &crossfade.previous_sink;
+        previous_sink.set_volume(self.music_volume * (1.0 - progress));
+
+        if let Some(music_sink) = self.music_sink.borrow().as_ref() {
+            music_sink.set_volume(self.music_volume * progress);
+        }
+
+        if progress >= 1.0 {
+            previous_sink.stop();
+            *crossfade_ref = None;
+        }
+    }
+
     pub fn is_music_playing(&self) -> bool {
         self.music_sink
             .borrow()
@@ -59,19 +145,42 @@ impl AudioManager {
             .unwrap_or_default()
     }
 
-    pub fn play_music<MusicSource>(&self, buffer: MusicSource, loops: bool)
-    where
-        MusicSource: Clone + Send + AsRef<[u8]> + Sync + 'static,
-    {
+    /// True if the buffer is the latest track and it's still playing
+    pub fn is_playing_music_buffer(&self, buffer: &SoundBuffer) -> bool {
+        let same_buffer = self
+            .music_buffer
+            .borrow()
+            .as_ref()
+            .is_some_and(|music_buffer| Arc::ptr_eq(&music_buffer.0, &buffer.0));
+
+        same_buffer && self.is_music_playing()
+    }
+
+    pub fn play_music(&self, buffer: SoundBuffer, loops: bool) {
+        let loop_points = loops.then(LoopPoints::default);
+
+        self.crossfade_music(buffer, loop_points, Duration::ZERO);
+    }
+
+    /// Fades out the current track while fading in the new track
+    pub fn crossfade_music(
+        &self,
+        buffer: SoundBuffer,
+        loop_points: Option<LoopPoints>,
+        duration: Duration,
+    ) {
         let stream_handle = match self.stream_handle.as_ref() {
             Some(stream_handle) => stream_handle,
             None => return,
         };
 
-        if let Some(music_sink) = self.music_sink.borrow().as_ref() {
-            music_sink.stop();
+        // only one track can fade out at a time
+        if let Some(crossfade) = self.crossfade.borrow_mut().take() {
+            crossfade.previous_sink.stop();
         }
 
+        let previous_sink = self.music_sink.borrow_mut().take();
+
         let music_sink = match rodio::Sink::try_new(stream_handle) {
             Ok(music_sink) => music_sink,
             Err(e) => {
@@ -80,19 +189,43 @@ impl AudioManager {
             }
         };
 
+        *self.music_buffer.borrow_mut() = Some(buffer.clone());
+
         use std::io::{BufReader, Cursor};
-        let cursor = Cursor::new(buffer);
-        let reader = BufReader::new(cursor);
 
-        if loops {
-            match rodio::Decoder::new_looped(reader) {
+        match loop_points {
+            Some(loop_points) if loop_points != LoopPoints::default() => {
+                match LoopedMusic::new(buffer, loop_points) {
+                    Ok(music) => music_sink.append(music),
+                    Err(e) => log::error!("{e}"),
+                }
+            }
+            Some(_) => match rodio::Decoder::new_looped(BufReader::new(Cursor::new(buffer))) {
                 Ok(decoder) => music_sink.append(decoder),
                 Err(e) => log::error!("{e}"),
-            }
-        } else {
-            match rodio::Decoder::new(reader) {
+            },
+            None => match rodio::Decoder::new(BufReader::new(Cursor::new(buffer))) {
                 Ok(decoder) => music_sink.append(decoder),
                 Err(e) => log::error!("{e}"),
+            },
+        }
+
+        match previous_sink {
+            Some(previous_sink) if !duration.is_zero() && !previous_sink.empty() => {
+                music_sink.set_volume(0.0);
+
+                *self.crossfade.borrow_mut() = Some(Crossfade {
+                    previous_sink,
+                    start: Instant::now(),
+                    duration,
+                });
+            }
+            Some(previous_sink) => {
+                previous_sink.stop();
+                music_sink.set_volume(self.music_volume);
+            }
+            None => {
+                music_sink.set_volume(self.music_volume);
             }
         }
 
@@ -100,18 +233,56 @@ impl AudioManager {
     }
 
     pub fn stop_music(&self) {
+        if let Some(crossfade) = self.crossfade.borrow_mut().take() {
+            crossfade.previous_sink.stop();
+        }
+
         if let Some(music_sink) = self.music_sink.borrow().as_ref() {
             music_sink.stop();
         }
+
+        *self.music_buffer.borrow_mut() = None;
     }
 
-    // todo: AudioPriority
     pub fn play_sound(&self, buffer: &SoundBuffer) {
+        self.play_sound_with_options(buffer, SoundOptions::default());
+    }
+
+    pub fn play_sound_with_options(&self, buffer: &SoundBuffer, options: SoundOptions) {
         let stream_handle = match self.stream_handle.as_ref() {
             Some(stream_handle) => stream_handle,
             None => return,
         };
 
+        let mut voices = self.voices.borrow_mut();
+        voices.retain(|voice| !voice.sink.empty());
+
+        if options.priority == AudioPriority::Lowest
+            && voices
+                .iter()
+                .any(|voice| Arc::ptr_eq(&voice.buffer.0, &buffer.0))
+        {
+            return;
+        }
+
+        if voices.len() >= MAX_VOICES {
+            // replace the oldest sound with the lowest priority
+            let replaced_index = voices
+                .iter()
+                .enumerate()
+                .filter(|(_, voice)| {
+                    options.priority == AudioPriority::Highest || voice.priority < options.priority
+                })
+                .min_by_key(|(_, voice)| voice.priority)
+                .map(|(index, _)| index);
+
+            let Some(index) = replaced_index else {
+                return;
+            };
+
+            voices.remove(index).sink.stop();
+        }
+
         use std::io::{BufReader, Cursor};
         let cursor = Cursor::new(buffer.clone());
         let reader = BufReader::new(cursor);
@@ -123,10 +294,128 @@ impl AudioManager {
             }
         };
 
-        let res = stream_handle.play_raw(decoder.convert_samples().amplify(self.sfx_volume));
+        let sink = match rodio::Sink::try_new(stream_handle) {
+            Ok(sink) => sink,
+            Err(e) => {
+                log::error!("failed to create sound sink: {e}");
+                return;
+            }
+        };
+
+        sink.append(
+            decoder
+                .convert_samples::<f32>()
+                .amplify(self.sfx_volume * options.volume)
+                .speed(options.pitch),
+        );
+
+        voices.push(Voice {
+            buffer: buffer.clone(),
+            priority: options.priority,
+            sink,
+        });
+    }
+}
+
+type MusicDecoder = rodio::Decoder<std::io::BufReader<std::io::Cursor<SoundBuffer>>>;
+
+/// Music decoded as it plays, the loop is kept in memory as it's decoded to replay without decoding again
+struct LoopedMusic {
+    decoder: Option<MusicDecoder>,
+    channels: u16,
+    sample_rate: u32,
+    position: usize,
+    loop_start: usize,
+    loop_end: Option<usize>,
+    loop_samples: Vec<i16>,
+    loop_position: usize,
+}
+
+impl LoopedMusic {
+    fn new(buffer: SoundBuffer, loop_points: LoopPoints) -> Result<Self, DecoderError> {
+        use std::io::{BufReader, Cursor};
+
+        let decoder = rodio::Decoder::new(BufReader::new(Cursor::new(buffer)))?;
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+
+        // convert to an index aligned to the start of a frame
+        let ms_to_index = |ms: u64| {
+            let frame = ms * sample_rate as u64 / 1000;
+            frame as usize * channels as usize
+        };
+
+        let loop_end = (loop_points.end_ms > 0).then(|| ms_to_index(loop_points.end_ms));
+        let mut loop_start = ms_to_index(loop_points.start_ms);
+
+        if loop_end.is_some_and(|loop_end| loop_start >= loop_end) {
+            loop_start = 0;
+        }
+
+        Ok(Self {
+            decoder: Some(decoder),
+            channels,
+            sample_rate,
+            position: 0,
+            loop_start,
+            loop_end,
+            loop_samples: Vec::new(),
+            loop_position: 0,
+        })
+    }
+}
+
+impl Iterator for LoopedMusic {
+    type Item = i16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(decoder) = &mut self.decoder {
+            let reached_loop_end = self
+                .loop_end
+                .is_some_and(|loop_end| self.position >= loop_end);
+
+            let sample = if reached_loop_end {
+                None
+            } else {
+                decoder.next()
+            };
+
+            if let Some(sample) = sample {
+                if self.position >= self.loop_start {
+                    self.loop_samples.push(sample);
+                }
+
+                self.position += 1;
 
-        if let Err(e) = res {
-            log::error!("{e}");
+                return Some(sample);
+            }
+
+            // the loop is fully decoded, replay it from memory from now on
+            self.decoder = None;
         }
+
+        // an empty loop ends the track
+        let sample = *self.loop_samples.get(self.loop_position)?;
+        self.loop_position = (self.loop_position + 1) % self.loop_samples.len();
+
+        Some(sample)
+    }
+}
+
+impl Source for LoopedMusic {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
     }
 }
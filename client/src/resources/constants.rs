@@ -13,7 +13,7 @@ pub const TRUE_RESOLUTION: UVec2 = UVec2::new(
 pub const MAX_VOLUME: u8 = 100;
 
 pub const MAX_CARDS: usize = 30;
-pub const BATTLE_INPUTS: [Input; 11] = [
+pub const BATTLE_INPUTS: [Input; 12] = [
     Input::Up,
     Input::Down,
     Input::Left,
@@ -23,6 +23,7 @@ pub const BATTLE_INPUTS: [Input; 11] = [
     Input::Special,
     Input::ShoulderL,
     Input::ShoulderR,
+    Input::EndTurn,
     Input::Confirm,
     Input::Cancel,
 ];
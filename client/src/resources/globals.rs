@@ -1,12 +1,12 @@
-use crate::args::Args;
-use crate::battle::BattleProps;
+use crate::args::{Args, Command};
+use crate::battle::{BattleProps, PlayerSetup};
 use crate::lua_api::BattleLuaApi;
 use crate::packages::*;
 use crate::render::{Animator, BackgroundPipeline, SpritePipelineCollection};
 use crate::resources::*;
 use crate::saves::{Config, GlobalSave};
 use framework::prelude::*;
-use packets::structures::FileHash;
+use packets::structures::{FileHash, FolderRules};
 use std::collections::HashSet;
 use std::sync::Arc;
 
@@ -20,6 +20,8 @@ pub struct Globals {
     pub block_packages: PackageManager<BlockPackage>,
     pub library_packages: PackageManager<LibraryPackage>,
     pub battle_api: BattleLuaApi,
+    pub folder_rules: FolderRules,
+    pub command: Option<Command>,
 
     // sounds
     pub audio: AudioManager,
@@ -49,6 +51,7 @@ pub struct Globals {
     pub player_deleted_sfx: SoundBuffer,
     pub hurt_sfx: SoundBuffer,
     pub explode_sfx: SoundBuffer,
+    pub counter_sfx: SoundBuffer,
 
     // assets
     pub assets: LocalAssetManager,
@@ -68,14 +71,32 @@ pub struct Globals {
 
 impl Globals {
     pub fn new(game_io: &mut GameIO<Globals>, args: Args) -> Self {
-        let assets = LocalAssetManager::new(game_io);
+        let command = args.command.clone();
+
+        // commands run headless, without audio or textures
+        let headless = command.is_some();
+
+        let assets = if headless {
+            LocalAssetManager::new_headless()
+        } else {
+            LocalAssetManager::new(game_io)
+        };
+
         let font_texture = assets.texture(game_io, ResourcePaths::FONTS);
 
         let config = Config::load(&assets);
         let music_volume = config.music_volume();
         let sfx_volume = config.sfx_volume();
 
-        if config.fullscreen {
+        let audio = if headless {
+            AudioManager::new_disabled()
+        } else {
+            AudioManager::new()
+                .with_music_volume(music_volume)
+                .with_sfx_volume(sfx_volume)
+        };
+
+        if config.fullscreen && !headless {
             game_io.window_mut().set_fullscreen(true);
         }
 
@@ -89,11 +110,11 @@ impl Globals {
             block_packages: PackageManager::new(PackageCategory::Block),
             library_packages: PackageManager::new(PackageCategory::Library),
             battle_api: BattleLuaApi::new(),
+            folder_rules: FolderRules::default(),
+            command,
 
             // sounds
-            audio: AudioManager::new()
-                .with_music_volume(music_volume)
-                .with_sfx_volume(sfx_volume),
+            audio,
             battle_music: assets.audio(ResourcePaths::BATTLE_MUSIC),
             cursor_move_sfx: assets.audio(ResourcePaths::CURSOR_MOVE_SFX),
             cursor_select_sfx: assets.audio(ResourcePaths::CURSOR_SELECT_SFX),
@@ -120,6 +141,7 @@ impl Globals {
             player_deleted_sfx: assets.audio(ResourcePaths::PLAYER_DELETED_SFX),
             hurt_sfx: assets.audio(ResourcePaths::HURT_SFX),
             explode_sfx: assets.audio(ResourcePaths::EXPLODE_SFX),
+            counter_sfx: assets.audio(ResourcePaths::COUNTER_SFX),
 
             // assets
             font_texture,
@@ -145,6 +167,7 @@ impl Globals {
     // updated by the Overlay
     pub fn tick(&mut self) {
         self.network.tick();
+        self.audio.tick();
     }
 
     pub fn load_virtual_package(
@@ -191,22 +214,59 @@ impl Globals {
         self.package_or_fallback_info(category, namespace, &id)
     }
 
+    /// Loads a package from the mods folder after startup, such as packages installed from a server
+    pub fn load_local_package(
+        &mut self,
+        category: PackageCategory,
+        path: &str,
+    ) -> Option<&PackageInfo> {
+        let namespace = PackageNamespace::Local;
+
+        let package_info = match category {
+            PackageCategory::Block => {
+                self.block_packages
+                    .load_package(&self.assets, namespace, path)
+            }
+            PackageCategory::Card => self
+                .card_packages
+                .load_package(&self.assets, namespace, path),
+            PackageCategory::Battle => {
+                self.battle_packages
+                    .load_package(&self.assets, namespace, path)
+            }
+            PackageCategory::Library => {
+                self.library_packages
+                    .load_package(&self.assets, namespace, path)
+            }
+            PackageCategory::Player => {
+                self.player_packages
+                    .load_package(&self.assets, namespace, path)
+            }
+            PackageCategory::Character => {
+                log::error!("attempt to load Character package");
+                None
+            }
+        }?;
+
+        let child_packages: Vec<_> = package_info.child_packages().collect();
+        let id = package_info.id.clone();
+
+        self.character_packages
+            .load_child_packages(&self.assets, namespace, child_packages);
+
+        self.package_or_fallback_info(category, namespace, &id)
+    }
+
     pub fn battle_dependencies(&self, props: &BattleProps) -> Vec<&PackageInfo> {
         let player_package_iter = props
             .player_setups
             .iter()
             .map(|setup| setup.player_package.package_info.triplet());
 
-        let card_package_iter = props.player_setups.iter().flat_map(|setup| {
-            let ns = if setup.local {
-                PackageNamespace::Local
-            } else {
-                PackageNamespace::Remote(setup.index)
-            };
-
-            let card_iter = setup.folder.cards.iter();
-            card_iter.map(move |card| (PackageCategory::Card, ns, card.package_id.clone()))
-        });
+        let card_package_iter = props
+            .player_setups
+            .iter()
+            .flat_map(Self::card_package_triplets);
 
         let battle_package_iter = std::iter::once(props.battle_package)
             .flatten()
@@ -219,6 +279,23 @@ impl Globals {
         self.package_dependency_iter(package_iter)
     }
 
+    /// Packages for a single player's character and folder, along with their dependencies
+    pub fn player_dependencies(&self, setup: &PlayerSetup) -> Vec<&PackageInfo> {
+        let player_package_iter = std::iter::once(setup.player_package.package_info.triplet());
+        let package_iter = player_package_iter.chain(Self::card_package_triplets(setup));
+
+        self.package_dependency_iter(package_iter)
+    }
+
+    fn card_package_triplets<'a>(
+        setup: &'a PlayerSetup,
+    ) -> impl Iterator<Item = (PackageCategory, PackageNamespace, String)> + 'a {
+        let ns = setup.namespace();
+
+        let card_iter = setup.folder.cards.iter();
+        card_iter.map(move |card| (PackageCategory::Card, ns, card.package_id.clone()))
+    }
+
     pub fn package_dependency_iter<I>(&self, iter: I) -> Vec<&PackageInfo>
     where
         I: IntoIterator<Item = (PackageCategory, PackageNamespace, String)>,
@@ -19,13 +19,15 @@ pub enum Input {
     Sprint,
     ShoulderL,
     ShoulderR,
+    EndTurn,
     Minimap,
+    Chat,
     AdvanceFrame,
     RewindFrame,
 }
 
 impl Input {
-    pub const REQUIRED_INPUTS: [Input; 10] = [
+    pub const REQUIRED_INPUTS: [Input; 11] = [
         Input::Up,
         Input::Down,
         Input::Left,
@@ -36,5 +38,6 @@ impl Input {
         Input::Option,
         Input::ShoulderL,
         Input::ShoulderR,
+        Input::EndTurn,
     ];
 }
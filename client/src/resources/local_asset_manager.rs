@@ -23,18 +23,28 @@ pub struct LocalAssetManager {
     text_cache: RefCell<HashMap<String, String>>,
     texture_cache: RefCell<HashMap<String, Arc<Texture>>>,
     sound_cache: RefCell<HashMap<String, SoundBuffer>>,
+    headless: bool,
 }
 
 impl LocalAssetManager {
     pub fn new(game_io: &GameIO<Globals>) -> Self {
-        let text = HashMap::from([(ResourcePaths::BLANK.to_string(), String::new())]);
+        let manager = Self {
+            headless: false,
+            ..Self::new_headless()
+        };
 
-        let textures = HashMap::from([(
-            ResourcePaths::BLANK.to_string(),
-            RenderTarget::new(game_io, UVec2::new(1, 1))
-                .texture()
-                .clone(),
-        )]);
+        manager
+            .texture_cache
+            .borrow_mut()
+            .insert(ResourcePaths::BLANK.to_string(), blank_texture(game_io));
+
+        manager
+    }
+
+    /// Skips decoding textures and sounds, every texture and sound resolves to a blank placeholder.
+    /// Creates no textures until one is requested
+    pub fn new_headless() -> Self {
+        let text = HashMap::from([(ResourcePaths::BLANK.to_string(), String::new())]);
 
         let sounds = HashMap::from([(
             ResourcePaths::BLANK.to_string(),
@@ -44,8 +54,9 @@ impl LocalAssetManager {
         Self {
             loaded_zips: RefCell::new(HashMap::new()),
             text_cache: RefCell::new(text),
-            texture_cache: RefCell::new(textures),
+            texture_cache: RefCell::new(HashMap::new()),
             sound_cache: RefCell::new(sounds),
+            headless: true,
         }
     }
 
@@ -155,6 +166,7 @@ impl LocalAssetManager {
                     read_result
                 }
                 AssetDataType::CompressedText => unreachable!(),
+                AssetDataType::Texture if self.headless => Ok(0),
                 AssetDataType::Texture => {
                     let mut bytes = Vec::new();
                     let read_result = file.read_to_end(&mut bytes);
@@ -171,6 +183,7 @@ impl LocalAssetManager {
 
                     read_result
                 }
+                AssetDataType::Audio if self.headless => Ok(0),
                 AssetDataType::Audio => {
                     let mut bytes = Vec::new();
                     let read_result = file.read_to_end(&mut bytes);
@@ -246,6 +259,11 @@ impl AssetManager for LocalAssetManager {
 
         if let Some(texture) = texture_cache.get(path) {
             texture.clone()
+        } else if self.headless {
+            texture_cache
+                .entry(ResourcePaths::BLANK.to_string())
+                .or_insert_with(|| blank_texture(game_io))
+                .clone()
         } else {
             let bytes = fs::read(path).unwrap_or_default();
             let texture = match Texture::load_from_memory(game_io, &bytes) {
@@ -266,6 +284,8 @@ impl AssetManager for LocalAssetManager {
 
         if let Some(sound) = sound_cache.get(path) {
             sound.clone()
+        } else if self.headless {
+            sound_cache.get(ResourcePaths::BLANK).unwrap().clone()
         } else {
             let bytes = fs::read(path).unwrap_or_default();
             let sound = SoundBuffer(Arc::new(bytes));
@@ -274,3 +294,9 @@ impl AssetManager for LocalAssetManager {
         }
     }
 }
+
+fn blank_texture(game_io: &GameIO<Globals>) -> Arc<Texture> {
+    RenderTarget::new(game_io, UVec2::new(1, 1))
+        .texture()
+        .clone()
+}
@@ -1,3 +1,5 @@
+use crate::packages::PackageCategory;
+
 pub struct ResourcePaths;
 
 #[cfg(not(target_os = "android"))]
@@ -21,6 +23,11 @@ lazy_static::lazy_static! {
 impl ResourcePaths {
     pub const CACHE_FOLDER: &str = "cache/";
     pub const MOD_CACHE_FOLDER: &str = "cache/local_packages/";
+    pub const PLAYER_MODS_FOLDER: &str = "./mods/players";
+    pub const CARD_MODS_FOLDER: &str = "./mods/cards";
+    pub const BATTLE_MODS_FOLDER: &str = "./mods/enemies";
+    pub const BLOCK_MODS_FOLDER: &str = "./mods/blocks";
+    pub const LIBRARY_MODS_FOLDER: &str = "./mods/libraries";
     pub const VIRTUAL_PREFIX: &str = "/virtual/";
     pub const SEPARATOR: &str = "/";
 
@@ -54,6 +61,7 @@ impl ResourcePaths {
     pub const PLAYER_DELETED_SFX: &str = "resources/sfx/player_deleted.ogg";
     pub const HURT_SFX: &str = "resources/sfx/hurt.ogg";
     pub const EXPLODE_SFX: &str = "resources/sfx/explode.ogg";
+    pub const COUNTER_SFX: &str = "resources/sfx/counter.ogg";
 
     // General
     pub const BLANK: &str = "";
@@ -114,6 +122,14 @@ impl ResourcePaths {
     pub const OVERWORLD_WARP_ANIMATION: &str = "resources/scenes/overworld/warp.animation";
     pub const OVERWORLD_BBS: &str = "resources/scenes/overworld/bbs/bbs.png";
     pub const OVERWORLD_BBS_ANIMATION: &str = "resources/scenes/overworld/bbs/bbs.animation";
+    pub const OVERWORLD_MINIMAP_OVERLAY: &str = "resources/scenes/overworld/minimap/mm_over.png";
+    pub const OVERWORLD_MINIMAP_MARKERS: &str = "resources/scenes/overworld/minimap/markers.png";
+    pub const OVERWORLD_MINIMAP_MARKERS_ANIMATION: &str =
+        "resources/scenes/overworld/minimap/markers.animation";
+    pub const OVERWORLD_ITEMS_BG: &str = "resources/scenes/items/bg.png";
+    pub const OVERWORLD_SHOP_BG: &str = "resources/scenes/vendors/bg.png";
+    pub const OVERWORLD_SHOP_LIST: &str = "resources/scenes/vendors/list.png";
+    pub const OVERWORLD_SHOP_MONEY: &str = "resources/scenes/vendors/price.png";
 
     // FoldersScene
     pub const FOLDERS_BG: &str = "resources/scenes/folder_list/bg.png";
@@ -181,13 +197,28 @@ impl ResourcePaths {
     pub const BATTLE_EXPLOSION_ANIMATION: &str = "resources/scenes/battle/explosion.animation";
     pub const BATTLE_SPLASH: &str = "resources/scenes/battle/splash.png";
     pub const BATTLE_SPLASH_ANIMATION: &str = "resources/scenes/battle/splash.animation";
+    pub const BATTLE_EMOTIONS: &str = "resources/scenes/battle/emotions.png";
     pub const BATTLE_STATUSES: &str = "resources/scenes/battle/statuses.png";
     pub const BATTLE_STATUSES_ANIMATION: &str = "resources/scenes/battle/statuses.animation";
+    pub const BATTLE_COUNTER_REVEAL: &str = "resources/scenes/battle/counter_reveal.png";
+    pub const BATTLE_COUNTER_REVEAL_ANIMATION: &str =
+        "resources/scenes/battle/counter_reveal.animation";
 
     // ConfigScene
     pub const CONFIG_BG: &str = "resources/scenes/config/bg.png";
     pub const CONFIG_BG_ANIMATION: &str = "resources/scenes/config/bg.animation";
 
+    pub fn mods_folder(category: PackageCategory) -> Option<&'static str> {
+        match category {
+            PackageCategory::Block => Some(Self::BLOCK_MODS_FOLDER),
+            PackageCategory::Card => Some(Self::CARD_MODS_FOLDER),
+            PackageCategory::Battle => Some(Self::BATTLE_MODS_FOLDER),
+            PackageCategory::Library => Some(Self::LIBRARY_MODS_FOLDER),
+            PackageCategory::Player => Some(Self::PLAYER_MODS_FOLDER),
+            PackageCategory::Character => None,
+        }
+    }
+
     pub fn game_folder() -> &'static str {
         &GAME_PATH
     }
@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::Element;
 use crate::packages::*;
 use crate::render::ui::*;
@@ -20,6 +21,16 @@ impl Card {
         game_io: &GameIO<Globals>,
         sprite_queue: &mut SpriteColorQueue,
         position: Vec2,
+    ) {
+        self.draw_scaled_icon(game_io, sprite_queue, position, Vec2::ONE);
+    }
+
+    pub fn draw_scaled_icon(
+        &self,
+        game_io: &GameIO<Globals>,
+        sprite_queue: &mut SpriteColorQueue,
+        position: Vec2,
+        scale: Vec2,
     ) {
         let globals = game_io.globals();
         let assets = &globals.assets;
@@ -42,6 +53,7 @@ impl Card {
 
         let mut sprite = Sprite::new(icon_texture, globals.default_sampler.clone());
         sprite.set_position(position);
+        sprite.set_scale(scale);
         sprite_queue.draw_sprite(&sprite);
     }
 
@@ -71,6 +83,7 @@ impl Card {
         &self,
         game_io: &GameIO<Globals>,
         sprite_queue: &mut SpriteColorQueue,
+        element_chart: &ElementChart,
         position: Vec2,
         scale: f32,
     ) {
@@ -104,8 +117,11 @@ impl Card {
 
         // secondary_element
         if package.card_properties.secondary_element != Element::None {
-            let mut element_sprite =
-                ElementSprite::new(game_io, package.card_properties.secondary_element);
+            let mut element_sprite = ElementSprite::new_from_chart(
+                game_io,
+                element_chart,
+                package.card_properties.secondary_element,
+            );
             element_sprite.set_origin(-ELEMENT2_OFFSET);
             element_sprite.set_position(position);
             element_sprite.set_scale(scale);
@@ -113,7 +129,8 @@ impl Card {
         }
 
         // element
-        let mut element_sprite = ElementSprite::new(game_io, package.card_properties.element);
+        let mut element_sprite =
+            ElementSprite::new_from_chart(game_io, element_chart, package.card_properties.element);
         element_sprite.set_origin(-ELEMENT_OFFSET);
         element_sprite.set_position(position);
         element_sprite.set_scale(scale);
@@ -191,7 +208,8 @@ impl Card {
         label.draw(game_io, sprite_queue, short_name);
 
         // element
-        let mut element_sprite = ElementSprite::new(game_io, element);
+        let element_chart = ElementChart::built_in();
+        let mut element_sprite = ElementSprite::new_from_chart(game_io, element_chart, element);
         element_sprite.set_position(ELEMENT_OFFSET + position);
         sprite_queue.draw_sprite(&element_sprite);
 
@@ -45,7 +45,11 @@ impl Config {
             return default_config;
         }
 
-        let config = Config::from(config_text.as_str());
+        let mut config = Config::from(config_text.as_str());
+
+        if config.patch_missing_bindings() {
+            config.save();
+        }
 
         if !config.validate() {
             // todo: don't override the entire config file, patch out / correct what we have
@@ -60,6 +64,50 @@ impl Config {
         let _ = std::fs::write("config.ini", self.to_string());
     }
 
+    /// Binds required inputs missing from older config files to their defaults when free,
+    /// returns true if any binding was added
+    fn patch_missing_bindings(&mut self) -> bool {
+        let default_config = Config::default();
+
+        let patched_keys =
+            Config::patch_bindings(&mut self.key_bindings, &default_config.key_bindings);
+
+        let patched_buttons = Config::patch_bindings(
+            &mut self.controller_bindings,
+            &default_config.controller_bindings,
+        );
+
+        patched_keys || patched_buttons
+    }
+
+    fn patch_bindings<V: std::cmp::PartialEq + Clone>(
+        bindings: &mut HashMap<Input, V>,
+        default_bindings: &HashMap<Input, V>,
+    ) -> bool {
+        let mut patched = false;
+
+        for input in Input::REQUIRED_INPUTS {
+            if bindings.contains_key(&input) {
+                continue;
+            }
+
+            let Some(default_value) = default_bindings.get(&input) else {
+                continue;
+            };
+
+            let taken = Input::REQUIRED_INPUTS
+                .iter()
+                .any(|other_input| bindings.get(other_input) == Some(default_value));
+
+            if !taken {
+                bindings.insert(input, default_value.clone());
+                patched = true;
+            }
+        }
+
+        patched
+    }
+
     fn validate_bindings<V: std::cmp::PartialEq>(bindings: &HashMap<Input, V>) -> bool {
         let required_bindings = Input::REQUIRED_INPUTS
             .iter()
@@ -87,6 +135,7 @@ impl Default for Config {
             (Input::Down, Key::S),
             (Input::ShoulderL, Key::Q),
             (Input::ShoulderR, Key::E),
+            (Input::EndTurn, Key::R),
             (Input::Confirm, Key::Space),
             (Input::UseCard, Key::Space),
             (Input::Cancel, Key::LShift),
@@ -95,6 +144,7 @@ impl Default for Config {
             (Input::Option, Key::F),
             (Input::Special, Key::F),
             (Input::Minimap, Key::M),
+            (Input::Chat, Key::Return),
             (Input::Pause, Key::Escape),
             (Input::RewindFrame, Key::Left),
             (Input::AdvanceFrame, Key::Right),
@@ -107,6 +157,7 @@ impl Default for Config {
             (Input::Down, Button::DPadDown),
             (Input::ShoulderL, Button::LeftTrigger),
             (Input::ShoulderR, Button::RightTrigger),
+            (Input::EndTurn, Button::RightStick),
             (Input::Confirm, Button::A),
             (Input::UseCard, Button::A),
             (Input::Cancel, Button::B),
@@ -1,5 +1,9 @@
+use crate::packages::PackageNamespace;
+use crate::resources::Globals;
 use crate::saves::Card;
+use framework::prelude::GameIO;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Folder {
@@ -17,7 +21,55 @@ impl Folder {
         }
     }
 
-    // todo: handle card classes, likely will need to take in game_io and namespace for resolving card classes
+    /// Indices of cards exceeding copy or class limits
+    pub fn invalid_card_indices(&self, game_io: &GameIO<Globals>) -> Vec<usize> {
+        Self::find_invalid_cards(game_io, self.cards.iter().map(Some))
+    }
+
+    /// Empty slots are skipped while keeping their index, cards past a limit are flagged
+    pub fn find_invalid_cards<'a>(
+        game_io: &GameIO<Globals>,
+        cards: impl IntoIterator<Item = Option<&'a Card>>,
+    ) -> Vec<usize> {
+        let globals = game_io.globals();
+        let card_packages = &globals.card_packages;
+
+        let mut copy_counts: HashMap<&str, usize> = HashMap::new();
+        let mut class_counts = HashMap::new();
+        let mut invalid_indices = Vec::new();
+
+        for (i, card) in cards.into_iter().enumerate() {
+            let Some(card) = card else {
+                continue;
+            };
+
+            let Some(package) =
+                card_packages.package_or_fallback(PackageNamespace::Server, &card.package_id)
+            else {
+                continue;
+            };
+
+            let card_properties = &package.card_properties;
+            let card_class = card_properties.card_class;
+
+            let copy_count = copy_counts.entry(&card.package_id).or_default();
+            *copy_count += 1;
+
+            let class_count = class_counts.entry(card_class as u8).or_insert(0);
+            *class_count += 1;
+
+            let over_class_limit = card_class
+                .folder_limit(&globals.folder_rules)
+                .is_some_and(|limit| *class_count > limit);
+
+            if *copy_count > card_properties.limit || over_class_limit {
+                invalid_indices.push(i);
+            }
+        }
+
+        invalid_indices
+    }
+
     pub fn shuffle(&mut self, rng: &mut impl rand::Rng) {
         use rand::seq::SliceRandom;
 
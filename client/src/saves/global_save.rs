@@ -1,4 +1,4 @@
-use super::{Folder, ServerInfo};
+use super::{Folder, MutedPlayer, ServerInfo};
 use crate::packages::*;
 use crate::resources::{AssetManager, Globals};
 use framework::prelude::GameIO;
@@ -11,6 +11,8 @@ pub struct GlobalSave {
     pub folders: Vec<Folder>,
     pub selected_folder: usize,
     pub server_list: Vec<ServerInfo>,
+    #[serde(default)]
+    pub muted_players: Vec<MutedPlayer>,
 }
 
 impl GlobalSave {
@@ -79,6 +81,7 @@ impl Default for GlobalSave {
             folders: Vec::new(),
             selected_folder: 0,
             server_list: Vec::new(),
+            muted_players: Vec::new(),
         }
     }
 }
@@ -2,10 +2,12 @@ mod card;
 mod config;
 mod folder;
 mod global_save;
+mod muted_player;
 mod server_info;
 
 pub use card::*;
 pub use config::*;
 pub use folder::*;
 pub use global_save::*;
+pub use muted_player::*;
 pub use server_info::*;
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MutedPlayer {
+    pub name: String,
+    pub identity_hash: String,
+}
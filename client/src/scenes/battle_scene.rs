@@ -1,11 +1,12 @@
 use crate::battle::*;
 use crate::bindable::SpriteColorMode;
 use crate::lua_api::{battle_init, create_battle_vm};
-use crate::packages::{Package, PackageInfo};
+use crate::packages::{Package, PackageCategory, PackageInfo, PackageNamespace};
 use crate::render::*;
 use crate::resources::*;
 use framework::prelude::*;
 use packets::NetplayPacket;
+use std::cell::RefCell;
 use std::collections::VecDeque;
 
 const SLOW_COOLDOWN: FrameTime = INPUT_BUFFER_LIMIT as FrameTime;
@@ -96,6 +97,9 @@ impl BattleScene {
             battle_init(context);
         }
 
+        // battle packages may resize the field
+        scene.simulation.fit_camera_to_field(game_io);
+
         // load the players in the correct order
         for setup in props.player_setups.iter_mut() {
             // shuffle cards
@@ -111,8 +115,18 @@ impl BattleScene {
                 std::mem::take(&mut setup.folder.cards),
             );
 
-            if let Err(e) = result {
-                log::error!("{e}");
+            match result {
+                Ok(entity_id) => {
+                    let emotion_id = setup.emotion as usize;
+                    Player::set_emotion(&mut scene.simulation, entity_id, emotion_id);
+
+                    let entities = &mut scene.simulation.entities;
+
+                    if let Ok(entity) = entities.query_one_mut::<&mut Entity>(entity_id.into()) {
+                        entity.team = setup.team;
+                    }
+                }
+                Err(e) => log::error!("{e}"),
             }
 
             if setup.local {
@@ -131,11 +145,68 @@ impl BattleScene {
     }
 
     fn load_vms(&mut self, game_io: &GameIO<Globals>, props: &BattleProps) {
-        let dependencies = game_io.globals().battle_dependencies(props);
+        let globals = game_io.globals();
+        let dependencies = globals.battle_dependencies(props);
+        let mut combo_results = Vec::new();
 
         for package_info in dependencies {
             self.load_vm(game_io, package_info);
         }
+
+        // combos only apply to the cards of the player with the package defining them
+        for setup in &props.player_setups {
+            let namespace = setup.namespace();
+
+            for package_info in globals.player_dependencies(setup) {
+                self.load_combos(game_io, namespace, package_info, &mut combo_results);
+            }
+        }
+
+        // combo results may not be in any folder
+        for package_info in globals.package_dependency_iter(combo_results) {
+            self.load_vm(game_io, package_info);
+        }
+    }
+
+    fn load_combos(
+        &mut self,
+        game_io: &GameIO<Globals>,
+        player_namespace: PackageNamespace,
+        package_info: &PackageInfo,
+        combo_results: &mut Vec<(PackageCategory, PackageNamespace, String)>,
+    ) {
+        let globals = game_io.globals();
+        let ns = package_info.namespace;
+        let id = &package_info.id;
+
+        let combos = match package_info.package_category {
+            PackageCategory::Card => globals
+                .card_packages
+                .package(ns, id)
+                .map(|package| &package.combos),
+            PackageCategory::Library => globals
+                .library_packages
+                .package(ns, id)
+                .map(|package| &package.combos),
+            _ => None,
+        };
+
+        let card_combos = (self.simulation.card_combos)
+            .entry(player_namespace)
+            .or_default();
+
+        for combo in combos.into_iter().flatten() {
+            let result_triplet = (PackageCategory::Card, ns, combo.result.package_id.clone());
+
+            if !combo_results.contains(&result_triplet) {
+                combo_results.push(result_triplet);
+            }
+
+            // the same package can be a dependency of multiple packages
+            if !card_combos.contains(combo) {
+                card_combos.push(combo.clone());
+            }
+        }
     }
 
     fn find_vm(&self, package_info: &PackageInfo) -> Option<usize> {
@@ -150,6 +221,11 @@ impl BattleScene {
         if let Some(vm_index) = existing_vm {
             let vm = &mut self.vms[vm_index];
 
+            if vm.namespace == package_info.namespace {
+                // already loaded, such as a combo result that's also in a folder
+                return vm_index;
+            }
+
             if vm.namespace > package_info.namespace {
                 // drop the vm to a lower namespace to make it more accessible
                 vm.namespace = package_info.namespace;
@@ -477,6 +553,95 @@ impl BattleScene {
         }
     }
 
+    /// Simulates without waiting on the network, the local player is fed by the input script
+    pub fn simulate_headless(
+        &mut self,
+        game_io: &GameIO<Globals>,
+        input_script: &InputScript,
+        frames: FrameTime,
+    ) {
+        for _ in 0..frames {
+            if self.simulation.exit {
+                break;
+            }
+
+            let pressed = input_script.inputs_at(self.simulation.time);
+
+            if let Some(controller) = self.player_controllers.get_mut(self.local_index) {
+                controller.input_buffer.push_back(pressed);
+            }
+
+            self.simulate(game_io);
+        }
+    }
+
+    pub fn simulation(&self) -> &BattleSimulation {
+        &self.simulation
+    }
+
+    /// Skips the intro and card select
+    pub fn skip_to_battle(&mut self) {
+        self.simulation.intro_complete = true;
+        self.state = Box::new(BattleState::new());
+    }
+
+    /// Loads the package and its dependencies, returns the vm index for the package
+    pub fn load_package_vm(
+        &mut self,
+        game_io: &GameIO<Globals>,
+        package_info: &PackageInfo,
+    ) -> usize {
+        let globals = game_io.globals();
+        let dependencies = globals.package_dependency_iter([package_info.triplet()]);
+        let mut combo_results = Vec::new();
+
+        for dependency_info in dependencies {
+            self.load_vm(game_io, dependency_info);
+            let namespace = package_info.namespace;
+            self.load_combos(game_io, namespace, dependency_info, &mut combo_results);
+        }
+
+        // combo results may not be in any folder
+        for dependency_info in globals.package_dependency_iter(combo_results) {
+            self.load_vm(game_io, dependency_info);
+        }
+
+        match self.find_vm(package_info) {
+            Some(vm_index) => vm_index,
+            None => self.load_vm(game_io, package_info),
+        }
+    }
+
+    /// Calls into a vm with access to the battle api
+    pub fn call_lua<F>(
+        &mut self,
+        game_io: &GameIO<Globals>,
+        vm_index: usize,
+        callback: F,
+    ) -> rollback_mlua::Result<()>
+    where
+        F: FnOnce(&rollback_mlua::Lua) -> rollback_mlua::Result<()>,
+    {
+        let lua = &self.vms[vm_index].lua;
+
+        let api_ctx = RefCell::new(BattleScriptContext {
+            vm_index,
+            vms: &self.vms,
+            game_io,
+            simulation: &mut self.simulation,
+        });
+
+        let lua_api = &game_io.globals().battle_api;
+        let mut result = Ok(());
+
+        lua_api.inject_dynamic(lua, &api_ctx, |lua| {
+            result = callback(lua);
+            Ok(())
+        });
+
+        result
+    }
+
     fn detect_exit_request(&self) -> bool {
         self.backups
             .front()
@@ -1,3 +1,4 @@
+use crate::args::Command;
 use crate::bindable::SpriteColorMode;
 use crate::packages::*;
 use crate::render::ui::{FontStyle, Text, TextStyle};
@@ -6,7 +7,7 @@ use crate::resources::*;
 use framework::logging::LogRecord;
 use framework::prelude::*;
 
-use super::MainMenuScene;
+use super::{MainMenuScene, PackageTestScene, SimulateScene};
 
 const LOG_MARGIN: f32 = 2.0;
 
@@ -75,7 +76,7 @@ impl BootScene {
             let mut player_packages = PackageManager::<PlayerPackage>::new(PackageCategory::Player);
             player_packages.load_packages_in_folder(
                 &thread_assets,
-                "./mods/players",
+                ResourcePaths::PLAYER_MODS_FOLDER,
                 |progress, total| {
                     let status_update = StatusUpdate {
                         label: "Loading Players",
@@ -95,7 +96,7 @@ impl BootScene {
             let mut card_packages = PackageManager::<CardPackage>::new(PackageCategory::Card);
             card_packages.load_packages_in_folder(
                 &thread_assets,
-                "./mods/cards",
+                ResourcePaths::CARD_MODS_FOLDER,
                 |progress, total| {
                     let status_update = StatusUpdate {
                         label: "Loading Cards",
@@ -115,7 +116,7 @@ impl BootScene {
             let mut battle_packages = PackageManager::<BattlePackage>::new(PackageCategory::Battle);
             battle_packages.load_packages_in_folder(
                 &thread_assets,
-                "./mods/enemies",
+                ResourcePaths::BATTLE_MODS_FOLDER,
                 |progress, total| {
                     let status_update = StatusUpdate {
                         label: "Loading Battles",
@@ -135,7 +136,7 @@ impl BootScene {
             let mut block_packages = PackageManager::<BlockPackage>::new(PackageCategory::Block);
             block_packages.load_packages_in_folder(
                 &thread_assets,
-                "./mods/blocks",
+                ResourcePaths::BLOCK_MODS_FOLDER,
                 |progress, total| {
                     let status_update = StatusUpdate {
                         label: "Loading Blocks",
@@ -154,7 +155,7 @@ impl BootScene {
                 PackageManager::<LibraryPackage>::new(PackageCategory::Library);
             library_packages.load_packages_in_folder(
                 &thread_assets,
-                "./mods/libraries",
+                ResourcePaths::LIBRARY_MODS_FOLDER,
                 |progress, total| {
                     let status_update = StatusUpdate {
                         label: "Loading Libraries",
@@ -310,6 +311,21 @@ impl Scene<Globals> for BootScene {
             return;
         }
 
+        // skip the menus when running from the command line
+        if self.done {
+            match &game_io.globals().command {
+                Some(Command::Simulate(_)) => {
+                    self.next_scene = NextScene::new_swap(SimulateScene::new());
+                    return;
+                }
+                Some(Command::Test(_)) => {
+                    self.next_scene = NextScene::new_swap(PackageTestScene::new());
+                    return;
+                }
+                None => {}
+            }
+        }
+
         let input_util = InputUtil::new(game_io);
 
         // transfer to the next scene
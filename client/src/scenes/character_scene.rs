@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::SpriteColorMode;
 use crate::packages::PlayerPackage;
 use crate::render::ui::{
@@ -193,7 +194,9 @@ impl StatusPage {
         page.sprites.push(page_sprite);
 
         if let Some(point) = layout_animator.point("ELEMENT") {
-            let mut sprite = ElementSprite::new(game_io, player_package.element);
+            let element_chart = ElementChart::built_in();
+            let mut sprite =
+                ElementSprite::new_from_chart(game_io, element_chart, player_package.element);
             sprite.set_position(point);
             page.sprites.push(sprite);
         }
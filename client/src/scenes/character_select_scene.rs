@@ -1,3 +1,4 @@
+use crate::battle::ElementChart;
 use crate::bindable::SpriteColorMode;
 use crate::packages::{PackageNamespace, PlayerPackage};
 use crate::render::ui::{
@@ -105,7 +106,9 @@ impl CharacterSelectScene {
         health_ui.snap_health(player_package.health);
 
         // element_sprite
-        let mut element_sprite = ElementSprite::new(game_io, player_package.element);
+        let element_chart = ElementChart::built_in();
+        let mut element_sprite =
+            ElementSprite::new_from_chart(game_io, element_chart, player_package.element);
         element_sprite.set_position(bg_animator.point("ELEMENT").unwrap_or_default());
 
         // name_position
@@ -209,7 +212,9 @@ impl CharacterSelectScene {
             self.health_ui.set_health(player_package.health);
 
             let old_element_position = self.element_sprite.position();
-            self.element_sprite = ElementSprite::new(game_io, player_package.element);
+            let element_chart = ElementChart::built_in();
+            self.element_sprite =
+                ElementSprite::new_from_chart(game_io, element_chart, player_package.element);
             self.element_sprite.set_position(old_element_position);
         }
 
@@ -61,6 +61,7 @@ impl FolderEditScene {
             ResourcePaths::FOLDER_DOCK_ANIMATION,
         );
         folder_dock.update_card_count();
+        folder_dock.flag_invalid_cards = true;
 
         // pack_dock
         let pack_dock = Dock::new(
@@ -80,6 +81,14 @@ impl FolderEditScene {
 
         let (event_sender, event_receiver) = flume::unbounded();
 
+        let mut textbox = Textbox::new_navigation(game_io);
+
+        if !folder.invalid_card_indices(game_io).is_empty() {
+            // folders can break rules sent by the server after they were built
+            let message = String::from("Some cards break the folder rules and are marked with !");
+            show_error(&mut textbox, game_io, message);
+        }
+
         Self {
             folder_index,
             camera,
@@ -104,7 +113,7 @@ impl FolderEditScene {
             folder_dock,
             pack_dock,
             folder_size_sprite,
-            textbox: Textbox::new_navigation(game_io),
+            textbox,
             event_sender,
             event_receiver,
             next_scene: NextScene::None,
@@ -382,7 +391,7 @@ fn handle_context_menu_input(scene: &mut FolderEditScene, game_io: &mut GameIO<G
                 .package_or_fallback(PackageNamespace::Server, &item.card.package_id)
                 .unwrap();
 
-            package.card_properties.element as u8
+            package.card_properties.element.0
         }),
         FolderSorting::Number => {
             sort_card_items(card_items, |item: &CardListItem| -(item.count as isize))
@@ -404,6 +413,8 @@ fn handle_context_menu_input(scene: &mut FolderEditScene, game_io: &mut GameIO<G
 
     // blanks should always be at the bottom
     card_items.sort_by_key(|item| !item.is_some());
+
+    scene.folder_dock.invalidate_card_flags();
 }
 
 fn sort_card_items<F, K>(card_items: &mut [Option<CardListItem>], key_function: F)
@@ -422,6 +433,7 @@ fn dock_internal_swap(scene: &mut FolderEditScene, game_io: &GameIO<Globals>, in
             transfer_to_pack(scene, index);
         } else {
             scene.folder_dock.card_items.swap(selected_index, index);
+            scene.folder_dock.invalidate_card_flags();
         }
     } else {
         let selected_index = scene.pack_dock.scroll_tracker.selected_index();
@@ -493,15 +505,48 @@ fn transfer_to_folder(
         })
         .count();
 
-    let card_manager = &game_io.globals().card_packages;
+    let globals = game_io.globals();
+    let card_manager = &globals.card_packages;
     let package =
         card_manager.package_or_fallback(PackageNamespace::Server, &card_item.card.package_id)?;
+    let card_properties = &package.card_properties;
 
-    if existing_count >= package.card_properties.limit {
+    if existing_count >= card_properties.limit {
         // folder already has too many
+        let message = format!(
+            "Only {} copies of {} are allowed.",
+            card_properties.limit, card_properties.short_name
+        );
+
+        show_error(&mut scene.textbox, game_io, message);
         return None;
     }
 
+    // maintain class requirement
+    let card_class = card_properties.card_class;
+
+    if let Some(class_limit) = card_class.folder_limit(&globals.folder_rules) {
+        let class_count = folder_card_items
+            .iter()
+            .flatten()
+            .flat_map(|item| {
+                card_manager.package_or_fallback(PackageNamespace::Server, &item.card.package_id)
+            })
+            .filter(|package| package.card_properties.card_class == card_class)
+            .count();
+
+        if class_count >= class_limit {
+            let message = format!(
+                "Only {} {} cards are allowed.",
+                class_limit,
+                card_class.name()
+            );
+
+            show_error(&mut scene.textbox, game_io, message);
+            return None;
+        }
+    }
+
     // search for an empty slot to insert the card into
     let empty_index = folder_card_items
         .iter_mut()
@@ -529,6 +574,14 @@ fn transfer_to_folder(
     Some(empty_index)
 }
 
+fn show_error(textbox: &mut Textbox, game_io: &GameIO<Globals>, message: String) {
+    let globals = game_io.globals();
+    globals.audio.play_sound(&globals.cursor_error_sfx);
+
+    textbox.push_interface(TextboxMessage::new(message));
+    textbox.open();
+}
+
 fn transfer_to_pack(scene: &mut FolderEditScene, from_index: usize) -> Option<usize> {
     let card = scene
         .folder_dock
@@ -566,6 +619,9 @@ fn transfer_to_pack(scene: &mut FolderEditScene, from_index: usize) -> Option<us
 struct Dock {
     card_items: Vec<Option<CardListItem>>,
     card_count: usize,
+    flag_invalid_cards: bool,
+    /// Cached until the cards change
+    invalid_indices: Option<Vec<usize>>,
     scroll_tracker: ScrollTracker,
     dock_sprite: Sprite,
     dock_animator: Animator,
@@ -625,6 +681,8 @@ impl Dock {
         let mut dock = Self {
             card_items,
             card_count: 0,
+            flag_invalid_cards: false,
+            invalid_indices: None,
             scroll_tracker,
             dock_sprite,
             dock_animator,
@@ -662,6 +720,12 @@ impl Dock {
         }
 
         self.card_count = self.card_items.iter().filter(|item| item.is_some()).count();
+        self.invalidate_card_flags();
+    }
+
+    /// Required after changing the order or contents of the dock
+    fn invalidate_card_flags(&mut self) {
+        self.invalid_indices = None;
     }
 
     fn update_preview(&mut self) -> Option<()> {
@@ -696,6 +760,17 @@ impl Dock {
 
         self.card_preview.set_position(card_position);
 
+        if self.flag_invalid_cards && self.invalid_indices.is_none() {
+            let cards = self
+                .card_items
+                .iter()
+                .map(|item| item.as_ref().map(|item| &item.card));
+
+            self.invalid_indices = Some(Folder::find_invalid_cards(game_io, cards));
+        }
+
+        let invalid_indices = self.invalid_indices.as_deref().unwrap_or_default();
+
         // draw list items
         for i in self.scroll_tracker.view_range() {
             let card_item = match &self.card_items[i] {
@@ -708,7 +783,8 @@ impl Dock {
             let mut position = self.list_position + offset;
             position.y += relative_index as f32 * self.scroll_tracker.cursor_multiplier();
 
-            card_item.draw_list_item(game_io, sprite_queue, position);
+            let invalid = invalid_indices.contains(&i);
+            card_item.draw_list_item(game_io, sprite_queue, position, invalid);
         }
 
         // draw scrollbar
@@ -787,15 +863,25 @@ impl CardListItem {
         game_io: &GameIO<Globals>,
         sprite_queue: &mut SpriteColorQueue,
         position: Vec2,
+        invalid: bool,
     ) {
         self.card.draw_list_item(game_io, sprite_queue, position);
 
+        const COUNT_OFFSET: Vec2 = Vec2::new(120.0, 3.0);
+
+        if invalid {
+            let mut label = Text::new(game_io, FontStyle::Thick);
+            label.style.shadow_color = TEXT_DARK_SHADOW_COLOR;
+            label.style.color = Color::RED;
+            label.style.bounds.set_position(COUNT_OFFSET + position);
+            label.text = String::from(" !");
+            label.draw(game_io, sprite_queue);
+        }
+
         if !self.show_count {
             return;
         }
 
-        const COUNT_OFFSET: Vec2 = Vec2::new(120.0, 3.0);
-
         let mut label = Text::new(game_io, FontStyle::Thick);
         label.style.shadow_color = TEXT_DARK_SHADOW_COLOR;
         label.style.bounds.set_position(COUNT_OFFSET + position);
@@ -1,10 +1,11 @@
-use super::OverworldOnlineScene;
+use super::{OverworldOnlineScene, ServerTransfer};
 use crate::bindable::SpriteColorMode;
 use crate::packages::PackageNamespace;
 use crate::render::ui::*;
 use crate::render::*;
 use crate::resources::*;
 use framework::prelude::*;
+use packets::structures::FolderRules;
 use packets::{ClientPacket, Reliability, ServerPacket, SERVER_TICK_RATE};
 
 enum Event {
@@ -19,6 +20,7 @@ pub struct InitialConnectScene {
     bg_animator: Animator,
     address: String,
     data: Option<String>,
+    transfer: Option<ServerTransfer>,
     online_scene: Option<OverworldOnlineScene>,
     task: AsyncTask<()>,
     event_sender: flume::Sender<Event>,
@@ -117,6 +119,7 @@ impl InitialConnectScene {
             bg_animator,
             address,
             data,
+            transfer: None,
             online_scene: None,
             task,
             event_sender,
@@ -127,6 +130,12 @@ impl InitialConnectScene {
             next_scene: NextScene::None,
         }
     }
+
+    /// Used for server to server transfers, carries the player over to the next server
+    pub fn with_transfer(mut self, transfer: ServerTransfer) -> Self {
+        self.transfer = Some(transfer);
+        self
+    }
 }
 
 impl Scene<Globals> for InitialConnectScene {
@@ -182,7 +191,7 @@ impl Scene<Globals> for InitialConnectScene {
         while let Ok(event) = self.event_receiver.try_recv() {
             match event {
                 Event::ReceivedPayloadSize(send_packet, packet_receiver, max_payload_size) => {
-                    let online_scene = OverworldOnlineScene::new(
+                    let mut online_scene = OverworldOnlineScene::new(
                         game_io,
                         self.address.clone(),
                         max_payload_size,
@@ -190,7 +199,10 @@ impl Scene<Globals> for InitialConnectScene {
                         packet_receiver,
                     );
 
-                    online_scene.start_connection(game_io, self.data.take());
+                    let transfer_token = (self.transfer.take())
+                        .map(|transfer| online_scene.receive_transfer(transfer));
+
+                    online_scene.start_connection(game_io, self.data.take(), transfer_token);
 
                     self.online_scene = Some(online_scene);
                 }
@@ -219,6 +231,7 @@ impl Scene<Globals> for InitialConnectScene {
         if self.bg_animator.is_complete() && self.success {
             let globals = game_io.globals_mut();
             globals.remove_namespace(PackageNamespace::Server);
+            globals.folder_rules = FolderRules::default();
 
             let mut online_scene = self.online_scene.take().unwrap();
 
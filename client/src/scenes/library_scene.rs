@@ -32,8 +32,12 @@ impl LibraryScene {
 
         let background_sprite = assets.new_sprite(game_io, ResourcePaths::LIBRARY_BG);
 
-        // docks
-        let docks = vec![Dock::new(game_io, CardClass::Standard)];
+        // docks, skipping classes without cards
+        let docks: Vec<_> = CardClass::LIST
+            .into_iter()
+            .map(|card_class| Dock::new(game_io, card_class))
+            .filter(|dock| dock.card_class == CardClass::Standard || !dock.cards.is_empty())
+            .collect();
 
         // card
         let card_position = background_animator.point("card").unwrap_or_default();
@@ -72,6 +76,7 @@ impl LibraryScene {
         }
 
         let input_util = InputUtil::new(game_io);
+        let original_dock = self.active_dock;
 
         if input_util.was_just_pressed(Input::Right) && self.active_dock + 1 < self.docks.len() {
             self.active_dock += 1;
@@ -81,6 +86,13 @@ impl LibraryScene {
             self.active_dock -= 1;
         }
 
+        if original_dock != self.active_dock {
+            self.update_preview();
+
+            let globals = game_io.globals();
+            globals.audio.play_sound(&globals.page_turn_sfx);
+        }
+
         // cancelling
         if input_util.was_just_pressed(Input::Cancel) {
             let transition = crate::transitions::new_scene_pop(game_io);
@@ -91,15 +103,13 @@ impl LibraryScene {
         }
     }
 
-    fn update_preview(&mut self) -> Option<()> {
+    fn update_preview(&mut self) {
         let active_dock = &mut self.docks[self.active_dock];
 
         let selected_index = active_dock.scroll_tracker.selected_index();
-        let card = active_dock.cards.get(selected_index)?;
-
-        self.card_preview.set_card(Some(card.clone()));
+        let card = active_dock.cards.get(selected_index);
 
-        None
+        self.card_preview.set_card(card.cloned());
     }
 }
 
@@ -126,10 +136,19 @@ impl Scene<Globals> for LibraryScene {
             SpriteColorQueue::new(game_io, &self.camera, SpriteColorMode::Multiply);
 
         // draw title
-        SceneTitle::new("LIBRARY").draw(game_io, &mut sprite_queue);
+        let active_dock = &mut self.docks[self.active_dock];
+
+        let title = match active_dock.card_class {
+            CardClass::Standard => "LIBRARY",
+            CardClass::Mega => "MEGA LIBRARY",
+            CardClass::Giga => "GIGA LIBRARY",
+            CardClass::Dark => "DARK LIBRARY",
+        };
+
+        SceneTitle::new(title).draw(game_io, &mut sprite_queue);
 
         // draw docks
-        self.docks[0].draw(game_io, &mut sprite_queue);
+        active_dock.draw(game_io, &mut sprite_queue);
 
         self.card_preview.draw(game_io, &mut sprite_queue);
 
@@ -138,6 +157,7 @@ impl Scene<Globals> for LibraryScene {
 }
 
 struct Dock {
+    card_class: CardClass,
     cards: Vec<Card>,
     scroll_tracker: ScrollTracker,
     dock_sprite: Sprite,
@@ -156,13 +176,13 @@ impl Dock {
         let available_packages = globals.card_packages.local_packages();
 
         // cards
-        // todo: filter using card_class
         let cards: Vec<_> = available_packages
             .filter(|id| {
                 globals
                     .card_packages
                     .package_or_fallback(PackageNamespace::Server, id)
-                    .is_some()
+                    .map(|package| package.card_properties.card_class == card_class)
+                    .unwrap_or_default()
             })
             .map(|id| Card {
                 package_id: id.clone(),
@@ -210,6 +230,7 @@ impl Dock {
         //     .map(|point| PageArrows::new(game_io, (dock_offset + point)));
 
         Self {
+            card_class,
             cards,
             scroll_tracker,
             dock_sprite,
@@ -13,8 +13,10 @@ mod netplay_init_scene;
 mod overlay;
 mod overworld_online_scene;
 mod overworld_scene_base;
+mod package_test_scene;
 mod server_edit_scene;
 mod server_list_scene;
+mod simulate_scene;
 
 pub use battle_scene::*;
 pub use battle_select_scene::*;
@@ -31,5 +33,7 @@ pub use netplay_init_scene::*;
 pub use overlay::*;
 pub use overworld_online_scene::*;
 pub use overworld_scene_base::*;
+pub use package_test_scene::*;
 pub use server_edit_scene::*;
 pub use server_list_scene::*;
+pub use simulate_scene::*;
@@ -1,6 +1,6 @@
 use super::BattleScene;
 use crate::battle::{BattleProps, BattleStatisticsCallback, PlayerSetup};
-use crate::bindable::SpriteColorMode;
+use crate::bindable::{Emotion, SpriteColorMode, Team};
 use crate::packages::PackageNamespace;
 use crate::render::*;
 use crate::resources::*;
@@ -30,6 +30,7 @@ struct RemotePlayerConnection {
     index: usize,
     player_package: String,
     folder: Folder,
+    emotion: Emotion,
     // todo: blocks
     load_map: HashMap<FileHash, PackageCategory>,
     requested_packages: Option<Vec<FileHash>>,
@@ -43,6 +44,8 @@ struct RemotePlayerConnection {
 
 pub struct NetplayInitScene {
     local_index: usize,
+    local_emotion: Emotion,
+    teams: Vec<u8>,
     battle_package: Option<(PackageNamespace, String)>,
     data: Option<String>,
     background: Option<Background>,
@@ -68,7 +71,9 @@ impl NetplayInitScene {
         battle_package: Option<(PackageNamespace, String)>,
         data: Option<String>,
         remote_players: Vec<RemotePlayerInfo>,
+        teams: Vec<u8>,
         fallback_address: String,
+        local_emotion: Emotion,
         statistics_callback: Option<BattleStatisticsCallback>,
     ) -> Self {
         let local_index = Self::resolve_local_index(&remote_players);
@@ -134,6 +139,7 @@ impl NetplayInitScene {
                 index: info.index,
                 player_package: String::new(),
                 folder: Folder::default(),
+                emotion: Emotion::Normal,
                 load_map: HashMap::new(),
                 requested_packages: None,
                 ready_for_packages: false,
@@ -147,6 +153,8 @@ impl NetplayInitScene {
 
         Self {
             local_index,
+            local_emotion,
+            teams,
             battle_package,
             data,
             background,
@@ -166,6 +174,12 @@ impl NetplayInitScene {
         }
     }
 
+    fn resolve_team(teams: &[u8], index: usize) -> Team {
+        let number = teams.get(index).cloned().unwrap_or_default();
+
+        Team::from_netplay_number(number)
+    }
+
     fn resolve_local_index(remote_players: &[RemotePlayerInfo]) -> usize {
         let mut possible_indexes = Vec::from_iter(0..remote_players.len() + 1);
 
@@ -269,9 +283,11 @@ impl NetplayInitScene {
             NetplayPacket::PlayerSetup {
                 player_package,
                 cards,
+                emotion,
                 ..
             } => {
                 connection.player_package = player_package;
+                connection.emotion = emotion;
                 connection.folder.cards = cards
                     .into_iter()
                     .map(|(package_id, code)| Card { package_id, code })
@@ -484,6 +500,7 @@ impl NetplayInitScene {
             index: self.local_index,
             player_package: player_package_info.id.clone(),
             cards,
+            emotion: self.local_emotion,
         })
     }
 
@@ -592,8 +609,10 @@ impl NetplayInitScene {
                 props.background = background;
             }
 
-            // correct index
+            // correct index and emotion
             props.player_setups[0].index = self.local_index;
+            props.player_setups[0].emotion = self.local_emotion;
+            props.player_setups[0].team = Self::resolve_team(&self.teams, self.local_index);
 
             // setup other players
             for connection in &mut self.player_connections {
@@ -617,8 +636,10 @@ impl NetplayInitScene {
                 props.player_setups.push(PlayerSetup {
                     player_package,
                     folder: connection.folder.clone(),
+                    emotion: connection.emotion,
                     index: connection.index,
                     local: false,
+                    team: Self::resolve_team(&self.teams, connection.index),
                     input_buffer: std::mem::take(&mut connection.input_buffer),
                 });
 
@@ -1,22 +1,25 @@
-use super::{NetplayInitScene, OverworldSceneBase};
+use super::{InitialConnectScene, NetplayInitScene, OverworldSceneBase};
 use crate::battle::BattleProps;
 use crate::bindable::Emotion;
 use crate::overworld::components::*;
 use crate::overworld::{
-    movement_interpolation_system, CameraAction, ObjectData, ObjectType, OverworldBaseEvent,
+    movement_interpolation_system, system_actor_property_animation, system_object_interaction,
+    system_open_pending_door, system_opened_chest, CameraAction, ObjectData, ObjectType,
+    OverworldBaseEvent,
 };
-use crate::overworld::{Item, ServerAssetManager};
-use crate::packages::PackageNamespace;
+use crate::overworld::{Item, ItemsMenu, ServerAssetManager, Shop};
+use crate::packages::{PackageCategory, PackageNamespace};
 use crate::render::ui::{
-    TextboxDoorstop, TextboxDoorstopRemover, TextboxInterface, TextboxMessage, TextboxPrompt,
-    TextboxQuestion, TextboxQuiz,
+    SceneOption, TextboxDoorstop, TextboxDoorstopRemover, TextboxInterface, TextboxMessage,
+    TextboxPrompt, TextboxQuestion, TextboxQuiz,
 };
 use crate::render::AnimatorLoopMode;
 use crate::resources::*;
+use crate::saves::MutedPlayer;
 use crate::scenes::BattleScene;
 use bimap::BiMap;
 use framework::prelude::*;
-use packets::structures::{BattleStatistics, FileHash};
+use packets::structures::{BattleStatistics, FileHash, FolderRules, ShopItem};
 use packets::{
     address_parsing, ClientAssetType, ClientPacket, Reliability, ServerPacket, SERVER_TICK_RATE,
 };
@@ -26,11 +29,33 @@ enum Event {
     BattleStatistics(Option<BattleStatistics>),
     ServerTextboxResponse(u8),
     ServerPromptResponse(String),
-    RemoveActor { actor_id: String },
-    Disconnected { message: String },
+    RemoveActor {
+        actor_id: String,
+    },
+    InstallPackage {
+        category: PackageCategory,
+        name: String,
+        package_path: String,
+        hash: FileHash,
+    },
+    Disconnected {
+        message: String,
+    },
+    TransferServer {
+        address: String,
+        data: Option<String>,
+        token: String,
+    },
     Leave,
 }
 
+/// Carried into the next server's scene when a server transfers the player
+pub struct ServerTransfer {
+    token: String,
+    player_avatar: Option<(Sprite, Animator)>,
+    assets: Option<ServerAssetManager>,
+}
+
 pub struct OverworldOnlineScene {
     base_scene: OverworldSceneBase,
     next_scene: NextScene<Globals>,
@@ -51,6 +76,7 @@ pub struct OverworldOnlineScene {
     doorstop_remover: Option<TextboxDoorstopRemover>,
     encounter_packages: HashMap<String, String>, // server_path -> package_id
     loaded_zips: HashMap<String, FileHash>,      // server_path -> hash
+    shop_items: Vec<ShopItem>,
 }
 
 impl Drop for OverworldOnlineScene {
@@ -75,6 +101,24 @@ impl OverworldOnlineScene {
             .insert_one(player_entity, HiddenSprite::default())
             .unwrap();
 
+        // key items
+        let items_menu = ItemsMenu::new(game_io, {
+            let send_packet = send_packet.clone();
+
+            move |item_id: &str| {
+                send_packet(
+                    Reliability::ReliableOrdered,
+                    ClientPacket::ItemUse {
+                        item_id: item_id.to_string(),
+                    },
+                );
+            }
+        });
+
+        let menu_manager = &mut base_scene.menu_manager;
+        let items_menu_index = menu_manager.register_menu(Box::new(items_menu));
+        menu_manager.bind_navigation_option(SceneOption::KeyItems, items_menu_index);
+
         let (event_sender, event_receiver) = flume::unbounded();
         let assets = ServerAssetManager::new(game_io, &address);
 
@@ -99,10 +143,29 @@ impl OverworldOnlineScene {
             doorstop_remover: None,
             encounter_packages: HashMap::new(),
             loaded_zips: HashMap::new(),
+            shop_items: Vec::new(),
         }
     }
 
-    pub fn start_connection(&self, game_io: &GameIO<Globals>, data: Option<String>) {
+    /// Reuses the previous server's player avatar and asset cache, returns the transfer token
+    pub fn receive_transfer(&mut self, transfer: ServerTransfer) -> String {
+        if let Some((sprite, animator)) = transfer.player_avatar {
+            self.base_scene.set_player_avatar(sprite, animator);
+        }
+
+        if let Some(assets) = transfer.assets {
+            self.assets = assets;
+        }
+
+        transfer.token
+    }
+
+    pub fn start_connection(
+        &self,
+        game_io: &GameIO<Globals>,
+        data: Option<String>,
+        transfer_token: Option<String>,
+    ) {
         let globals = game_io.globals();
         let global_save = &globals.global_save;
 
@@ -118,6 +181,7 @@ impl OverworldOnlineScene {
                 username: global_save.nickname.clone(),
                 identity: String::new(),
                 data,
+                transfer_token,
             },
         );
 
@@ -250,6 +314,8 @@ impl OverworldOnlineScene {
                 self.send_ready_packet(game_io);
             }
             ServerPacket::TransferWarp => {
+                system_open_pending_door(&mut self.base_scene);
+
                 let player_entity = self.base_scene.player_data.entity;
                 let send_packet = self.send_packet.clone();
 
@@ -257,7 +323,22 @@ impl OverworldOnlineScene {
                     send_packet(Reliability::ReliableOrdered, ClientPacket::TransferredOut);
                 });
             }
-            ServerPacket::TransferStart => log::warn!("TransferStart hasn't been implemented"),
+            ServerPacket::TransferStart => {
+                system_open_pending_door(&mut self.base_scene);
+
+                // actors from the previous area won't receive disconnect packets
+                let player_entity = self.base_scene.player_data.entity;
+                let entities = &mut self.base_scene.entities;
+
+                self.actor_id_map.retain(|_, entity| {
+                    if *entity == player_entity {
+                        return true;
+                    }
+
+                    let _ = entities.despawn(*entity);
+                    false
+                });
+            }
             ServerPacket::TransferComplete { warp_in, direction } => {
                 let player_entity = self.base_scene.player_data.entity;
                 let send_packet = self.send_packet.clone();
@@ -286,8 +367,39 @@ impl OverworldOnlineScene {
             ServerPacket::TransferServer {
                 address,
                 data,
+                token,
                 warp_out,
-            } => log::warn!("TransferServer hasn't been implemented"),
+            } => {
+                // the server kicks us after the transfer, avoid displaying it
+                self.connected = false;
+
+                let data = if data.is_empty() { None } else { Some(data) };
+
+                let event = Event::TransferServer {
+                    address,
+                    data,
+                    token,
+                };
+
+                if warp_out {
+                    let player_entity = self.base_scene.player_data.entity;
+                    let event_sender = self.event_sender.clone();
+
+                    WarpEffect::warp_out(
+                        game_io,
+                        &mut self.base_scene,
+                        player_entity,
+                        move |_, _| {
+                            event_sender.send(event).unwrap();
+                        },
+                    );
+                } else {
+                    self.event_sender.send(event).unwrap();
+                }
+            }
+            ServerPacket::Kick { reason } if !self.connected => {
+                log::debug!("Ignoring kick after disconnect: {reason:?}");
+            }
             ServerPacket::Kick { reason } => {
                 self.event_sender
                     .send(Event::Disconnected {
@@ -345,6 +457,7 @@ impl OverworldOnlineScene {
 
                 if let Some(map) = load_map(game_io, &self.assets, &data) {
                     self.base_scene.set_world(game_io, &self.assets, map);
+                    system_opened_chest(game_io, &mut self.base_scene);
                 } else {
                     log::warn!("failed to load map provided by server");
                 }
@@ -374,18 +487,24 @@ impl OverworldOnlineScene {
                 name,
                 description,
             } => {
-                self.base_scene.player_data.items.push(Item {
+                let player_data = &mut self.base_scene.player_data;
+                player_data.items.push(Item {
                     id,
                     name,
                     description,
                 });
+
+                self.base_scene.menu_manager.update_player_data(player_data);
             }
             ServerPacket::RemoveItem { id } => {
-                let items = &mut self.base_scene.player_data.items;
+                let player_data = &mut self.base_scene.player_data;
+                let items = &mut player_data.items;
 
                 if let Some(index) = items.iter().position(|item| item.id == id) {
                     items.remove(index);
                 }
+
+                self.base_scene.menu_manager.update_player_data(player_data);
             }
             ServerPacket::PlaySound { path } => {
                 let sound = self.assets.audio(&path);
@@ -646,18 +765,87 @@ impl OverworldOnlineScene {
                 }
             }
             ServerPacket::ShopInventory { items } => {
-                log::warn!("ShopInventory hasn't been implemented")
+                // the inventory is sent right before OpenShop, even while another shop is open
+                self.shop_items = items;
             }
             ServerPacket::OpenShop {
                 mug_texture_path,
                 mug_animation_path,
-            } => log::warn!("OpenShop hasn't been implemented"),
+            } => {
+                let on_purchase = {
+                    let send_packet = self.send_packet.clone();
+
+                    move |item_name: &str| {
+                        send_packet(
+                            Reliability::ReliableOrdered,
+                            ClientPacket::ShopPurchase {
+                                item_name: item_name.to_string(),
+                            },
+                        );
+                    }
+                };
+
+                let on_close = {
+                    let send_packet = self.send_packet.clone();
+
+                    move || {
+                        send_packet(Reliability::ReliableOrdered, ClientPacket::ShopClose);
+                    }
+                };
+
+                let mut shop = Shop::new(
+                    game_io,
+                    &self.assets,
+                    &mug_texture_path,
+                    &mug_animation_path,
+                    on_purchase,
+                    on_close,
+                );
+
+                let items = std::mem::take(&mut self.shop_items);
+                shop.set_items(game_io, items);
+                shop.set_money(self.base_scene.player_data.money);
+
+                self.base_scene.menu_manager.open_shop(shop);
+            }
             ServerPacket::OfferPackage {
                 id,
                 name,
                 category,
                 package_path,
-            } => log::warn!("OfferPackage hasn't been implemented"),
+                hash,
+            } => {
+                let globals = game_io.globals();
+                let namespace = PackageNamespace::Local;
+
+                if ResourcePaths::mods_folder(category).is_none() {
+                    log::warn!("{category:?} packages can't be installed");
+                } else if globals
+                    .package_or_fallback_info(category, namespace, &id)
+                    .is_none()
+                {
+                    let event_sender = self.event_sender.clone();
+                    let message = format!("Install {name}?");
+
+                    let interface = TextboxQuestion::new(message, move |accepted| {
+                        if accepted {
+                            let event = Event::InstallPackage {
+                                category,
+                                name,
+                                package_path,
+                                hash,
+                            };
+
+                            event_sender.send(event).unwrap();
+                        }
+                    });
+
+                    self.base_scene.menu_manager.use_player_avatar(game_io);
+                    self.base_scene
+                        .menu_manager
+                        .push_textbox_interface(interface);
+                }
+            }
             ServerPacket::LoadPackage {
                 category,
                 package_path,
@@ -695,6 +883,10 @@ impl OverworldOnlineScene {
             ServerPacket::ModBlacklist { blacklist_path } => {
                 log::warn!("ModBlacklist hasn't been implemented")
             }
+            ServerPacket::FolderRules { rules } => {
+                let globals = game_io.globals_mut();
+                globals.folder_rules = rules.unwrap_or_default();
+            }
             ServerPacket::InitiateEncounter { package_path, data } => {
                 let globals = game_io.globals();
 
@@ -705,6 +897,7 @@ impl OverworldOnlineScene {
                         .package_or_fallback(PackageNamespace::Server, package_id);
 
                     let mut props = BattleProps::new_with_defaults(game_io, battle_package);
+                    props.player_setups[0].emotion = self.base_scene.player_data.emotion;
 
                     // callback
                     let event_sender = self.event_sender.clone();
@@ -731,6 +924,7 @@ impl OverworldOnlineScene {
                 package_path,
                 data,
                 remote_players,
+                teams,
             } => {
                 (self.send_packet)(Reliability::ReliableOrdered, ClientPacket::EncounterStart);
 
@@ -755,7 +949,9 @@ impl OverworldOnlineScene {
                     battle_package,
                     data,
                     remote_players,
+                    teams,
                     self.server_address.clone(),
+                    self.base_scene.player_data.emotion,
                     Some(statistics_callback),
                 );
 
@@ -766,6 +962,7 @@ impl OverworldOnlineScene {
             ServerPacket::ActorConnected {
                 actor_id,
                 name,
+                identity_hash,
                 texture_path,
                 animation_path,
                 direction: initial_direction,
@@ -799,6 +996,13 @@ impl OverworldOnlineScene {
                         .spawn_player_actor(game_io, texture, animator, position)
                 };
 
+                let _ = self.base_scene.entities.insert_one(entity, NameLabel(name));
+
+                if let Some(identity_hash) = identity_hash {
+                    let entities = &mut self.base_scene.entities;
+                    let _ = entities.insert_one(entity, IdentityHash(identity_hash));
+                }
+
                 if entity != self.base_scene.player_data.entity {
                     let entities = &mut self.base_scene.entities;
 
@@ -824,6 +1028,7 @@ impl OverworldOnlineScene {
                         (
                             InteractableActor(actor_id.clone()),
                             MovementInterpolator::new(game_io, position, initial_direction),
+                            ActorPropertyAnimator::new(rotation),
                         ),
                     );
 
@@ -926,6 +1131,34 @@ impl OverworldOnlineScene {
                 emote_id,
                 use_custom_emotes,
             } => log::warn!("ActorEmote hasn't been implemented"),
+            ServerPacket::Chat { actor_id, message } => {
+                let entity =
+                    actor_id.and_then(|actor_id| self.actor_id_map.get_by_left(&actor_id).cloned());
+
+                let entities = &mut self.base_scene.entities;
+                let name = entity
+                    .and_then(|entity| entities.query_one_mut::<&NameLabel>(entity).ok())
+                    .map(|label| label.0.clone());
+
+                let identity_hash = entity
+                    .and_then(|entity| entities.query_one_mut::<&IdentityHash>(entity).ok())
+                    .map(|identity_hash| identity_hash.0.as_str());
+
+                let global_save = &game_io.globals().global_save;
+                let muted = identity_hash.is_some_and(|identity_hash| {
+                    (global_save.muted_players.iter())
+                        .any(|muted| muted.identity_hash == identity_hash)
+                });
+
+                if !muted {
+                    if let Some(entity) = entity {
+                        let _ = entities.insert_one(entity, ChatBubble::new(message.clone()));
+                    }
+
+                    let chat = self.base_scene.menu_manager.chat_mut();
+                    chat.push_message(name.as_deref(), &message);
+                }
+            }
             ServerPacket::ActorAnimate {
                 actor_id,
                 state,
@@ -950,7 +1183,22 @@ impl OverworldOnlineScene {
             ServerPacket::ActorPropertyKeyFrames {
                 actor_id,
                 keyframes,
-            } => log::warn!("ActorPropertyKeyFrames hasn't been implemented"),
+            } => {
+                if let Some(entity) = self.actor_id_map.get_by_left(&actor_id) {
+                    let entities = &mut self.base_scene.entities;
+
+                    if let Ok(property_animator) =
+                        entities.query_one_mut::<&mut ActorPropertyAnimator>(*entity)
+                    {
+                        property_animator.set_keyframes(keyframes);
+                    } else {
+                        let mut property_animator = ActorPropertyAnimator::default();
+                        property_animator.set_keyframes(keyframes);
+
+                        let _ = entities.insert_one(*entity, property_animator);
+                    }
+                }
+            }
             ServerPacket::ActorMinimapColor { actor_id, color } => {
                 if let Some(entity) = self.actor_id_map.get_by_left(&actor_id) {
                     let entities = &mut self.base_scene.entities;
@@ -1022,16 +1270,20 @@ impl OverworldOnlineScene {
         }
     }
 
-    fn handle_events(&mut self, game_io: &GameIO<Globals>) {
+    fn handle_events(&mut self, game_io: &mut GameIO<Globals>) {
         self.handle_base_events();
 
         while let Ok(event) = self.event_receiver.try_recv() {
             match event {
                 Event::BattleStatistics(statistics) => {
-                    let player_data = &self.base_scene.player_data;
+                    let player_data = &mut self.base_scene.player_data;
 
                     let battle_stats = match statistics {
-                        Some(statistics) => statistics,
+                        Some(statistics) => {
+                            // keep the emotion from the battle
+                            player_data.emotion = statistics.emotion;
+                            statistics
+                        }
                         None => BattleStatistics {
                             health: player_data.health as u32,
                             emotion: player_data.emotion,
@@ -1053,6 +1305,21 @@ impl OverworldOnlineScene {
                     Reliability::ReliableOrdered,
                     ClientPacket::PromptResponse { response },
                 ),
+                Event::InstallPackage {
+                    category,
+                    name,
+                    package_path,
+                    hash,
+                } => {
+                    let message =
+                        self.install_package(game_io, category, &name, &package_path, hash);
+                    let interface = TextboxMessage::new(message);
+
+                    self.base_scene.menu_manager.use_player_avatar(game_io);
+                    self.base_scene
+                        .menu_manager
+                        .push_textbox_interface(interface);
+                }
                 Event::RemoveActor { actor_id } => {
                     if let Some((_, entity)) = self.actor_id_map.remove_by_left(&actor_id) {
                         let _ = self.base_scene.entities.despawn(entity);
@@ -1077,7 +1344,17 @@ impl OverworldOnlineScene {
 
                     self.connected = false;
                 }
+                Event::TransferServer {
+                    address,
+                    data,
+                    token,
+                } => {
+                    self.transfer_server(game_io, address, data, token);
+                }
                 Event::Leave => {
+                    // server rules shouldn't affect folders outside of the server
+                    game_io.globals_mut().folder_rules = FolderRules::default();
+
                     let transition = crate::transitions::new_connect(game_io);
                     *self.base_scene.next_scene() =
                         NextScene::new_pop().with_transition(transition);
@@ -1086,6 +1363,35 @@ impl OverworldOnlineScene {
         }
     }
 
+    fn transfer_server(
+        &mut self,
+        game_io: &GameIO<Globals>,
+        address: String,
+        data: Option<String>,
+        token: String,
+    ) {
+        // the asset cache is shared when only the data in the address changes
+        let assets = if self.assets.shares_cache_with(&address) {
+            let replacement = ServerAssetManager::new(game_io, &self.server_address);
+            Some(std::mem::replace(&mut self.assets, replacement))
+        } else {
+            None
+        };
+
+        let transfer = ServerTransfer {
+            token,
+            player_avatar: self.base_scene.player_avatar(),
+            assets,
+        };
+
+        let transition = crate::transitions::new_connect(game_io);
+        let connect_scene =
+            InitialConnectScene::new(game_io, address, data, false).with_transfer(transfer);
+
+        *self.base_scene.next_scene() =
+            NextScene::new_swap(connect_scene).with_transition(transition);
+    }
+
     fn handle_input(&mut self, game_io: &mut GameIO<Globals>) {
         let input_util = InputUtil::new(game_io);
 
@@ -1105,15 +1411,24 @@ impl OverworldOnlineScene {
         }
 
         if input_util.was_just_pressed(Input::Confirm) {
-            self.handle_interaction(0);
+            self.handle_interaction(game_io, 0);
         }
 
         if input_util.was_just_pressed(Input::ShoulderL) {
-            self.handle_interaction(1);
+            self.handle_interaction(game_io, 1);
         }
     }
 
-    fn handle_interaction(&mut self, button: u8) {
+    fn handle_interaction(&mut self, game_io: &GameIO<Globals>, button: u8) {
+        // doors, signs, and chests are partially handled by the client
+        if let Some(tile_object_id) = self.base_scene.player_data.object_interaction {
+            if button == 0
+                && !system_object_interaction(game_io, &mut self.base_scene, tile_object_id)
+            {
+                return;
+            }
+        }
+
         let player_data = &self.base_scene.player_data;
         let send_packet = &self.send_packet;
 
@@ -1156,6 +1471,51 @@ impl OverworldOnlineScene {
         );
     }
 
+    fn send_chat_message(&mut self, game_io: &mut GameIO<Globals>, message: String) {
+        let entities = &self.base_scene.entities;
+        let global_save = &mut game_io.globals_mut().global_save;
+        let chat = self.base_scene.menu_manager.chat_mut();
+
+        // mute commands are handled locally
+        // players are muted by the identity hash the server shares, names are only for display
+        if let Some(name) = message.strip_prefix("/mute ") {
+            let name = name.trim();
+            let identity_hashes = identity_hashes_for_name(entities, name);
+
+            if identity_hashes.is_empty() {
+                chat.push_message(None, &format!("No player named {name} is connected"));
+                return;
+            }
+
+            for identity_hash in identity_hashes {
+                let already_muted = (global_save.muted_players.iter())
+                    .any(|muted| muted.identity_hash == identity_hash);
+
+                if !already_muted {
+                    global_save.muted_players.push(MutedPlayer {
+                        name: name.to_string(),
+                        identity_hash,
+                    });
+                }
+            }
+
+            chat.push_message(None, &format!("Muted {name}"));
+            global_save.save();
+        } else if let Some(name) = message.strip_prefix("/unmute ") {
+            let name = name.trim();
+            let identity_hashes = identity_hashes_for_name(entities, name);
+
+            global_save.muted_players.retain(|muted| {
+                muted.name != name && !identity_hashes.contains(&muted.identity_hash)
+            });
+
+            chat.push_message(None, &format!("Unmuted {name}"));
+            global_save.save();
+        } else {
+            (self.send_packet)(Reliability::ReliableOrdered, ClientPacket::Chat { message });
+        }
+    }
+
     fn send_position(&mut self, game_io: &GameIO<Globals>) {
         if !self.connected {
             return;
@@ -1204,6 +1564,49 @@ impl OverworldOnlineScene {
     }
 }
 
+impl OverworldOnlineScene {
+    /// Extracts an offered package into the mods folder, returns a message for the player
+    fn install_package(
+        &self,
+        game_io: &mut GameIO<Globals>,
+        category: PackageCategory,
+        name: &str,
+        package_path: &str,
+        hash: FileHash,
+    ) -> String {
+        let bytes = self.assets.binary(package_path);
+
+        if FileHash::hash(&bytes) != hash {
+            log::error!("hash mismatch for {package_path:?}");
+            return format!("Failed to download {name}.");
+        }
+
+        let Some(mods_folder) = ResourcePaths::mods_folder(category) else {
+            return format!("{name} can't be installed.");
+        };
+
+        // folder name from the hash to avoid collisions with existing folders
+        let base_path = format!("{mods_folder}/{hash}/");
+
+        if let Err(e) = extract_package(&bytes, &base_path) {
+            log::error!("failed to install {package_path:?}: {e}");
+            let _ = std::fs::remove_dir_all(&base_path);
+
+            return format!("Failed to install {name}.");
+        }
+
+        let globals = game_io.globals_mut();
+
+        if globals.load_local_package(category, &base_path).is_none() {
+            let _ = std::fs::remove_dir_all(&base_path);
+
+            return format!("Failed to install {name}.");
+        }
+
+        format!("Installed {name}!")
+    }
+}
+
 impl Scene<Globals> for OverworldOnlineScene {
     fn next_scene(&mut self) -> &mut NextScene<Globals> {
         &mut self.next_scene
@@ -1213,6 +1616,8 @@ impl Scene<Globals> for OverworldOnlineScene {
         // handle events triggered from other scenes
         // should be called before handling packets, but it's not necessary to do this every frame
         self.handle_events(game_io);
+
+        self.base_scene.resume_music(game_io, &self.assets);
     }
 
     fn update(&mut self, game_io: &mut GameIO<Globals>) {
@@ -1221,11 +1626,16 @@ impl Scene<Globals> for OverworldOnlineScene {
         self.base_scene.update(game_io);
         self.send_position(game_io);
 
+        if let Some(message) = self.base_scene.menu_manager.chat_mut().take_message() {
+            self.send_chat_message(game_io, message);
+        }
+
         if !self.base_scene.is_input_locked(game_io) {
             self.handle_input(game_io);
         }
 
         movement_interpolation_system(game_io, &mut self.base_scene);
+        system_actor_property_animation(game_io, &mut self.base_scene, &self.assets);
         self.handle_events(game_io);
         self.handle_next_scene(game_io);
     }
@@ -1235,6 +1645,45 @@ impl Scene<Globals> for OverworldOnlineScene {
     }
 }
 
+fn extract_package(bytes: &[u8], base_path: &str) -> std::io::Result<()> {
+    use std::io::Read;
+
+    let mut result = Ok(());
+
+    crate::zip::extract(bytes, |path, mut file| {
+        if result.is_err() || !file.is_file() {
+            return;
+        }
+
+        let path = format!("{base_path}{path}");
+
+        let mut write_file = || {
+            let mut file_bytes = Vec::new();
+            file.read_to_end(&mut file_bytes)?;
+
+            if let Some(parent_path) = ResourcePaths::parent(&path) {
+                std::fs::create_dir_all(parent_path)?;
+            }
+
+            std::fs::write(&path, &file_bytes)
+        };
+
+        result = write_file();
+    });
+
+    result
+}
+
+fn identity_hashes_for_name(entities: &hecs::World, name: &str) -> Vec<String> {
+    let mut query = entities.query::<(&NameLabel, &IdentityHash)>();
+
+    query
+        .into_iter()
+        .filter(|(_, (label, _))| label.0 == name)
+        .map(|(_, (_, identity_hash))| identity_hash.0.clone())
+        .collect()
+}
+
 fn ms_time(game_io: &GameIO<Globals>) -> u64 {
     let duration = game_io.frame_start_instant() - game_io.game_start_instant();
 
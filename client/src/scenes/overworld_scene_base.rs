@@ -5,8 +5,11 @@ use crate::render::ui::*;
 use crate::render::*;
 use crate::resources::*;
 use framework::prelude::*;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
+
+const MAP_MUSIC_FADE_DURATION: Duration = Duration::from_millis(500);
 
 pub struct OverworldSceneBase {
     pub world_camera: Camera,
@@ -129,7 +132,147 @@ impl OverworldSceneBase {
                 .generate_background(game_io, assets);
         }
 
+        if self.map.song_path() != map.song_path()
+            || self.map.song_loop_points() != map.song_loop_points()
+        {
+            Self::play_map_music(game_io, assets, &map);
+        }
+
+        self.menu_manager
+            .minimap_mut()
+            .set_map(game_io, assets, &map);
+
         self.map = map;
+        self.sync_path_npcs(game_io, assets);
+    }
+
+    /// Restarts the map's song if another scene replaced it, such as a battle
+    pub fn resume_music(&self, game_io: &GameIO<Globals>, assets: &impl AssetManager) {
+        let audio = &game_io.globals().audio;
+        let song_path = self.map.song_path();
+
+        if song_path.is_empty() || audio.is_playing_music_buffer(&assets.audio(song_path)) {
+            return;
+        }
+
+        Self::play_map_music(game_io, assets, &self.map);
+    }
+
+    fn play_map_music(game_io: &GameIO<Globals>, assets: &impl AssetManager, map: &Map) {
+        let audio = &game_io.globals().audio;
+
+        if map.song_path().is_empty() {
+            audio.stop_music();
+        } else {
+            let buffer = assets.audio(map.song_path());
+            let loop_points = Some(map.song_loop_points());
+            audio.crossfade_music(buffer, loop_points, MAP_MUSIC_FADE_DURATION);
+        }
+    }
+
+    /// Respawns actors for "NPC Path" objects, resuming progress for paths that still exist
+    fn sync_path_npcs(&mut self, game_io: &GameIO<Globals>, assets: &impl AssetManager) {
+        let mut previous_npcs = HashMap::new();
+
+        for (entity, (npc, position)) in self.entities.query_mut::<(&PathNpc, &Vec3)>() {
+            previous_npcs.insert(
+                npc.object_id,
+                (entity, *position, npc.target_index, npc.reversed),
+            );
+        }
+
+        for (entity, ..) in previous_npcs.values() {
+            let _ = self.entities.despawn(*entity);
+        }
+
+        let mut npcs = Vec::new();
+
+        for (_, (data, path)) in self
+            .map
+            .object_entities()
+            .query::<(&ObjectData, &ObjectPath)>()
+            .iter()
+        {
+            if !matches!(data.object_type, ObjectType::NpcPath) || !data.visible {
+                continue;
+            }
+
+            let Some(start) = path.points.first() else {
+                continue;
+            };
+
+            let properties = &data.custom_properties;
+
+            let speed = if properties.has_property("speed") {
+                properties.get_f32("speed") * self.map.tile_size().y as f32 / 60.0
+            } else {
+                OVERWORLD_WALK_SPEED
+            };
+
+            let mut npc = PathNpc {
+                object_id: data.id,
+                layer: path.layer,
+                points: path.points.clone(),
+                speed,
+                target_index: 0,
+                reversed: false,
+            };
+
+            let mut position = start.extend(path.layer as f32);
+
+            if let Some((_, previous_position, target_index, reversed)) =
+                previous_npcs.get(&data.id)
+            {
+                if *target_index < npc.points.len() {
+                    position = *previous_position;
+                    npc.target_index = *target_index;
+                    npc.reversed = *reversed;
+                }
+            }
+
+            let texture_path = properties.get("texture").to_string();
+            let animation_path = properties.get("animation").to_string();
+            let solid = properties.get("solid") != "false";
+
+            npcs.push((npc, position, texture_path, animation_path, solid));
+        }
+
+        for (npc, position, texture_path, animation_path, solid) in npcs {
+            let texture = assets.texture(game_io, &texture_path);
+            let animator = Animator::load_new(assets, &animation_path);
+            let entity = self.spawn_player_actor(game_io, texture, animator, position);
+
+            let (collider, minimap_marker) = self
+                .entities
+                .query_one_mut::<(&mut ActorCollider, &mut PlayerMinimapMarker)>(entity)
+                .unwrap();
+
+            collider.solid = solid;
+            minimap_marker.color = Color::TRANSPARENT;
+
+            let _ = self.entities.insert_one(entity, npc);
+        }
+    }
+
+    pub fn player_avatar(&self) -> Option<(Sprite, Animator)> {
+        let player_entity = self.player_data.entity;
+        let mut query = self
+            .entities
+            .query_one::<(&Sprite, &Animator)>(player_entity)
+            .ok()?;
+
+        let (sprite, animator) = query.get()?;
+
+        Some((sprite.clone(), animator.clone()))
+    }
+
+    pub fn set_player_avatar(&mut self, sprite: Sprite, animator: Animator) {
+        let player_entity = self.player_data.entity;
+        let _ = self.entities.insert(player_entity, (sprite, animator));
+    }
+
+    pub fn world_time(&self) -> FrameTime {
+        self.world_time
     }
 
     pub fn is_input_locked(&self, game_io: &GameIO<Globals>) -> bool {
@@ -138,6 +281,7 @@ impl OverworldSceneBase {
             || self.camera_controller.is_locked()
             || self.input_locks > 0
             || self.player_is_warping()
+            || self.player_is_animated()
     }
 
     fn player_is_warping(&self) -> bool {
@@ -151,6 +295,17 @@ impl OverworldSceneBase {
         warp_controller.warp_entity.is_some()
     }
 
+    fn player_is_animated(&self) -> bool {
+        let entities = &self.entities;
+        let player_entity = self.player_data.entity;
+
+        let Ok(mut query) = entities.query_one::<&ActorPropertyAnimator>(player_entity) else {
+            return false;
+        };
+
+        matches!(query.get(), Some(property_animator) if property_animator.is_animating())
+    }
+
     pub fn add_input_lock(&mut self) {
         self.input_locks += 1;
     }
@@ -182,12 +337,20 @@ impl Scene<Globals> for OverworldSceneBase {
         system_player_interaction(game_io, self);
         system_warp_effect(game_io, self);
         system_warp(game_io, self);
+        system_path_npcs(self);
         system_movement_animation(self);
         system_movement(self);
+        system_chat_bubbles(self);
+        system_expire_pending_interactions(self);
 
         self.map.update(self.world_time);
         self.update_backgrounds();
 
+        let player_entity = self.player_data.entity;
+        self.menu_manager
+            .minimap_mut()
+            .update_markers(&self.map, &self.entities, player_entity);
+
         self.ui_camera.update(game_io);
         self.camera_controller.update(
             game_io,
@@ -213,6 +376,8 @@ impl Scene<Globals> for OverworldSceneBase {
                 .draw_objects_with_entities(&mut sprite_queue, &self.entities, i);
         }
 
+        draw_chat_bubbles(game_io, &mut sprite_queue, &self.entities);
+
         // draw foreground
         self.foreground.draw(game_io, render_pass);
 
@@ -252,6 +417,46 @@ impl OverworldSceneBase {
 
 const TEXT_SHADOW_COLOR: Color = Color::new(0.41, 0.41, 0.41, 1.0);
 
+fn draw_chat_bubbles(
+    game_io: &GameIO<Globals>,
+    sprite_queue: &mut SpriteColorQueue,
+    entities: &hecs::World,
+) {
+    const MAX_WIDTH: f32 = 96.0;
+    const PADDING: f32 = 2.0;
+
+    let assets = &game_io.globals().assets;
+    let mut background = assets.new_sprite(game_io, ResourcePaths::WHITE_PIXEL);
+
+    let mut text = Text::new(game_io, FontStyle::Thin).with_color(Color::BLACK);
+    text.style.bounds.width = MAX_WIDTH;
+
+    let mut query = entities.query::<hecs::Without<(&Sprite, &ChatBubble), &HiddenSprite>>();
+
+    for (_, (sprite, bubble)) in query.iter() {
+        text.text.clone_from(&bubble.text);
+
+        let size = text.measure().size;
+
+        // center above the sprite
+        let sprite_top = sprite.position().y - sprite.origin().y;
+        let position = Vec2::new(
+            sprite.position().x - size.x * 0.5,
+            sprite_top - size.y - PADDING * 2.0,
+        )
+        .floor();
+
+        background.set_bounds(Rect::from_corners(
+            position - PADDING,
+            position + size + PADDING,
+        ));
+        sprite_queue.draw_sprite(&background);
+
+        text.style.bounds.set_position(position);
+        text.draw(game_io, sprite_queue);
+    }
+}
+
 fn draw_map_name(game_io: &GameIO<Globals>, sprite_queue: &mut SpriteColorQueue, map: &Map) {
     const MARGIN: Vec2 = Vec2::new(1.0, 3.0);
 
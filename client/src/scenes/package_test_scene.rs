@@ -0,0 +1,240 @@
+use super::BattleScene;
+use crate::args::{Command, TestArgs};
+use crate::battle::*;
+use crate::lua_api::inject_test_api;
+use crate::packages::{PackageCategory, PackageInfo, PackageNamespace};
+use crate::resources::*;
+use framework::prelude::*;
+use std::io::Read;
+
+// a minute of battle
+const FRAME_LIMIT: usize = 3600;
+
+const TESTED_CATEGORIES: [PackageCategory; 6] = [
+    PackageCategory::Card,
+    PackageCategory::Character,
+    PackageCategory::Battle,
+    PackageCategory::Library,
+    PackageCategory::Player,
+    PackageCategory::Block,
+];
+
+/// Runs the scripts in a package's `tests` folder headlessly for the `test` command, then exits
+pub struct PackageTestScene {
+    next_scene: NextScene<Globals>,
+}
+
+impl PackageTestScene {
+    pub fn new() -> Box<Self> {
+        Box::new(Self {
+            next_scene: NextScene::None,
+        })
+    }
+
+    /// Returns the amount of passed and failed cases
+    fn run(game_io: &GameIO<Globals>, args: &TestArgs) -> Result<(usize, usize), String> {
+        let globals = game_io.globals();
+
+        let package_info = TESTED_CATEGORIES
+            .iter()
+            .find_map(|category| {
+                globals.package_or_fallback_info(*category, PackageNamespace::Local, &args.package)
+            })
+            .ok_or_else(|| format!("package {:?} not found", args.package))?;
+
+        let tests = Self::read_tests(game_io, package_info)?;
+
+        let mut passed = 0;
+        let mut failed = 0;
+
+        for (chunk_name, source) in tests {
+            println!("{chunk_name}");
+
+            // every case runs in a fresh battle
+            for case_index in 1.. {
+                let mut scene = Self::create_scene(game_io);
+                let vm_index = scene.load_package_vm(game_io, package_info);
+                let player_id = scene.simulation().local_player_id;
+
+                let mut case_name = None;
+
+                let result = scene.call_lua(game_io, vm_index, |lua| {
+                    inject_test_api(lua, player_id)?;
+                    lua.load(&source).set_name(&chunk_name)?.exec()?;
+
+                    let test_table: rollback_mlua::Table = lua.globals().get("Test")?;
+                    let cases: rollback_mlua::Table = test_table.get("_cases")?;
+
+                    if case_index > cases.raw_len() {
+                        return Ok(());
+                    }
+
+                    let case: rollback_mlua::Table = cases.get(case_index)?;
+                    case_name = Some(case.get::<_, String>("name")?);
+
+                    let start: rollback_mlua::Function = test_table.get("_start")?;
+                    start.call(case_index)
+                });
+
+                let result = match (result, &case_name) {
+                    // no cases left
+                    (Ok(()), None) => break,
+                    (Ok(()), Some(_)) => Self::run_case(game_io, &mut scene, vm_index),
+                    (Err(e), _) => Err(e.to_string()),
+                };
+
+                let name = case_name.as_deref().unwrap_or("(load)");
+
+                match result {
+                    Ok(()) => {
+                        println!("  pass: {name}");
+                        passed += 1;
+                    }
+                    Err(e) => {
+                        println!("  fail: {name}");
+
+                        for line in e.lines() {
+                            println!("    {line}");
+                        }
+
+                        failed += 1;
+                    }
+                }
+
+                if case_name.is_none() {
+                    // the file failed to load
+                    break;
+                }
+            }
+        }
+
+        Ok((passed, failed))
+    }
+
+    /// Reads the scripts in the `tests` folder from the package's zip, sorted by path
+    fn read_tests(
+        game_io: &GameIO<Globals>,
+        package_info: &PackageInfo,
+    ) -> Result<Vec<(String, String)>, String> {
+        let globals = game_io.globals();
+        let assets = &globals.assets;
+
+        // child packages are stored in their parent's zip
+        let root_info = match &package_info.parent_package {
+            Some((category, id)) => globals
+                .package_or_fallback_info(*category, package_info.namespace, id)
+                .ok_or_else(|| format!("parent package {id:?} not found"))?,
+            None => package_info,
+        };
+
+        let hash = root_info.hash;
+
+        let bytes = match assets.virtual_zip_bytes(&hash) {
+            Some(bytes) => bytes,
+            None => assets.binary(&format!("{}{}.zip", ResourcePaths::MOD_CACHE_FOLDER, hash)),
+        };
+
+        let zip_prefix = package_info
+            .base_path
+            .strip_prefix(&root_info.base_path)
+            .unwrap_or_default();
+        let tests_prefix = format!("{zip_prefix}tests/");
+        let chunk_prefix = ResourcePaths::shorten(&root_info.base_path);
+
+        let mut tests = Vec::new();
+
+        crate::zip::extract(&bytes, |path, mut file| {
+            let Some(file_name) = path.strip_prefix(&tests_prefix) else {
+                return;
+            };
+
+            if !file.is_file() || file_name.contains('/') || !file_name.ends_with(".lua") {
+                return;
+            }
+
+            let mut source = String::new();
+
+            if let Err(e) = file.read_to_string(&mut source) {
+                log::error!("failed to read {path:?}: {e}");
+                return;
+            }
+
+            tests.push((chunk_prefix.clone() + &path, source));
+        });
+
+        if tests.is_empty() {
+            return Err(format!(
+                "no tests found in {:?}",
+                chunk_prefix + &tests_prefix
+            ));
+        }
+
+        tests.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(tests)
+    }
+
+    fn create_scene(game_io: &GameIO<Globals>) -> BattleScene {
+        let mut props = BattleProps::new_with_defaults(game_io, None);
+        props.seed = Some(0);
+
+        let mut scene = BattleScene::new(game_io, props);
+        scene.skip_to_battle();
+        scene
+    }
+
+    fn run_case(
+        game_io: &GameIO<Globals>,
+        scene: &mut BattleScene,
+        vm_index: usize,
+    ) -> Result<(), String> {
+        let input_script = InputScript::default();
+
+        for _ in 0..FRAME_LIMIT {
+            let mut done = false;
+
+            let result = scene.call_lua(game_io, vm_index, |lua| {
+                let test_table: rollback_mlua::Table = lua.globals().get("Test")?;
+                let tick: rollback_mlua::Function = test_table.get("_tick")?;
+                done = tick.call(())?;
+
+                Ok(())
+            });
+
+            result.map_err(|e| e.to_string())?;
+
+            if done {
+                return Ok(());
+            }
+
+            scene.simulate_headless(game_io, &input_script, 1);
+        }
+
+        Err(format!("timed out after {FRAME_LIMIT} frames"))
+    }
+}
+
+impl Scene<Globals> for PackageTestScene {
+    fn next_scene(&mut self) -> &mut NextScene<Globals> {
+        &mut self.next_scene
+    }
+
+    fn update(&mut self, game_io: &mut GameIO<Globals>) {
+        let Some(Command::Test(args)) = game_io.globals().command.clone() else {
+            return;
+        };
+
+        match Self::run(game_io, &args) {
+            Ok((passed, failed)) => {
+                println!("{passed} passed, {failed} failed");
+                std::process::exit(if failed == 0 { 0 } else { 1 });
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn draw(&mut self, _game_io: &mut GameIO<Globals>, _render_pass: &mut RenderPass) {}
+}
@@ -0,0 +1,93 @@
+use super::BattleScene;
+use crate::args::{Command, SimulateArgs};
+use crate::battle::*;
+use crate::packages::PackageNamespace;
+use crate::resources::*;
+use framework::prelude::*;
+use std::fmt::Write;
+
+/// Runs a battle within a single update for the `simulate` command, then exits
+pub struct SimulateScene {
+    next_scene: NextScene<Globals>,
+}
+
+impl SimulateScene {
+    pub fn new() -> Box<Self> {
+        Box::new(Self {
+            next_scene: NextScene::None,
+        })
+    }
+
+    fn run(game_io: &GameIO<Globals>, args: &SimulateArgs) -> Result<String, String> {
+        let globals = game_io.globals();
+
+        let battle_package = globals
+            .battle_packages
+            .package(PackageNamespace::Local, &args.battle)
+            .ok_or_else(|| format!("battle package {:?} not found", args.battle))?;
+
+        let input_script = match &args.inputs {
+            Some(path) => {
+                let text = std::fs::read_to_string(path)
+                    .map_err(|e| format!("failed to read {path:?}: {e}"))?;
+
+                InputScript::parse(&text)?
+            }
+            None => InputScript::default(),
+        };
+
+        let mut props = BattleProps::new_with_defaults(game_io, Some(battle_package));
+        props.seed = Some(args.seed.unwrap_or_default());
+
+        let mut scene = BattleScene::new(game_io, props);
+        scene.simulate_headless(game_io, &input_script, args.frames);
+
+        // print the final state
+        let simulation = scene.simulation();
+        let mut output = format!("frames: {}\n", simulation.time);
+
+        type Query<'a> = (&'a Entity, &'a Living);
+
+        for (_, (entity, living)) in simulation.entities.query::<Query>().iter() {
+            let _ = writeln!(
+                output,
+                "{} ({}, {}) hp: {}/{}{}",
+                entity.name,
+                entity.x,
+                entity.y,
+                living.health,
+                living.max_health,
+                if entity.deleted { " deleted" } else { "" }
+            );
+        }
+
+        let _ = write!(output, "checksum: {}", simulation.checksum());
+
+        Ok(output)
+    }
+}
+
+impl Scene<Globals> for SimulateScene {
+    fn next_scene(&mut self) -> &mut NextScene<Globals> {
+        &mut self.next_scene
+    }
+
+    fn update(&mut self, game_io: &mut GameIO<Globals>) {
+        let Some(Command::Simulate(args)) = game_io.globals().command.clone() else {
+            return;
+        };
+
+        match Self::run(game_io, &args) {
+            Ok(output) => {
+                println!("{output}");
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn draw(&mut self, _game_io: &mut GameIO<Globals>, _render_pass: &mut RenderPass) {}
+}
@@ -33,6 +33,7 @@ pub enum ClientPacket {
         username: String,
         identity: String,
         data: String,
+        transfer_token: Option<String>,
     },
     Logout,
     RequestJoin,
@@ -55,6 +56,9 @@ pub enum ClientPacket {
     Emote {
         emote_id: u8,
     },
+    Chat {
+        message: String,
+    },
     CustomWarp {
         tile_object_id: u32,
     },
@@ -88,6 +92,9 @@ pub enum ClientPacket {
     ShopPurchase {
         item_name: String,
     },
+    ItemUse {
+        item_id: String,
+    },
     EncounterStart,
     BattleResults {
         battle_stats: BattleStatistics,
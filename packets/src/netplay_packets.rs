@@ -1,4 +1,4 @@
-use crate::structures::{FileHash, PackageCategory};
+use crate::structures::{Emotion, FileHash, PackageCategory};
 use serde::{Deserialize, Serialize};
 use strum::IntoStaticStr;
 
@@ -18,6 +18,7 @@ pub enum NetplayPacket {
         player_package: String,
         // package_id, code
         cards: Vec<(String, String)>,
+        emotion: Emotion,
         // todo: blocks
     },
     PackageList {
@@ -8,4 +8,5 @@ pub enum ServerCommPacket {
     Poll,
     Alive,
     Message { data: Vec<u8> },
+    TransferAuthorization { token: String, data: Vec<u8> },
 }
@@ -35,6 +35,7 @@ pub enum ServerPacket {
     TransferServer {
         address: String,
         data: String,
+        token: String,
         warp_out: bool,
     },
     Kick {
@@ -188,6 +189,7 @@ pub enum ServerPacket {
         id: String,
         category: PackageCategory,
         package_path: String,
+        hash: FileHash,
     },
     LoadPackage {
         category: PackageCategory,
@@ -199,6 +201,9 @@ pub enum ServerPacket {
     ModBlacklist {
         blacklist_path: Option<String>,
     },
+    FolderRules {
+        rules: Option<FolderRules>,
+    },
     InitiateEncounter {
         package_path: String,
         data: Option<String>,
@@ -207,10 +212,14 @@ pub enum ServerPacket {
         package_path: Option<String>,
         data: Option<String>,
         remote_players: Vec<RemotePlayerInfo>,
+        /// Team numbers by player index, empty to let the battle package decide
+        teams: Vec<u8>,
     },
     ActorConnected {
         actor_id: String,
         name: String,
+        /// Stable across sessions for players, None for bots
+        identity_hash: Option<String>,
         texture_path: String,
         animation_path: String,
         direction: Direction,
@@ -250,6 +259,10 @@ pub enum ServerPacket {
         emote_id: u8,
         use_custom_emotes: bool,
     },
+    Chat {
+        actor_id: Option<String>,
+        message: String,
+    },
     ActorAnimate {
         actor_id: String,
         state: String,
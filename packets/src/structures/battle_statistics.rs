@@ -9,9 +9,9 @@ pub struct BattleSurvivor {
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct BattleStatistics {
-    pub health: u32,      // todo: track
-    pub emotion: Emotion, // todo: track
-    pub ran: bool,        // todo: track
+    pub health: u32, // todo: track
+    pub emotion: Emotion,
+    pub ran: bool, // todo: track
     pub turns: u32,
     pub score: i32,
     pub enemy_survivors: Vec<BattleSurvivor>, // todo: track
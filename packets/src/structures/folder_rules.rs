@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+// limits on how many cards of each class a folder can hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FolderRules {
+    pub mega_limit: usize,
+    pub giga_limit: usize,
+    pub dark_limit: usize,
+}
+
+impl Default for FolderRules {
+    fn default() -> Self {
+        Self {
+            mega_limit: 5,
+            giga_limit: 1,
+            dark_limit: 3,
+        }
+    }
+}
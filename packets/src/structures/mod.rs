@@ -5,6 +5,7 @@ mod bbs_post;
 mod direction;
 mod emotion;
 mod file_hash;
+mod folder_rules;
 mod package_category;
 mod remote_player_info;
 mod shop_item;
@@ -16,6 +17,7 @@ pub use bbs_post::*;
 pub use direction::*;
 pub use emotion::*;
 pub use file_hash::*;
+pub use folder_rules::*;
 pub use package_category::*;
 pub use remote_player_info::*;
 pub use shop_item::*;
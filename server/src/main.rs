@@ -149,6 +149,20 @@ async fn main() {
           }
         }),
     )
+    .arg(
+      clap::Arg::new("transfer_peers")
+        .long("transfer-peer")
+        .value_name("IP_ADDRESS")
+        .help("Server allowed to transfer players to this server, can be repeated")
+        .takes_value(true)
+        .multiple_occurrences(true)
+        .validator(|value| {
+          value
+            .parse::<IpAddr>()
+            .map(|_| ())
+            .map_err(|_| String::from("Invalid IP address"))
+        }),
+    )
     .get_matches();
 
     let config = net::ServerConfig {
@@ -173,9 +187,14 @@ async fn main() {
         custom_emotes_path: matches
             .value_of("custom_emotes_path")
             .map(|path| path.to_string()),
+        transfer_peers: matches
+            .values_of("transfer_peers")
+            .map(|values| values.filter_map(|value| value.parse().ok()).collect())
+            .unwrap_or_default(),
         max_idle_packet_duration: 1.0,
         max_silence_duration: 5.0,
         heartbeat_rate: 0.5,
+        max_empty_instance_duration: 30.0,
     };
 
     let future = net::ServerBuilder::new(config)
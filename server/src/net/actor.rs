@@ -5,6 +5,8 @@ use std::time::Instant;
 pub struct Actor {
     pub id: String,
     pub name: String,
+    /// Hash of the player's identity, lets clients mute players regardless of their name
+    pub identity_hash: Option<String>,
     pub area_id: String,
     pub texture_path: String,
     pub animation_path: String,
@@ -28,6 +30,7 @@ impl Actor {
         ServerPacket::ActorConnected {
             actor_id: self.id.clone(),
             name: self.name.clone(),
+            identity_hash: self.identity_hash.clone(),
             texture_path: self.texture_path.clone(),
             animation_path: self.animation_path.clone(),
             direction: self.direction,
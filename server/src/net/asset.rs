@@ -109,6 +109,24 @@ impl Asset {
         }
     }
 
+    /// Returns the text of text assets, decompressing if necessary
+    pub fn text(&self) -> Option<String> {
+        use flate2::read::ZlibDecoder;
+        use std::io::Read;
+
+        match &self.data {
+            AssetData::Text(text) => Some(text.clone()),
+            AssetData::CompressedText(data) => {
+                let mut text = String::new();
+                let mut decoder = ZlibDecoder::new(data.as_slice());
+                decoder.read_to_string(&mut text).ok()?;
+
+                Some(text)
+            }
+            _ => None,
+        }
+    }
+
     // Resolves dependencies and alternate name. `load_from_*` functions automatically call this
     fn resolve_dependencies(&mut self, path: &std::path::Path) {
         let extension = path
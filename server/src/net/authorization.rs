@@ -0,0 +1,25 @@
+use std::net::SocketAddr;
+use std::time::Instant;
+
+pub struct Authorization {
+    pub identity: String,
+    pub host: String,
+    pub data: Vec<u8>,
+    // set when the host server transferred the player to us
+    pub player_id: Option<String>,
+}
+
+// handed to us by another server before transferring a player
+pub(super) struct TransferTicket {
+    pub origin_address: SocketAddr,
+    pub data: Vec<u8>,
+    pub creation_time: Instant,
+}
+
+// a player's login, held until the transfer ticket arrives for transferred players
+pub(super) struct LoginRequest {
+    pub player_id: String,
+    pub identity: String,
+    pub data: String,
+    pub creation_time: Instant,
+}
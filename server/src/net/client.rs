@@ -2,6 +2,7 @@ use super::{Actor, Direction, PlayerData, WidgetTracker};
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::net::SocketAddr;
+use std::time::Instant;
 
 #[derive(Default)]
 pub(super) struct BattleTrackingInfo {
@@ -22,6 +23,7 @@ pub(super) struct Client {
     pub ready: bool,
     pub transferring: bool,
     pub area_join_time: u64,
+    pub last_position_time: Instant,
     pub cached_assets: HashSet<String>,
     pub texture_buffer: Vec<u8>,
     pub animation_buffer: Vec<u8>,
@@ -46,16 +48,18 @@ impl Client {
         spawn_direction: Direction,
     ) -> Client {
         use super::asset;
-        use std::time::Instant;
+        use packets::structures::FileHash;
         use uuid::Uuid;
 
         let id = Uuid::new_v4().to_string();
+        let identity_hash = FileHash::hash(identity.as_bytes()).to_string();
 
         Client {
             socket_address,
             actor: Actor {
                 id: id.clone(),
                 name,
+                identity_hash: Some(identity_hash),
                 area_id,
                 texture_path: asset::get_player_texture_path(&id),
                 animation_path: asset::get_player_animation_path(&id),
@@ -82,6 +86,7 @@ impl Client {
             ready: false,
             transferring: false,
             area_join_time: 0,
+            last_position_time: Instant::now(),
             cached_assets: HashSet::new(),
             texture_buffer: Vec::new(),
             animation_buffer: Vec::new(),
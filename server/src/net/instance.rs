@@ -0,0 +1,74 @@
+use std::time::{Duration, Instant};
+
+/// An area cloned from a template for a group of players
+pub struct Instance {
+    id: String,
+    template_id: String,
+    members: Vec<String>,
+    empty_since: Option<Instant>,
+}
+
+impl Instance {
+    pub fn new(id: String, template_id: String) -> Instance {
+        Instance {
+            id,
+            template_id,
+            members: Vec::new(),
+            empty_since: None,
+        }
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_template_id(&self) -> &str {
+        &self.template_id
+    }
+
+    pub fn get_members(&self) -> &Vec<String> {
+        &self.members
+    }
+
+    pub fn is_member(&self, player_id: &str) -> bool {
+        self.members.iter().any(|id| id == player_id)
+    }
+
+    pub(super) fn add_member(&mut self, player_id: String) {
+        if !self.is_member(&player_id) {
+            self.members.push(player_id);
+        }
+    }
+
+    pub(super) fn remove_member(&mut self, player_id: &str) {
+        self.members
+            .iter()
+            .position(|id| id == player_id)
+            .map(|position| self.members.swap_remove(position));
+    }
+
+    /// How long the instance has been without players
+    pub fn get_empty_duration(&self) -> Option<Duration> {
+        self.empty_since.map(|instant| instant.elapsed())
+    }
+
+    pub(super) fn set_occupied(&mut self, occupied: bool) {
+        if occupied {
+            self.empty_since = None;
+        } else if self.empty_since.is_none() {
+            self.empty_since = Some(Instant::now());
+        }
+    }
+}
+
+pub(super) enum InstanceEvent {
+    Start {
+        instance_id: String,
+        template_id: String,
+        player_ids: Vec<String>,
+    },
+    End {
+        instance_id: String,
+        template_id: String,
+    },
+}
@@ -25,6 +25,7 @@ pub struct Map {
     foreground_vel_y: f32,
     foreground_parallax: f32,
     song_path: String,
+    minimap_texture_path: String,
     custom_properties: HashMap<String, String>,
     width: usize,
     height: usize,
@@ -59,6 +60,7 @@ impl Map {
             foreground_vel_y: 0.0,
             foreground_parallax: 0.0,
             song_path: String::new(),
+            minimap_texture_path: String::new(),
             custom_properties: HashMap::new(),
             width: 0,
             height: 0,
@@ -270,6 +272,18 @@ impl Map {
         self.mark_dirty();
     }
 
+    pub fn get_minimap_texture_path(&self) -> &String {
+        &self.minimap_texture_path
+    }
+
+    pub fn set_minimap_texture_path(&mut self, path: String) {
+        self.custom_properties
+            .insert(String::from("Minimap Texture"), path.clone());
+
+        self.minimap_texture_path = path;
+        self.mark_dirty();
+    }
+
     pub fn get_background_texture_path(&self) -> &String {
         &self.background_texture_path
     }
@@ -418,6 +432,9 @@ impl Map {
             "Song" => {
                 self.song_path = value;
             }
+            "Minimap Texture" => {
+                self.minimap_texture_path = value;
+            }
             _ => {}
         }
 
@@ -670,12 +687,25 @@ impl Map {
 
         let tileset_paths = self.tilesets.iter().map(|tileset| &tileset.path);
 
+        // npc paths are walked by actors created by the client
+        let npc_asset_paths = self
+            .objects
+            .iter()
+            .filter(|object| object.class.eq_ignore_ascii_case("NPC Path"))
+            .flat_map(|object| {
+                let properties = &object.custom_properties;
+                [properties.get("Texture"), properties.get("Animation")]
+            })
+            .flatten();
+
         let dependencies = tileset_paths
+            .chain(npc_asset_paths)
             .chain(std::iter::once(&self.background_texture_path))
             .chain(std::iter::once(&self.background_animation_path))
             .chain(std::iter::once(&self.foreground_texture_path))
             .chain(std::iter::once(&self.foreground_animation_path))
             .chain(std::iter::once(&self.song_path))
+            .chain(std::iter::once(&self.minimap_texture_path))
             .filter(|path| path.starts_with("/server/")) // provided by server
             .cloned()
             .map(AssetID::AssetPath)
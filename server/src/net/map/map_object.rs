@@ -125,6 +125,20 @@ impl MapObject {
         }
     }
 
+    /// Distance in tiles from a point to the edge of the object, ignoring rotation
+    pub fn distance_to(&self, x: f32, y: f32) -> f32 {
+        let (min_x, min_y, max_x, max_y) = match self.data {
+            // tile objects are anchored to the bottom corner of the tile they cover
+            MapObjectData::TileObject { .. } => (self.x - 1.0, self.y - 1.0, self.x, self.y),
+            _ => (self.x, self.y, self.x + self.width, self.y + self.height),
+        };
+
+        let distance_x = (min_x - x).max(x - max_x).max(0.0);
+        let distance_y = (min_y - y).max(y - max_y).max(0.0);
+
+        distance_x.hypot(distance_y)
+    }
+
     pub fn render(&mut self, scale_x: f32, scale_y: f32) -> String {
         use super::render_helpers::render_custom_properties;
 
@@ -5,11 +5,15 @@ mod actor;
 mod area;
 pub mod asset;
 mod asset_manager;
+mod authorization;
 mod boot;
 mod client;
+mod instance;
 mod item;
 pub mod map;
+mod movement;
 mod packet_orchestrator;
+mod pathfinding;
 mod player_data;
 mod plugin_wrapper;
 mod server;
@@ -22,6 +26,8 @@ pub(super) use packet_orchestrator::*;
 pub use actor::Actor;
 pub use area::Area;
 pub use asset::{Asset, AssetID, PackageInfo};
+pub use authorization::Authorization;
+pub use instance::Instance;
 pub use item::Item;
 pub use net::Net;
 pub use packets::structures::*;
@@ -0,0 +1,130 @@
+use super::map::Map;
+
+// world units per second, matches the client's run speed
+const RUN_SPEED: f32 = 140.0;
+
+// tiles per second, matches the client's defaults
+const DEFAULT_CONVEYOR_SPEED: f32 = 6.0;
+const DEFAULT_ICE_SPEED: f32 = 6.0;
+const DEFAULT_TREADMILL_SPEED: f32 = 1.875;
+
+/// Allows for packets bunching up in transit, in tiles
+const POSITION_TOLERANCE: f32 = 1.0;
+
+/// Clients send positions every tick, longer gaps shouldn't allow larger jumps
+const MAX_ELAPSED_SECS: f32 = 1.0;
+
+/// Doors are opened from the next tile over
+const DOOR_RANGE: f32 = 1.5;
+
+/// Fastest movement tiles in a tileset can cause, in tiles per second
+#[derive(Default, Clone, Copy)]
+pub struct TilesetSpeeds {
+    /// Conveyors and ice, replaces the player's own movement
+    pub sliding: f32,
+    /// Treadmills, added to the player's own movement
+    pub pushing: f32,
+}
+
+pub fn parse_tileset_speeds(text: &str) -> TilesetSpeeds {
+    let mut speeds = TilesetSpeeds::default();
+
+    let tileset_element = match text.parse::<minidom::Element>() {
+        Ok(tileset_element) => tileset_element,
+        Err(_) => return speeds,
+    };
+
+    for tile_element in tileset_element.children() {
+        if tile_element.name() != "tile" {
+            continue;
+        }
+
+        let tile_class = tile_element
+            .attr("class")
+            .or_else(|| tile_element.attr("type"))
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let default_speed = match tile_class.as_str() {
+            "conveyor" => DEFAULT_CONVEYOR_SPEED,
+            "ice" => DEFAULT_ICE_SPEED,
+            "treadmill" => DEFAULT_TREADMILL_SPEED,
+            _ => continue,
+        };
+
+        let speed = tile_element
+            .get_child("properties", minidom::NSChoice::Any)
+            .into_iter()
+            .flat_map(|properties_element| properties_element.children())
+            .find(|property_element| {
+                let name = property_element.attr("name").unwrap_or_default();
+                name.eq_ignore_ascii_case("speed")
+            })
+            .and_then(|property_element| property_element.attr("value"))
+            .and_then(|value| value.parse::<f32>().ok())
+            .unwrap_or(default_speed)
+            .abs();
+
+        if tile_class == "treadmill" {
+            speeds.pushing = speeds.pushing.max(speed);
+        } else {
+            speeds.sliding = speeds.sliding.max(speed);
+        }
+    }
+
+    speeds
+}
+
+/// Fastest a player can move on the map, in tiles per second
+pub fn max_player_speed(map: &Map, tileset_speeds: &[TilesetSpeeds]) -> f32 {
+    // tile space halves the tile width
+    let tile_size = (map.get_tile_width() / 2).min(map.get_tile_height()).max(1);
+    let run_speed = RUN_SPEED / tile_size as f32;
+
+    tileset_speeds.iter().fold(run_speed, |max_speed, speeds| {
+        max_speed
+            .max(run_speed + speeds.pushing)
+            .max(speeds.sliding)
+    })
+}
+
+/// Distance a player can cover in the elapsed time, in tiles
+pub fn max_player_distance(max_speed: f32, elapsed_secs: f32) -> f32 {
+    max_speed * elapsed_secs.min(MAX_ELAPSED_SECS) + POSITION_TOLERANCE
+}
+
+/// Destinations of Position Warps and same area Doors near the position,
+/// these warps are handled by the client without asking the server
+pub fn client_warp_targets(
+    map: &Map,
+    (x, y, z): (f32, f32, f32),
+    max_distance: f32,
+) -> Vec<(f32, f32, f32)> {
+    map.get_objects()
+        .iter()
+        .filter(|object| z >= 0.0 && z as usize == object.layer)
+        .filter_map(|object| {
+            let range = match object.class.to_lowercase().as_str() {
+                "position warp" => max_distance,
+                "door" if !object.custom_properties.contains_key("Area") => {
+                    max_distance + DOOR_RANGE
+                }
+                _ => return None,
+            };
+
+            if !object.custom_properties.contains_key("X") || object.distance_to(x, y) > range {
+                return None;
+            }
+
+            let get_f32 = |name: &str| {
+                object
+                    .custom_properties
+                    .get(name)
+                    .and_then(|value| value.parse::<f32>().ok())
+                    .unwrap_or_default()
+            };
+
+            Some((get_f32("X"), get_f32("Y"), get_f32("Z")))
+        })
+        .collect()
+}
@@ -1,12 +1,14 @@
 use super::asset_manager::AssetManager;
 use super::boot::Boot;
 use super::client::{BattleTrackingInfo, Client};
+use super::instance::InstanceEvent;
 use super::map::Map;
+use super::movement::TilesetSpeeds;
 use super::*;
 use crate::jobs::JobPromise;
 use crate::threads::ThreadMessage;
 use flume::Sender;
-use packets::{Reliability, ServerPacket};
+use packets::{Reliability, ServerCommPacket, ServerPacket};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -22,6 +24,10 @@ pub struct Net {
     active_plugin: usize,
     kick_list: Vec<Boot>,
     items: HashMap<String, Item>,
+    tileset_stairs: HashMap<String, Vec<(u32, Direction)>>,
+    tileset_speeds: HashMap<String, TilesetSpeeds>,
+    instances: HashMap<String, Instance>,
+    instance_events: Vec<InstanceEvent>,
 }
 
 impl Net {
@@ -80,6 +86,10 @@ impl Net {
             active_plugin: 0,
             kick_list: Vec::new(),
             items: HashMap::new(),
+            tileset_stairs: HashMap::new(),
+            tileset_speeds: HashMap::new(),
+            instances: HashMap::new(),
+            instance_events: Vec::new(),
         }
     }
 
@@ -88,6 +98,8 @@ impl Net {
     }
 
     pub fn set_asset(&mut self, path: String, asset: Asset) {
+        self.tileset_stairs.remove(&path);
+        self.tileset_speeds.remove(&path);
         self.asset_manager.set_asset(path.clone(), asset);
 
         update_cached_clients(
@@ -133,6 +145,10 @@ impl Net {
             return;
         }
 
+        if self.instances.contains_key(id) {
+            self.return_instance_players(id);
+        }
+
         let map_path = get_map_path(id);
         self.asset_manager.remove_asset(&map_path);
 
@@ -142,10 +158,121 @@ impl Net {
             for player_id in player_ids {
                 self.kick_player(player_id, "Area destroyed", true);
             }
+
+            for bot_id in area.get_connected_bots() {
+                self.bots.remove(bot_id);
+            }
+        }
+
+        if let Some(instance) = self.instances.remove(id) {
+            self.instance_events.push(InstanceEvent::End {
+                instance_id: id.to_string(),
+                template_id: instance.get_template_id().to_string(),
+            });
+        }
+    }
+
+    /// Moves players in the instance to the spawn of its template area, falls back to the default area
+    fn return_instance_players(&mut self, instance_id: &str) {
+        let Some(instance) = self.instances.get(instance_id) else {
+            return;
+        };
+
+        let template_id = instance.get_template_id();
+
+        let target_id = if self.areas.contains_key(template_id) {
+            template_id.to_string()
+        } else {
+            String::from("default")
+        };
+
+        let Some(target_area) = self.areas.get(&target_id) else {
+            return;
+        };
+
+        let target_map = target_area.get_map();
+        let (x, y, z) = target_map.get_spawn();
+        let direction = target_map.get_spawn_direction();
+
+        let player_ids = self
+            .areas
+            .get(instance_id)
+            .map(|area| area.get_connected_players().clone())
+            .unwrap_or_default();
+
+        for player_id in player_ids {
+            self.transfer_player(&player_id, &target_id, true, x, y, z, direction);
+        }
+    }
+
+    pub fn get_instances(&self) -> impl Iterator<Item = &Instance> {
+        self.instances.values()
+    }
+
+    pub fn get_instance(&self, instance_id: &str) -> Option<&Instance> {
+        self.instances.get(instance_id)
+    }
+
+    /// Clones the template area into a new area and transfers the players into it, returns the instance id
+    pub fn create_instance(&mut self, template_id: &str, player_ids: &[String]) -> Option<String> {
+        use uuid::Uuid;
+
+        let map = self.areas.get(template_id)?.get_map().clone();
+        let instance_id = format!("{template_id}-{}", Uuid::new_v4());
+
+        self.add_area(instance_id.clone(), map);
+
+        let instance = Instance::new(instance_id.clone(), template_id.to_string());
+        self.instances.insert(instance_id.clone(), instance);
+
+        for player_id in player_ids {
+            self.add_instance_member(&instance_id, player_id);
+        }
+
+        self.instance_events.push(InstanceEvent::Start {
+            instance_id: instance_id.clone(),
+            template_id: template_id.to_string(),
+            player_ids: self.instances[&instance_id].get_members().clone(),
+        });
+
+        Some(instance_id)
+    }
+
+    /// Adds the player to the instance and transfers them to its spawn
+    pub fn add_instance_member(&mut self, instance_id: &str, player_id: &str) {
+        if !self.instances.contains_key(instance_id) || !self.clients.contains_key(player_id) {
+            return;
+        }
+
+        // players can only be a member of one instance
+        for instance in self.instances.values_mut() {
+            instance.remove_member(player_id);
+        }
+
+        if let Some(instance) = self.instances.get_mut(instance_id) {
+            instance.add_member(player_id.to_string());
+        }
+
+        let Some(area) = self.areas.get(instance_id) else {
+            return;
+        };
+
+        let map = area.get_map();
+        let (x, y, z) = map.get_spawn();
+        let direction = map.get_spawn_direction();
+
+        self.transfer_player(player_id, instance_id, true, x, y, z, direction);
+    }
+
+    pub fn remove_instance_member(&mut self, instance_id: &str, player_id: &str) {
+        if let Some(instance) = self.instances.get_mut(instance_id) {
+            instance.remove_member(player_id);
         }
     }
 
     pub fn remove_asset(&mut self, path: &str) {
+        self.tileset_stairs.remove(path);
+        self.tileset_speeds.remove(path);
         self.asset_manager.remove_asset(path);
     }
 
@@ -318,6 +445,46 @@ impl Net {
             .send_by_id(target_id, Reliability::Reliable, packet);
     }
 
+    pub fn broadcast_chat(&mut self, actor_id: &str, message: &str) {
+        let area_id = if let Some(client) = self.clients.get(actor_id) {
+            &client.actor.area_id
+        } else if let Some(bot) = self.bots.get(actor_id) {
+            &bot.area_id
+        } else {
+            return;
+        };
+
+        let area = match self.areas.get(area_id) {
+            Some(area) => area,
+            None => return, // area deleted, should be getting kicked
+        };
+
+        let packet = ServerPacket::Chat {
+            actor_id: Some(actor_id.to_string()),
+            message: message.to_string(),
+        };
+
+        broadcast_to_area(
+            &mut *self.packet_orchestrator.borrow_mut(),
+            area,
+            Reliability::ReliableOrdered,
+            packet,
+        );
+    }
+
+    pub fn send_chat(&mut self, target_id: &str, actor_id: Option<&str>, message: &str) {
+        let packet = ServerPacket::Chat {
+            actor_id: actor_id.map(|id| id.to_string()),
+            message: message.to_string(),
+        };
+
+        self.packet_orchestrator.borrow_mut().send_by_id(
+            target_id,
+            Reliability::ReliableOrdered,
+            packet,
+        );
+    }
+
     pub fn set_player_minimap_color(&mut self, id: &str, color: (u8, u8, u8, u8)) {
         let client = match self.clients.get_mut(id) {
             Some(client) => client,
@@ -658,6 +825,45 @@ impl Net {
         }
     }
 
+    /// Fastest a player can move in the area, in tiles per second
+    pub(crate) fn max_player_speed(&mut self, area_id: &str) -> Option<f32> {
+        let area = self.areas.get(area_id)?;
+        let map = area.get_map();
+
+        let tileset_speeds: Vec<_> = map
+            .get_tilesets()
+            .iter()
+            .map(|tileset| {
+                *self
+                    .tileset_speeds
+                    .entry(tileset.path.clone())
+                    .or_insert_with(|| {
+                        self.asset_manager
+                            .get_asset(&tileset.path)
+                            .and_then(|asset| asset.text())
+                            .map(|text| super::movement::parse_tileset_speeds(&text))
+                            .unwrap_or_default()
+                    })
+            })
+            .collect();
+
+        Some(super::movement::max_player_speed(map, &tileset_speeds))
+    }
+
+    /// Destinations of warps the client can take on its own from the position
+    pub(crate) fn client_warp_targets(
+        &self,
+        area_id: &str,
+        position: (f32, f32, f32),
+        max_distance: f32,
+    ) -> Vec<(f32, f32, f32)> {
+        let Some(area) = self.areas.get(area_id) else {
+            return Vec::new();
+        };
+
+        super::movement::client_warp_targets(area.get_map(), position, max_distance)
+    }
+
     pub(crate) fn update_player_position(
         &mut self,
         id: &str,
@@ -949,6 +1155,7 @@ impl Net {
         ids: &[&str],
         package_path: Option<String>,
         data: Option<String>,
+        teams: Vec<u8>,
     ) {
         if let Some(package_path) = package_path.as_ref() {
             let player_ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
@@ -1000,6 +1207,7 @@ impl Net {
                         package_path: package_path.clone(),
                         data: data.clone(),
                         remote_players,
+                        teams: teams.clone(),
                     },
                 );
             }
@@ -1048,6 +1256,14 @@ impl Net {
         );
     }
 
+    pub fn set_folder_rules_for_player(&mut self, player_id: &str, rules: Option<FolderRules>) {
+        self.packet_orchestrator.borrow_mut().send_by_id(
+            player_id,
+            Reliability::ReliableOrdered,
+            ServerPacket::FolderRules { rules },
+        );
+    }
+
     pub fn offer_package(&mut self, player_id: &str, package_path: &str) {
         ensure_asset(
             &mut *self.packet_orchestrator.borrow_mut(),
@@ -1087,11 +1303,20 @@ impl Net {
                 continue;
             };
 
+            let hash = match &asset.data {
+                AssetData::Data(data) => FileHash::hash(data),
+                _ => {
+                    log::warn!("{:?} is not a zip", asset_path);
+                    continue;
+                }
+            };
+
             packets.push(ServerPacket::OfferPackage {
                 name: package_info.name.clone(),
                 id: package_info.id.clone(),
                 category: package_info.category,
                 package_path: asset_path.to_string(),
+                hash,
             });
         }
 
@@ -1308,6 +1533,99 @@ impl Net {
         }
     }
 
+    /// Applies the server side of built in objects, such as door transfers and chest rewards
+    pub(super) fn resolve_object_interaction(&mut self, player_id: &str, object_id: u32) {
+        // allows for the player's position lagging behind the interaction
+        const INTERACTION_RANGE: f32 = 1.5;
+
+        let Some(actor) = self.clients.get(player_id).map(|client| &client.actor) else {
+            return;
+        };
+
+        let area_id = &actor.area_id;
+
+        let Some(area) = self.areas.get_mut(area_id) else {
+            return;
+        };
+
+        let map = area.get_map_mut();
+
+        let Some(object) = map.get_object_by_id(object_id) else {
+            return;
+        };
+
+        if actor.z < 0.0
+            || actor.z as usize != object.layer
+            || object.distance_to(actor.x, actor.y) > INTERACTION_RANGE
+        {
+            log::debug!("{player_id} interacted with object {object_id} out of range");
+            return;
+        }
+
+        let class = object.class.to_lowercase();
+        let properties = object.custom_properties.clone();
+
+        let get_f32 = |name: &str| {
+            properties
+                .get(name)
+                .and_then(|value| value.parse::<f32>().ok())
+                .unwrap_or_default()
+        };
+
+        match class.as_str() {
+            "door" => {
+                let Some(target_area_id) = properties.get("Area") else {
+                    return;
+                };
+
+                let Some(target_area) = self.areas.get(target_area_id) else {
+                    log::warn!("Door {object_id} leads to a non existent area: {target_area_id:?}");
+                    return;
+                };
+
+                let target_map = target_area.get_map();
+
+                let (x, y, z) = if properties.contains_key("X") {
+                    (get_f32("X"), get_f32("Y"), get_f32("Z"))
+                } else {
+                    target_map.get_spawn()
+                };
+
+                let direction = match properties.get("Direction") {
+                    Some(direction_string) => Direction::from(direction_string.as_str()),
+                    None => target_map.get_spawn_direction(),
+                };
+
+                self.transfer_player(player_id, target_area_id, true, x, y, z, direction);
+            }
+            "chest" => {
+                if properties.get("Opened").map(String::as_str) == Some("true") {
+                    return;
+                }
+
+                map.set_object_custom_property(
+                    object_id,
+                    String::from("Opened"),
+                    String::from("true"),
+                );
+
+                if let Some(item_id) = properties.get("Item") {
+                    self.give_player_item(player_id, item_id.clone());
+                }
+
+                let money = properties
+                    .get("Money")
+                    .and_then(|value| value.parse::<u32>().ok());
+
+                if let (Some(money), Some(client)) = (money, self.clients.get(player_id)) {
+                    let total = client.player_data.money.saturating_add(money);
+                    self.set_player_money(player_id, total);
+                }
+            }
+            _ => {}
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn transfer_player(
         &mut self,
@@ -1448,12 +1766,26 @@ impl Net {
     }
 
     pub fn transfer_server(&mut self, id: &str, address: &str, data: &str, warp_out: bool) {
+        use uuid::Uuid;
+
+        // hand the destination a token to verify the transfer with
+        let token = Uuid::new_v4().to_string();
+
+        self.send_server_comm(
+            address.to_string(),
+            ServerCommPacket::TransferAuthorization {
+                token: token.clone(),
+                data: data.as_bytes().to_vec(),
+            },
+        );
+
         self.packet_orchestrator.borrow_mut().send_by_id(
             id,
             Reliability::ReliableOrdered,
             ServerPacket::TransferServer {
                 address: address.to_string(),
                 data: data.to_string(),
+                token,
                 warp_out,
             },
         );
@@ -1490,6 +1822,10 @@ impl Net {
         out
     }
 
+    pub(super) fn take_instance_events(&mut self) -> Vec<InstanceEvent> {
+        std::mem::take(&mut self.instance_events)
+    }
+
     pub(super) fn add_client(
         &mut self,
         socket_address: std::net::SocketAddr,
@@ -2056,6 +2392,122 @@ impl Net {
         }
     }
 
+    /// Finds a path between two tile positions, avoiding solid actors
+    pub fn find_path(
+        &mut self,
+        area_id: &str,
+        start: (f32, f32, f32),
+        goal: (f32, f32, f32),
+        max_length: Option<f32>,
+    ) -> Option<Vec<(f32, f32, f32)>> {
+        let area = self.areas.get(area_id)?;
+        let map = area.get_map();
+
+        let mut stairs = HashMap::new();
+
+        for tileset in map.get_tilesets() {
+            let tileset_stairs = self
+                .tileset_stairs
+                .entry(tileset.path.clone())
+                .or_insert_with(|| {
+                    self.asset_manager
+                        .get_asset(&tileset.path)
+                        .and_then(|asset| asset.text())
+                        .map(|text| super::pathfinding::parse_tileset_stairs(&text))
+                        .unwrap_or_default()
+                });
+
+            for (id, direction) in tileset_stairs {
+                stairs.insert(tileset.first_gid + *id, *direction);
+            }
+        }
+
+        let player_actors = area
+            .get_connected_players()
+            .iter()
+            .filter_map(|id| self.clients.get(id))
+            .map(|client| &client.actor);
+
+        let bot_actors = area
+            .get_connected_bots()
+            .iter()
+            .filter_map(|id| self.bots.get(id));
+
+        let blocked = player_actors
+            .chain(bot_actors)
+            .filter(|actor| actor.solid)
+            .map(|actor| {
+                (
+                    actor.x.floor() as i32,
+                    actor.y.floor() as i32,
+                    actor.z.floor() as i32,
+                )
+            })
+            .collect();
+
+        super::pathfinding::find_path(map, &stairs, &blocked, start, goal, max_length)
+    }
+
+    /// Animates the bot along the path at a speed in tiles per second, returns the duration in seconds
+    pub fn move_bot_along_path(
+        &mut self,
+        id: &str,
+        path: &[(f32, f32, f32)],
+        speed: Option<f32>,
+    ) -> f32 {
+        let Some(bot) = self.bots.get(id) else {
+            return 0.0;
+        };
+
+        let Some(area) = self.areas.get(&bot.area_id) else {
+            return 0.0;
+        };
+
+        let speed = speed.unwrap_or_else(|| {
+            // matches the walking speed of clients
+            const WALK_SPEED: f32 = 80.0;
+            WALK_SPEED / area.get_map().get_tile_height().max(1) as f32
+        });
+
+        if speed <= 0.0 {
+            return 0.0;
+        }
+
+        let mut previous = (bot.x, bot.y, bot.z);
+        let mut total_duration = 0.0;
+        let mut keyframes = Vec::with_capacity(path.len());
+
+        for &(x, y, z) in path {
+            let offset = (x - previous.0, y - previous.1);
+            let distance = (offset.0 * offset.0 + offset.1 * offset.1).sqrt();
+            let duration = distance.max((z - previous.2).abs()) / speed;
+
+            let mut property_steps = vec![
+                (ActorProperty::X(x), Ease::Linear),
+                (ActorProperty::Y(y), Ease::Linear),
+                (ActorProperty::Z(z), Ease::Linear),
+            ];
+
+            let direction = Direction::from_offset(offset);
+
+            if !matches!(direction, Direction::None) {
+                property_steps.push((ActorProperty::Direction(direction), Ease::Floor));
+            }
+
+            keyframes.push(KeyFrame {
+                property_steps,
+                duration,
+            });
+
+            total_duration += duration;
+            previous = (x, y, z);
+        }
+
+        self.animate_bot_properties(id, keyframes);
+
+        total_duration
+    }
+
     pub fn transfer_bot(&mut self, id: &str, area_id: &str, warp_in: bool, x: f32, y: f32, z: f32) {
         if self.areas.get(area_id).is_none() {
             // non existent area
@@ -2137,6 +2589,10 @@ impl Net {
     }
 
     pub fn message_server(&mut self, address: String, data: Vec<u8>) {
+        self.send_server_comm(address, ServerCommPacket::Message { data });
+    }
+
+    fn send_server_comm(&mut self, address: String, packet: ServerCommPacket) {
         let message_sender = self.message_sender.clone();
 
         async_std::task::spawn(async move {
@@ -2146,7 +2602,7 @@ impl Net {
                 message_sender
                     .send(ThreadMessage::MessageServer {
                         socket_address,
-                        data,
+                        packet,
                     })
                     .unwrap();
             }
@@ -2163,6 +2619,7 @@ impl Net {
     pub(super) fn tick(&mut self) {
         self.broadcast_bot_positions();
         self.broadcast_map_changes();
+        self.remove_empty_instances();
     }
 
     fn broadcast_bot_positions(&mut self) {
@@ -2199,6 +2656,52 @@ impl Net {
         }
     }
 
+    fn remove_empty_instances(&mut self) {
+        let max_empty_duration = self.config.max_empty_instance_duration;
+        let mut expired_ids = Vec::new();
+
+        for instance in self.instances.values_mut() {
+            let instance_id = instance.get_id().to_string();
+
+            // members leave by disconnecting or transferring out
+            let departed_ids: Vec<String> = instance
+                .get_members()
+                .iter()
+                .filter(|player_id| {
+                    !matches!(
+                        self.clients.get(*player_id),
+                        Some(client) if client.actor.area_id == instance_id
+                            || client.warp_area == instance_id
+                    )
+                })
+                .cloned()
+                .collect();
+
+            for player_id in departed_ids {
+                instance.remove_member(&player_id);
+            }
+
+            let has_players = matches!(
+                self.areas.get(&instance_id),
+                Some(area) if !area.get_connected_players().is_empty()
+            );
+
+            instance.set_occupied(has_players || !instance.get_members().is_empty());
+
+            let Some(empty_duration) = instance.get_empty_duration() else {
+                continue;
+            };
+
+            if empty_duration.as_secs_f32() >= max_empty_duration {
+                expired_ids.push(instance_id);
+            }
+        }
+
+        for instance_id in expired_ids {
+            self.remove_area(&instance_id);
+        }
+    }
+
     fn broadcast_map_changes(&mut self) {
         use super::asset::get_map_path;
 
@@ -671,9 +671,11 @@ mod tests {
             player_asset_limit: 0,
             avatar_dimensions_limit: 0,
             custom_emotes_path: None,
+            transfer_peers: Vec::new(),
             max_idle_packet_duration: 0.0,
             max_silence_duration: 0.0,
             heartbeat_rate: 0.0,
+            max_empty_instance_duration: 0.0,
         };
 
         PacketOrchestrator::new(Rc::new(socket), Rc::new(config))
@@ -0,0 +1,584 @@
+use super::map::{Map, MapObjectData, Tile};
+use super::Direction;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+type Node = (i32, i32, i32);
+
+const DIAGONAL_COST: f32 = std::f32::consts::SQRT_2;
+
+/// Gives up after visiting this many tiles, unreachable goals would otherwise search the whole map
+const MAX_EXPANSIONS: usize = 10_000;
+
+/// Reads the direction of every stairs tile in a tileset, ids are relative to the tileset
+pub fn parse_tileset_stairs(text: &str) -> Vec<(u32, Direction)> {
+    let mut stairs = Vec::new();
+
+    let tileset_element = match text.parse::<minidom::Element>() {
+        Ok(tileset_element) => tileset_element,
+        Err(_) => return stairs,
+    };
+
+    for tile_element in tileset_element.children() {
+        if tile_element.name() != "tile" {
+            continue;
+        }
+
+        let tile_class = tile_element
+            .attr("class")
+            .or_else(|| tile_element.attr("type"))
+            .unwrap_or_default();
+
+        if !tile_class.eq_ignore_ascii_case("stairs") {
+            continue;
+        }
+
+        let direction = tile_element
+            .get_child("properties", minidom::NSChoice::Any)
+            .into_iter()
+            .flat_map(|properties_element| properties_element.children())
+            .find(|property_element| {
+                let name = property_element.attr("name").unwrap_or_default();
+                name.eq_ignore_ascii_case("direction")
+            })
+            .and_then(|property_element| property_element.attr("value"))
+            .map(Direction::from)
+            .unwrap_or(Direction::None);
+
+        let id = tile_element
+            .attr("id")
+            .and_then(|id| id.parse::<u32>().ok())
+            .unwrap_or_default();
+
+        stairs.push((id, direction));
+    }
+
+    stairs
+}
+
+/// A* over the tiles of a map, returns tile centers from the tile after the start to the goal
+///
+/// Tiles are walkable if they're not empty, not covered by solid tile objects, and not blocked.
+/// Stairs connect layers. Returns None if the path would be longer than max_length tiles
+pub fn find_path(
+    map: &Map,
+    stairs: &HashMap<u32, Direction>,
+    blocked: &HashSet<Node>,
+    start: (f32, f32, f32),
+    goal: (f32, f32, f32),
+    max_length: Option<f32>,
+) -> Option<Vec<(f32, f32, f32)>> {
+    let grid = Grid::new(map, stairs, blocked, start, goal);
+
+    let start = grid.start;
+    let goal = grid.goal;
+
+    if !grid.is_walkable(start) || !grid.is_walkable(goal) {
+        return None;
+    }
+
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: HashMap<Node, Node> = HashMap::new();
+    let mut costs: HashMap<Node, f32> = HashMap::new();
+
+    costs.insert(start, 0.0);
+    open_set.push(OpenNode {
+        node: start,
+        estimate: heuristic(start, goal),
+    });
+
+    let mut expansions = 0;
+
+    while let Some(OpenNode { node, .. }) = open_set.pop() {
+        if node == goal {
+            return Some(grid.resolve_points(&came_from, goal));
+        }
+
+        expansions += 1;
+
+        if expansions > MAX_EXPANSIONS {
+            return None;
+        }
+
+        let cost = costs[&node];
+
+        for (neighbor, step_cost) in grid.neighbors(node) {
+            let neighbor_cost = cost + step_cost;
+
+            if matches!(max_length, Some(max_length) if neighbor_cost > max_length) {
+                continue;
+            }
+
+            if matches!(costs.get(&neighbor), Some(&existing_cost) if existing_cost <= neighbor_cost)
+            {
+                continue;
+            }
+
+            costs.insert(neighbor, neighbor_cost);
+            came_from.insert(neighbor, node);
+            open_set.push(OpenNode {
+                node: neighbor,
+                estimate: neighbor_cost + heuristic(neighbor, goal),
+            });
+        }
+    }
+
+    None
+}
+
+fn heuristic(a: Node, b: Node) -> f32 {
+    let dx = (a.0 - b.0).abs() as f32;
+    let dy = (a.1 - b.1).abs() as f32;
+    let dz = (a.2 - b.2).abs() as f32;
+
+    // octile distance
+    dx.max(dy) + (DIAGONAL_COST - 1.0) * dx.min(dy) + dz
+}
+
+struct OpenNode {
+    node: Node,
+    estimate: f32,
+}
+
+impl PartialEq for OpenNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed for a min heap
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+struct Grid<'a> {
+    map: &'a Map,
+    stairs: &'a HashMap<u32, Direction>,
+    blocked: &'a HashSet<Node>,
+    solid_objects: HashSet<Node>,
+    start: Node,
+    goal: Node,
+}
+
+impl<'a> Grid<'a> {
+    fn new(
+        map: &'a Map,
+        stairs: &'a HashMap<u32, Direction>,
+        blocked: &'a HashSet<Node>,
+        start: (f32, f32, f32),
+        goal: (f32, f32, f32),
+    ) -> Self {
+        let solid_objects = map
+            .get_objects()
+            .iter()
+            .filter(|object| is_solid_object_class(&object.class))
+            .filter(|object| matches!(object.data, MapObjectData::TileObject { .. }))
+            .map(|object| {
+                // tile objects are anchored to the bottom corner of the tile they cover
+                let x = (object.x - 0.5).floor() as i32;
+                let y = (object.y - 0.5).floor() as i32;
+
+                (x, y, object.layer as i32)
+            })
+            .collect();
+
+        Self {
+            map,
+            stairs,
+            blocked,
+            solid_objects,
+            start: to_node(start),
+            goal: to_node(goal),
+        }
+    }
+
+    fn tile_at(&self, (x, y, z): Node) -> Option<Tile> {
+        if x < 0 || y < 0 || z < 0 {
+            return None;
+        }
+
+        let (x, y, z) = (x as usize, y as usize, z as usize);
+
+        if x >= self.map.get_width()
+            || y >= self.map.get_height()
+            || z >= self.map.get_layer_count()
+        {
+            return None;
+        }
+
+        Some(self.map.get_tile(x, y, z))
+    }
+
+    /// The tile offset leading up the stairs
+    fn stairs_offset(&self, node: Node) -> Option<(i32, i32)> {
+        let tile = self.tile_at(node)?;
+        let mut direction = *self.stairs.get(&tile.gid)?;
+
+        if tile.flipped_horizontally {
+            direction = direction.horizontal_mirror();
+        }
+
+        if tile.flipped_vertically {
+            direction = direction.vertical_mirror();
+        }
+
+        match direction {
+            Direction::UpLeft => Some((-1, 0)),
+            Direction::UpRight => Some((0, -1)),
+            Direction::DownLeft => Some((0, 1)),
+            Direction::DownRight => Some((1, 0)),
+            _ => None,
+        }
+    }
+
+    fn is_walkable(&self, node: Node) -> bool {
+        if !matches!(self.tile_at(node), Some(tile) if tile.gid != 0) {
+            return false;
+        }
+
+        // tiles above stairs are treated as holes
+        let (x, y, z) = node;
+
+        if self.stairs_offset((x, y, z - 1)).is_some() {
+            return false;
+        }
+
+        if self.solid_objects.contains(&node) {
+            return false;
+        }
+
+        node == self.start || node == self.goal || !self.blocked.contains(&node)
+    }
+
+    fn neighbors(&self, node: Node) -> Vec<(Node, f32)> {
+        let (x, y, z) = node;
+        let mut neighbors = Vec::new();
+        let stairs_offset = self.stairs_offset(node);
+
+        for offset_y in -1..=1 {
+            for offset_x in -1..=1 {
+                if offset_x == 0 && offset_y == 0 {
+                    continue;
+                }
+
+                let neighbor = (x + offset_x, y + offset_y, z);
+
+                if !self.is_walkable(neighbor) {
+                    continue;
+                }
+
+                let neighbor_stairs_offset = self.stairs_offset(neighbor);
+
+                if offset_x != 0 && offset_y != 0 {
+                    // avoid diagonals on stairs and cutting corners
+                    if stairs_offset.is_some()
+                        || neighbor_stairs_offset.is_some()
+                        || !self.is_walkable((x + offset_x, y, z))
+                        || !self.is_walkable((x, y + offset_y, z))
+                    {
+                        continue;
+                    }
+
+                    neighbors.push((neighbor, DIAGONAL_COST));
+                    continue;
+                }
+
+                // the top of stairs leads to the next layer, not the current one
+                if stairs_offset == Some((offset_x, offset_y))
+                    || neighbor_stairs_offset == Some((-offset_x, -offset_y))
+                {
+                    continue;
+                }
+
+                neighbors.push((neighbor, 1.0));
+            }
+        }
+
+        // walking up stairs
+        if let Some((offset_x, offset_y)) = stairs_offset {
+            let neighbor = (x + offset_x, y + offset_y, z + 1);
+
+            if self.is_walkable(neighbor) {
+                neighbors.push((neighbor, 1.0));
+            }
+        }
+
+        // walking down stairs
+        for (offset_x, offset_y) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let neighbor = (x + offset_x, y + offset_y, z - 1);
+
+            if self.stairs_offset(neighbor) == Some((-offset_x, -offset_y))
+                && self.is_walkable(neighbor)
+            {
+                neighbors.push((neighbor, 1.0));
+            }
+        }
+
+        neighbors
+    }
+
+    fn resolve_points(&self, came_from: &HashMap<Node, Node>, goal: Node) -> Vec<(f32, f32, f32)> {
+        let mut nodes = vec![goal];
+        let mut node = goal;
+
+        while let Some(&previous) = came_from.get(&node) {
+            nodes.push(previous);
+            node = previous;
+        }
+
+        nodes.reverse();
+
+        let points: Vec<_> = nodes
+            .into_iter()
+            .map(|node| self.node_center(node))
+            .collect();
+
+        // skip points along straight lines to keep paths short
+        let mut simplified_points: Vec<(f32, f32, f32)> = Vec::new();
+
+        for (i, point) in points.iter().enumerate().skip(1) {
+            let previous = points[i - 1];
+
+            if let Some(next) = points.get(i + 1) {
+                let step = (
+                    point.0 - previous.0,
+                    point.1 - previous.1,
+                    point.2 - previous.2,
+                );
+                let next_step = (next.0 - point.0, next.1 - point.1, next.2 - point.2);
+
+                if step == next_step {
+                    continue;
+                }
+            }
+
+            simplified_points.push(*point);
+        }
+
+        simplified_points
+    }
+
+    fn node_center(&self, node: Node) -> (f32, f32, f32) {
+        let (x, y, z) = node;
+
+        // stairs are halfway between layers at their center
+        let elevation = if self.stairs_offset(node).is_some() {
+            z as f32 + 0.5
+        } else {
+            z as f32
+        };
+
+        (x as f32 + 0.5, y as f32 + 0.5, elevation)
+    }
+}
+
+fn to_node((x, y, z): (f32, f32, f32)) -> Node {
+    (x.floor() as i32, y.floor() as i32, z.floor() as i32)
+}
+
+fn is_solid_object_class(class: &str) -> bool {
+    !matches!(
+        class.to_lowercase().as_str(),
+        "home warp"
+            | "position warp"
+            | "server warp"
+            | "custom server warp"
+            | "custom warp"
+            | "npc path"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLOOR: u32 = 1;
+    const STAIRS: u32 = 2;
+
+    /// Builds a map from rows of tiles for each layer, `.` is floor, `s` is stairs, anything else is a hole
+    fn create_map(layers: &[&[&str]]) -> Map {
+        let height = layers[0].len();
+        let width = layers[0][0].len();
+        let mut layer_elements = String::new();
+
+        for (i, rows) in layers.iter().enumerate() {
+            let data: Vec<_> = rows
+                .iter()
+                .flat_map(|row| row.chars())
+                .map(|c| match c {
+                    '.' => FLOOR,
+                    's' => STAIRS,
+                    _ => 0,
+                })
+                .map(|gid| gid.to_string())
+                .collect();
+
+            layer_elements += &format!(
+                r#"<layer id="{id}" name="Floor {i}" width="{width}" height="{height}" offsety="{offset}"><data encoding="csv">{data}</data></layer><objectgroup id="{object_id}" name="Floor {i}" offsety="{offset}"/>"#,
+                id = i * 2 + 1,
+                object_id = i * 2 + 2,
+                offset = i as i32 * -16,
+                data = data.join(","),
+            );
+        }
+
+        Map::from(&format!(
+            r#"<map version="1.5" orientation="isometric" width="{width}" height="{height}" tilewidth="64" tileheight="32" nextlayerid="{}" nextobjectid="1">{layer_elements}</map>"#,
+            layers.len() * 2 + 1,
+        ))
+    }
+
+    fn stairs_up_right() -> HashMap<u32, Direction> {
+        HashMap::from([(STAIRS, Direction::UpRight)])
+    }
+
+    #[test]
+    fn walls() {
+        let map = create_map(&[&["...", ".x.", "..."]]);
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &HashSet::new(),
+            (0.5, 1.5, 0.0),
+            (2.5, 1.5, 0.0),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(path.last(), Some(&(2.5, 1.5, 0.0)));
+        assert!(!path.contains(&(1.5, 1.5, 0.0)));
+
+        let map = create_map(&[&[".x.", ".x.", ".x."]]);
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &HashSet::new(),
+            (0.5, 1.5, 0.0),
+            (2.5, 1.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn corners() {
+        let map = create_map(&[&[".x", ".."]]);
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &HashSet::new(),
+            (0.5, 0.5, 0.0),
+            (1.5, 1.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, Some(vec![(0.5, 1.5, 0.0), (1.5, 1.5, 0.0)]));
+    }
+
+    #[test]
+    fn stairs() {
+        let map = create_map(&[&[".", "s", "."], &[".", "x", "x"]]);
+        let stairs = stairs_up_right();
+
+        let path = find_path(
+            &map,
+            &stairs,
+            &HashSet::new(),
+            (0.5, 2.5, 0.0),
+            (0.5, 0.5, 1.0),
+            None,
+        );
+
+        assert_eq!(path, Some(vec![(0.5, 0.5, 1.0)]));
+
+        let path = find_path(
+            &map,
+            &stairs,
+            &HashSet::new(),
+            (0.5, 0.5, 1.0),
+            (0.5, 2.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, Some(vec![(0.5, 2.5, 0.0)]));
+
+        // the top of the stairs doesn't lead to the lower layer
+        let path = find_path(
+            &map,
+            &stairs,
+            &HashSet::new(),
+            (0.5, 2.5, 0.0),
+            (0.5, 0.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn blocked_actors() {
+        let map = create_map(&[&["..."]]);
+        let blocked = HashSet::from([(0, 0, 0), (1, 0, 0)]);
+
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &blocked,
+            (0.5, 0.5, 0.0),
+            (2.5, 0.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, None);
+
+        // actors standing on the start or goal don't block the path
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &blocked,
+            (0.5, 0.5, 0.0),
+            (1.5, 0.5, 0.0),
+            None,
+        );
+
+        assert_eq!(path, Some(vec![(1.5, 0.5, 0.0)]));
+    }
+
+    #[test]
+    fn max_length() {
+        let map = create_map(&[&[".........."]]);
+
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &HashSet::new(),
+            (0.5, 0.5, 0.0),
+            (9.5, 0.5, 0.0),
+            Some(3.0),
+        );
+
+        assert_eq!(path, None);
+
+        let path = find_path(
+            &map,
+            &HashMap::new(),
+            &HashSet::new(),
+            (0.5, 0.5, 0.0),
+            (9.5, 0.5, 0.0),
+            Some(9.0),
+        );
+
+        assert_eq!(path, Some(vec![(9.5, 0.5, 0.0)]));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{BattleStatistics, Net};
+use super::{Authorization, BattleStatistics, Net};
 use crate::plugins::PluginInterface;
 
 pub(super) struct PluginWrapper {
@@ -50,9 +50,9 @@ impl PluginInterface for PluginWrapper {
         });
     }
 
-    fn handle_authorization(&mut self, net: &mut Net, identity: &str, host: &str, data: &[u8]) {
+    fn handle_authorization(&mut self, net: &mut Net, authorization: &Authorization) {
         self.wrap_calls(net, |plugin_interface, net| {
-            plugin_interface.handle_authorization(net, identity, host, data);
+            plugin_interface.handle_authorization(net, authorization);
         });
     }
 
@@ -129,6 +129,24 @@ impl PluginInterface for PluginWrapper {
         prevent_default
     }
 
+    fn handle_player_chat(
+        &mut self,
+        net: &mut Net,
+        player_id: &str,
+        message: &str,
+    ) -> Option<String> {
+        let mut message = Some(message.to_string());
+
+        // each plugin sees the message rewritten by the previous plugins
+        self.wrap_calls(net, |plugin_interface, net| {
+            if let Some(text) = message.take() {
+                message = plugin_interface.handle_player_chat(net, player_id, &text);
+            }
+        });
+
+        message
+    }
+
     fn handle_custom_warp(&mut self, net: &mut Net, player_id: &str, tile_object_id: u32) {
         self.wrap_calls(net, |plugin_interface, net| {
             plugin_interface.handle_custom_warp(net, player_id, tile_object_id)
@@ -255,6 +273,12 @@ impl PluginInterface for PluginWrapper {
         }
     }
 
+    fn handle_item_use(&mut self, net: &mut Net, player_id: &str, item_id: &str) {
+        self.wrap_calls(net, |plugin_interface, net| {
+            plugin_interface.handle_item_use(net, player_id, item_id)
+        });
+    }
+
     fn handle_battle_results(
         &mut self,
         net: &mut Net,
@@ -272,6 +296,24 @@ impl PluginInterface for PluginWrapper {
         }
     }
 
+    fn handle_instance_start(
+        &mut self,
+        net: &mut Net,
+        instance_id: &str,
+        template_id: &str,
+        player_ids: &[String],
+    ) {
+        self.wrap_calls(net, |plugin_interface, net| {
+            plugin_interface.handle_instance_start(net, instance_id, template_id, player_ids)
+        });
+    }
+
+    fn handle_instance_end(&mut self, net: &mut Net, instance_id: &str, template_id: &str) {
+        self.wrap_calls(net, |plugin_interface, net| {
+            plugin_interface.handle_instance_end(net, instance_id, template_id)
+        });
+    }
+
     fn handle_server_message(
         &mut self,
         net: &mut Net,
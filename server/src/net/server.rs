@@ -1,5 +1,8 @@
+use super::authorization::{LoginRequest, TransferTicket};
+use super::instance::InstanceEvent;
+use super::movement::max_player_distance;
 use super::plugin_wrapper::PluginWrapper;
-use super::{Net, PacketOrchestrator, ServerConfig};
+use super::{Authorization, Net, PacketOrchestrator, ServerConfig};
 use crate::jobs::{JobPromise, PromiseValue};
 use crate::plugins::PluginInterface;
 use crate::threads::{create_listening_thread, ListenerMessage, ThreadMessage};
@@ -11,7 +14,12 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::net::{SocketAddr, UdpSocket};
 use std::rc::Rc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+const TRANSFER_TICKET_LIFETIME: Duration = Duration::from_secs(30);
+const TRANSFER_LOGIN_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_TRANSFER_TICKETS: usize = 1024;
+const MAX_CHAT_LENGTH: usize = 256;
 
 pub struct Server {
     player_id_map: HashMap<SocketAddr, String>,
@@ -24,6 +32,8 @@ pub struct Server {
     time: Instant,
     last_heartbeat: Instant,
     pending_server_polls: HashMap<SocketAddr, Vec<JobPromise>>,
+    transfer_tickets: HashMap<String, TransferTicket>,
+    pending_transfer_logins: HashMap<String, LoginRequest>,
 }
 
 impl Server {
@@ -59,6 +69,8 @@ impl Server {
             time: Instant::now(),
             last_heartbeat: Instant::now(),
             pending_server_polls: HashMap::new(),
+            transfer_tickets: HashMap::new(),
+            pending_transfer_logins: HashMap::new(),
         }
     }
 
@@ -115,13 +127,13 @@ impl Server {
                             let packet_orchestrator = self.packet_orchestrator.borrow_mut();
                             packet_orchestrator.forward_netplay_packet(socket_address, packet);
                         }
-                        ThreadMessage::MessageServer { socket_address, data } => {
+                        ThreadMessage::MessageServer { socket_address, packet } => {
                             let mut packet_orchestrator = self.packet_orchestrator.borrow_mut();
                             packet_orchestrator.create_connection(socket_address);
                             packet_orchestrator.send_server_comm(
                                 socket_address,
                                 Reliability::ReliableOrdered,
-                                ServerCommPacket::Message { data }
+                                packet
                             );
                         }
                         ThreadMessage::PollServer { socket_address, promise } => {
@@ -183,6 +195,46 @@ impl Server {
 
         self.net.tick();
 
+        for event in self.net.take_instance_events() {
+            match event {
+                InstanceEvent::Start {
+                    instance_id,
+                    template_id,
+                    player_ids,
+                } => self.plugin_wrapper.handle_instance_start(
+                    &mut self.net,
+                    &instance_id,
+                    &template_id,
+                    &player_ids,
+                ),
+                InstanceEvent::End {
+                    instance_id,
+                    template_id,
+                } => self.plugin_wrapper.handle_instance_end(
+                    &mut self.net,
+                    &instance_id,
+                    &template_id,
+                ),
+            }
+        }
+
+        self.transfer_tickets
+            .retain(|_, ticket| ticket.creation_time.elapsed() < TRANSFER_TICKET_LIFETIME);
+
+        // stop waiting on tickets that never arrived
+        let expired_tokens: Vec<String> = self
+            .pending_transfer_logins
+            .iter()
+            .filter(|(_, login)| login.creation_time.elapsed() >= TRANSFER_LOGIN_TIMEOUT)
+            .map(|(token, _)| token.clone())
+            .collect();
+
+        for token in expired_tokens {
+            if let Some(login) = self.pending_transfer_logins.remove(&token) {
+                self.complete_login(login, None);
+            }
+        }
+
         if self.last_heartbeat.elapsed().as_secs_f32() >= self.config.heartbeat_rate {
             self.packet_orchestrator
                 .borrow_mut()
@@ -228,6 +280,29 @@ impl Server {
                 self.plugin_wrapper
                     .handle_server_message(&mut self.net, socket_address, &data);
             }
+            ServerCommPacket::TransferAuthorization { token, data } => {
+                if !self.config.transfer_peers.contains(&socket_address.ip()) {
+                    log::warn!(
+                        "Ignoring transfer authorization from untrusted server {socket_address}"
+                    );
+                    return;
+                }
+
+                let ticket = TransferTicket {
+                    origin_address: socket_address,
+                    data,
+                    creation_time: Instant::now(),
+                };
+
+                if let Some(login) = self.pending_transfer_logins.remove(&token) {
+                    // the player arrived before the ticket
+                    self.complete_login(login, Some(ticket));
+                } else if self.transfer_tickets.len() < MAX_TRANSFER_TICKETS {
+                    self.transfer_tickets.insert(token, ticket);
+                } else {
+                    log::warn!("Too many pending transfers, dropping ticket from {socket_address}");
+                }
+            }
         }
     }
 
@@ -326,8 +401,60 @@ impl Server {
                     z,
                     direction,
                 } => {
+                    let max_speed = net
+                        .get_player(player_id)
+                        .map(|actor| actor.area_id.clone())
+                        .and_then(|area_id| net.max_player_speed(&area_id))
+                        .unwrap_or_default();
+
+                    let max_distance = net
+                        .get_client(player_id)
+                        .map(|client| client.last_position_time.elapsed().as_secs_f32())
+                        .map(|elapsed_secs| max_player_distance(max_speed, elapsed_secs))
+                        .unwrap_or_default();
+
+                    // position warps and doors within the area are resolved by the client
+                    let client_warp_targets = net
+                        .get_player(player_id)
+                        .map(|actor| {
+                            let position = (actor.x, actor.y, actor.z);
+                            net.client_warp_targets(&actor.area_id, position, max_distance)
+                        })
+                        .unwrap_or_default();
+
                     if let Some(client) = net.get_client_mut(player_id) {
                         if client.ready && creation_time > client.area_join_time {
+                            let actor = &client.actor;
+                            let distance = (x - actor.x).hypot(y - actor.y);
+
+                            // teleports move the client to the warp position
+                            let server_warp = (client.warp_x, client.warp_y, client.warp_z);
+
+                            let warped = client_warp_targets
+                                .into_iter()
+                                .chain(std::iter::once(server_warp))
+                                .any(|(warp_x, warp_y, warp_z)| {
+                                    (x - warp_x).hypot(y - warp_y) <= max_distance
+                                        && (z - warp_z).abs() <= max_distance
+                                });
+
+                            if !warped
+                                && (distance > max_distance || (z - actor.z).abs() > max_distance)
+                            {
+                                // move the client back to the last valid position
+                                let (x, y, z, direction) =
+                                    (actor.x, actor.y, actor.z, actor.direction);
+
+                                if self.config.log_packets {
+                                    log::debug!("{player_id} moved too far, correcting position");
+                                }
+
+                                net.teleport_player(player_id, false, x, y, z, direction);
+                                return;
+                            }
+
+                            client.last_position_time = Instant::now();
+
                             #[allow(clippy::float_cmp)]
                             let position_changed =
                                 client.actor.x != x || client.actor.y != y || client.actor.z != z;
@@ -346,6 +473,7 @@ impl Server {
                 ClientPacket::Ready { time } => {
                     if let Some(client) = net.get_client_mut(player_id) {
                         client.area_join_time = time;
+                        client.last_position_time = Instant::now();
                         client.actor.x = client.warp_x;
                         client.actor.y = client.warp_y;
                         client.actor.z = client.warp_z;
@@ -399,6 +527,18 @@ impl Server {
                         net.set_player_emote(player_id, emote_id, false);
                     }
                 }
+                ClientPacket::Chat { message } => {
+                    // protect against attackers
+                    let message: String = message.chars().take(MAX_CHAT_LENGTH).collect();
+
+                    let message = self
+                        .plugin_wrapper
+                        .handle_player_chat(net, player_id, &message);
+
+                    if let Some(message) = message {
+                        net.broadcast_chat(player_id, &message);
+                    }
+                }
                 ClientPacket::ObjectInteraction {
                     tile_object_id,
                     button,
@@ -410,6 +550,10 @@ impl Server {
                             tile_object_id,
                             button,
                         );
+
+                        if button == 0 {
+                            net.resolve_object_interaction(player_id, tile_object_id);
+                        }
                     }
                 }
                 ClientPacket::ActorInteraction { actor_id, button } => {
@@ -470,6 +614,18 @@ impl Server {
                     self.plugin_wrapper
                         .handle_shop_purchase(net, player_id, &item_name);
                 }
+                ClientPacket::ItemUse { item_id } => {
+                    let has_item = net
+                        .get_player_data(player_id)
+                        .map(|player_data| player_data.items.contains(&item_id))
+                        .unwrap_or_default();
+
+                    // protect against attackers
+                    if has_item {
+                        self.plugin_wrapper
+                            .handle_item_use(net, player_id, &item_id);
+                    }
+                }
                 ClientPacket::EncounterStart => {
                     if let Some(client) = net.get_client_mut(player_id) {
                         if let Some(info) = client.battle_tracker.front() {
@@ -502,24 +658,46 @@ impl Server {
                     identity,
                     data,
                 } => {
-                    self.plugin_wrapper.handle_authorization(
-                        net,
-                        &identity,
-                        &origin_address,
-                        &data,
-                    );
+                    let authorization = Authorization {
+                        identity,
+                        host: origin_address,
+                        data,
+                        player_id: None,
+                    };
+
+                    self.plugin_wrapper
+                        .handle_authorization(net, &authorization);
                 }
                 ClientPacket::Login {
                     username,
                     identity,
                     data,
+                    transfer_token,
                 } => {
-                    let player_id = net.add_client(socket_address, username, identity);
+                    let player_id = net.add_client(socket_address, username, identity.clone());
 
                     self.player_id_map.insert(socket_address, player_id.clone());
 
-                    self.plugin_wrapper
-                        .handle_player_request(net, &player_id, &data);
+                    let login = LoginRequest {
+                        player_id,
+                        identity,
+                        data,
+                        creation_time: Instant::now(),
+                    };
+
+                    let Some(token) = transfer_token else {
+                        self.complete_login(login, None);
+                        return;
+                    };
+
+                    if let Some(ticket) = self.transfer_tickets.remove(&token) {
+                        self.complete_login(login, Some(ticket));
+                    } else if self.pending_transfer_logins.len() < MAX_TRANSFER_TICKETS {
+                        // the origin server's ticket may still be in transit
+                        self.pending_transfer_logins.insert(token, login);
+                    } else {
+                        self.complete_login(login, None);
+                    }
                 }
                 _ => {
                     if self.config.log_packets {
@@ -532,6 +710,28 @@ impl Server {
         }
     }
 
+    fn complete_login(&mut self, login: LoginRequest, ticket: Option<TransferTicket>) {
+        if self.net.get_player(&login.player_id).is_none() {
+            // disconnected while waiting for the ticket
+            return;
+        }
+
+        if let Some(ticket) = ticket {
+            let authorization = Authorization {
+                identity: login.identity,
+                host: ticket.origin_address.to_string(),
+                data: ticket.data,
+                player_id: Some(login.player_id.clone()),
+            };
+
+            self.plugin_wrapper
+                .handle_authorization(&mut self.net, &authorization);
+        }
+
+        self.plugin_wrapper
+            .handle_player_request(&mut self.net, &login.player_id, &login.data);
+    }
+
     fn disconnect_client(&mut self, socket_address: SocketAddr, reason: &str, warp_out: bool) {
         if let Some(player_id) = self.player_id_map.remove(&socket_address) {
             self.plugin_wrapper
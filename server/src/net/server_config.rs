@@ -10,7 +10,9 @@ pub struct ServerConfig {
     pub player_asset_limit: usize,
     pub avatar_dimensions_limit: u32,
     pub custom_emotes_path: Option<String>,
+    pub transfer_peers: Vec<std::net::IpAddr>,
     pub max_idle_packet_duration: f32,
     pub max_silence_duration: f32,
     pub heartbeat_rate: f32,
+    pub max_empty_instance_duration: f32,
 }
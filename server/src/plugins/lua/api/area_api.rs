@@ -256,6 +256,36 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
         }
     });
 
+    lua_api.add_dynamic_function("Net", "get_minimap", |api_ctx, lua_ctx, params| {
+        let area_id: mlua::String = lua_ctx.unpack_multi(params)?;
+        let area_id_str = area_id.to_str()?;
+
+        let net = api_ctx.net_ref.borrow();
+
+        if let Some(area) = net.get_area(area_id_str) {
+            lua_ctx.pack_multi(area.get_map().get_minimap_texture_path().as_str())
+        } else {
+            Err(create_area_error(area_id_str))
+        }
+    });
+
+    lua_api.add_dynamic_function("Net", "set_minimap", |api_ctx, lua_ctx, params| {
+        let (area_id, path): (mlua::String, String) = lua_ctx.unpack_multi(params)?;
+        let area_id_str = area_id.to_str()?;
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        if let Some(area) = net.get_area_mut(area_id_str) {
+            let map = area.get_map_mut();
+
+            map.set_minimap_texture_path(path);
+
+            lua_ctx.pack_multi(())
+        } else {
+            Err(create_area_error(area_id_str))
+        }
+    });
+
     lua_api.add_dynamic_function("Net", "get_background", |api_ctx, lua_ctx, params| {
         let area_id: mlua::String = lua_ctx.unpack_multi(params)?;
         let area_id_str = area_id.to_str()?;
@@ -319,6 +319,22 @@ Net:on("battle_results", function(event)
   battle_trackers[player_id]:resolve(event)
 end)
 
+-- bot paths
+
+function Async.move_bot_along_path(bot_id, path, speed)
+  local duration = Net._move_bot_along_path(bot_id, path, speed)
+
+  return Async.sleep(duration)
+end
+
+function Net.move_bot_along_path(bot_id, path, speed, callback)
+  local promise = Async.move_bot_along_path(bot_id, path, speed)
+
+  if callback then
+    promise.and_then(callback)
+  end
+end
+
 -- shops
 
 local shop_emitters = {}
@@ -71,6 +71,7 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
             let bot = Actor {
                 id: bot_id.clone(),
                 name: name.unwrap_or_default(),
+                identity_hash: None,
                 area_id,
                 texture_path: texture_path.unwrap_or_default(),
                 animation_path: animation_path.unwrap_or_default(),
@@ -211,6 +212,78 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
         lua_ctx.pack_multi(())
     });
 
+    lua_api.add_dynamic_function("Net", "find_path", |api_ctx, lua_ctx, params| {
+        let (area_id, start_table, goal_table, max_length): (
+            mlua::String,
+            mlua::Table,
+            mlua::Table,
+            Option<f32>,
+        ) = lua_ctx.unpack_multi(params)?;
+        let area_id_str = area_id.to_str()?;
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        if net.get_area(area_id_str).is_none() {
+            return Err(create_area_error(area_id_str));
+        }
+
+        let start = (
+            start_table.get("x")?,
+            start_table.get("y")?,
+            start_table.get("z")?,
+        );
+
+        let goal = (
+            goal_table.get("x")?,
+            goal_table.get("y")?,
+            goal_table.get("z")?,
+        );
+
+        let Some(path) = net.find_path(area_id_str, start, goal, max_length) else {
+            return lua_ctx.pack_multi(mlua::Nil);
+        };
+
+        let path_table = lua_ctx.create_table()?;
+
+        for (i, (x, y, z)) in path.into_iter().enumerate() {
+            let point_table = lua_ctx.create_table()?;
+            point_table.set("x", x)?;
+            point_table.set("y", y)?;
+            point_table.set("z", z)?;
+
+            path_table.set(i + 1, point_table)?;
+        }
+
+        lua_ctx.pack_multi(path_table)
+    });
+
+    lua_api.add_dynamic_function("Net", "_move_bot_along_path", |api_ctx, lua_ctx, params| {
+        let (bot_id, point_tables, speed): (mlua::String, Vec<mlua::Table>, Option<f32>) =
+            lua_ctx.unpack_multi(params)?;
+        let bot_id_str = bot_id.to_str()?;
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        if net.get_bot(bot_id_str).is_none() {
+            return Err(create_bot_error(bot_id_str));
+        }
+
+        let path = point_tables
+            .into_iter()
+            .map(|point_table| {
+                Ok((
+                    point_table.get("x")?,
+                    point_table.get("y")?,
+                    point_table.get("z")?,
+                ))
+            })
+            .collect::<mlua::Result<Vec<_>>>()?;
+
+        let duration = net.move_bot_along_path(bot_id_str, &path, speed);
+
+        lua_ctx.pack_multi(duration)
+    });
+
     lua_api.add_dynamic_function("Net", "animate_bot", |api_ctx, lua_ctx, params| {
         let (bot_id, name, loop_option): (mlua::String, mlua::String, Option<bool>) =
             lua_ctx.unpack_multi(params)?;
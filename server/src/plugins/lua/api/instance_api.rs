@@ -0,0 +1,136 @@
+use super::lua_errors::{create_area_error, create_instance_error, create_player_error};
+use super::LuaApi;
+
+pub fn inject_dynamic(lua_api: &mut LuaApi) {
+    lua_api.add_dynamic_function("Net", "create_instance", |api_ctx, lua_ctx, params| {
+        let (template_id, player_ids): (mlua::String, Option<Vec<String>>) =
+            lua_ctx.unpack_multi(params)?;
+        let template_id_str = template_id.to_str()?;
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        let player_ids = player_ids.unwrap_or_default();
+
+        if let Some(instance_id) = net.create_instance(template_id_str, &player_ids) {
+            lua_ctx.pack_multi(instance_id)
+        } else {
+            Err(create_area_error(template_id_str))
+        }
+    });
+
+    lua_api.add_dynamic_function("Net", "is_instance", |api_ctx, lua_ctx, params| {
+        let area_id: mlua::String = lua_ctx.unpack_multi(params)?;
+        let area_id_str = area_id.to_str()?;
+
+        let net = api_ctx.net_ref.borrow();
+
+        lua_ctx.pack_multi(net.get_instance(area_id_str).is_some())
+    });
+
+    lua_api.add_dynamic_function("Net", "list_instances", |api_ctx, lua_ctx, params| {
+        let template_id: Option<mlua::String> = lua_ctx.unpack_multi(params)?;
+        let template_id_str = template_id.as_ref().map(|id| id.to_str()).transpose()?;
+
+        let net = api_ctx.net_ref.borrow();
+
+        let instance_ids: mlua::Result<Vec<mlua::String>> = net
+            .get_instances()
+            .filter(|instance| {
+                template_id_str.is_none() || Some(instance.get_template_id()) == template_id_str
+            })
+            .map(|instance| lua_ctx.create_string(instance.get_id()))
+            .collect();
+
+        lua_ctx.pack_multi(instance_ids?)
+    });
+
+    lua_api.add_dynamic_function(
+        "Net",
+        "get_instance_template",
+        |api_ctx, lua_ctx, params| {
+            let instance_id: mlua::String = lua_ctx.unpack_multi(params)?;
+            let instance_id_str = instance_id.to_str()?;
+
+            let net = api_ctx.net_ref.borrow();
+
+            if let Some(instance) = net.get_instance(instance_id_str) {
+                lua_ctx.pack_multi(instance.get_template_id())
+            } else {
+                Err(create_instance_error(instance_id_str))
+            }
+        },
+    );
+
+    lua_api.add_dynamic_function(
+        "Net",
+        "list_instance_members",
+        |api_ctx, lua_ctx, params| {
+            let instance_id: mlua::String = lua_ctx.unpack_multi(params)?;
+            let instance_id_str = instance_id.to_str()?;
+
+            let net = api_ctx.net_ref.borrow();
+
+            if let Some(instance) = net.get_instance(instance_id_str) {
+                let player_ids: mlua::Result<Vec<mlua::String>> = instance
+                    .get_members()
+                    .iter()
+                    .map(|player_id| lua_ctx.create_string(player_id))
+                    .collect();
+
+                lua_ctx.pack_multi(player_ids?)
+            } else {
+                Err(create_instance_error(instance_id_str))
+            }
+        },
+    );
+
+    lua_api.add_dynamic_function("Net", "add_instance_member", |api_ctx, lua_ctx, params| {
+        let (instance_id, player_id): (mlua::String, mlua::String) =
+            lua_ctx.unpack_multi(params)?;
+        let (instance_id_str, player_id_str) = (instance_id.to_str()?, player_id.to_str()?);
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        if net.get_instance(instance_id_str).is_none() {
+            return Err(create_instance_error(instance_id_str));
+        }
+
+        if net.get_player(player_id_str).is_none() {
+            return Err(create_player_error(player_id_str));
+        }
+
+        net.add_instance_member(instance_id_str, player_id_str);
+
+        lua_ctx.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function(
+        "Net",
+        "remove_instance_member",
+        |api_ctx, lua_ctx, params| {
+            let (instance_id, player_id): (mlua::String, mlua::String) =
+                lua_ctx.unpack_multi(params)?;
+            let (instance_id_str, player_id_str) = (instance_id.to_str()?, player_id.to_str()?);
+
+            let mut net = api_ctx.net_ref.borrow_mut();
+
+            net.remove_instance_member(instance_id_str, player_id_str);
+
+            lua_ctx.pack_multi(())
+        },
+    );
+
+    lua_api.add_dynamic_function("Net", "get_player_instance", |api_ctx, lua_ctx, params| {
+        let player_id: mlua::String = lua_ctx.unpack_multi(params)?;
+        let player_id_str = player_id.to_str()?;
+
+        let net = api_ctx.net_ref.borrow();
+
+        let instance_id = net
+            .get_instances()
+            .find(|instance| instance.is_member(player_id_str))
+            .map(|instance| instance.get_id());
+
+        lua_ctx.pack_multi(instance_id)
+    });
+}
@@ -9,3 +9,7 @@ pub fn create_bot_error(id: &str) -> mlua::Error {
 pub fn create_player_error(id: &str) -> mlua::Error {
     mlua::Error::RuntimeError(format!("No player matching {:?} found.", id))
 }
+
+pub fn create_instance_error(id: &str) -> mlua::Error {
+    mlua::Error::RuntimeError(format!("No instance matching {:?} found.", id))
+}
@@ -3,6 +3,7 @@ mod area_api;
 mod asset_api;
 mod async_api;
 mod bot_api;
+mod instance_api;
 mod logging_api;
 mod lua_errors;
 mod lua_helpers;
@@ -65,6 +66,7 @@ impl LuaApi {
         player_data_api::inject_dynamic(&mut lua_api);
         widget_api::inject_dynamic(&mut lua_api);
         bot_api::inject_dynamic(&mut lua_api);
+        instance_api::inject_dynamic(&mut lua_api);
         synchronization_api::inject_dynamic(&mut lua_api);
 
         async_api::inject_static(&mut lua_api);
@@ -206,6 +206,30 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
         },
     );
 
+    lua_api.add_dynamic_function("Net", "send_chat", |api_ctx, lua_ctx, params| {
+        let (player_id, message, actor_id): (mlua::String, mlua::String, Option<mlua::String>) =
+            lua_ctx.unpack_multi(params)?;
+        let (player_id_str, message_str) = (player_id.to_str()?, message.to_str()?);
+        let actor_id_str = actor_id.as_ref().map(|id| id.to_str()).transpose()?;
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        net.send_chat(player_id_str, actor_id_str, message_str);
+
+        lua_ctx.pack_multi(())
+    });
+
+    lua_api.add_dynamic_function("Net", "broadcast_chat", |api_ctx, lua_ctx, params| {
+        let (actor_id, message): (mlua::String, mlua::String) = lua_ctx.unpack_multi(params)?;
+        let (actor_id_str, message_str) = (actor_id.to_str()?, message.to_str()?);
+
+        let mut net = api_ctx.net_ref.borrow_mut();
+
+        net.broadcast_chat(actor_id_str, message_str);
+
+        lua_ctx.pack_multi(())
+    });
+
     lua_api.add_dynamic_function(
         "Net",
         "set_player_minimap_color",
@@ -564,14 +588,18 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
             }
         }
 
-        net.initiate_netplay(&player_ids, package_path, data);
+        net.initiate_netplay(&player_ids, package_path, data, Vec::new());
 
         lua_ctx.pack_multi(())
     });
 
     lua_api.add_dynamic_function("Net", "_initiate_netplay", |api_ctx, lua_ctx, params| {
-        let (player_ids, package_path, data): (Vec<mlua::String>, Option<String>, Option<String>) =
-            lua_ctx.unpack_multi(params)?;
+        let (player_ids, package_path, data, teams): (
+            Vec<mlua::String>,
+            Option<String>,
+            Option<String>,
+            Option<Vec<u8>>,
+        ) = lua_ctx.unpack_multi(params)?;
 
         let mut net = api_ctx.net_ref.borrow_mut();
         let mut battle_tracker = api_ctx.battle_tracker_ref.borrow_mut();
@@ -585,7 +613,7 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
             }
         }
 
-        net.initiate_netplay(&player_ids, package_path, data);
+        net.initiate_netplay(&player_ids, package_path, data, teams.unwrap_or_default());
 
         lua_ctx.pack_multi(())
     });
@@ -628,6 +656,44 @@ pub fn inject_dynamic(lua_api: &mut LuaApi) {
         },
     );
 
+    lua_api.add_dynamic_function(
+        "Net",
+        "set_folder_rules_for_player",
+        |api_ctx, lua_ctx, params| {
+            use crate::net::FolderRules;
+
+            let (player_id, rules_table): (mlua::String, Option<mlua::Table>) =
+                lua_ctx.unpack_multi(params)?;
+            let player_id_str = player_id.to_str()?;
+
+            // unset fields fall back to the default rules
+            let rules = match rules_table {
+                Some(rules_table) => {
+                    let default_rules = FolderRules::default();
+
+                    Some(FolderRules {
+                        mega_limit: rules_table
+                            .get::<_, Option<usize>>("mega_limit")?
+                            .unwrap_or(default_rules.mega_limit),
+                        giga_limit: rules_table
+                            .get::<_, Option<usize>>("giga_limit")?
+                            .unwrap_or(default_rules.giga_limit),
+                        dark_limit: rules_table
+                            .get::<_, Option<usize>>("dark_limit")?
+                            .unwrap_or(default_rules.dark_limit),
+                    })
+                }
+                None => None,
+            };
+
+            let mut net = api_ctx.net_ref.borrow_mut();
+
+            net.set_folder_rules_for_player(player_id_str, rules);
+
+            lua_ctx.pack_multi(())
+        },
+    );
+
     lua_api.add_dynamic_function("Net", "offer_package", |api_ctx, lua_ctx, params| {
         let (player_id, package_id): (mlua::String, mlua::String) = lua_ctx.unpack_multi(params)?;
         let player_id_str = player_id.to_str()?;
@@ -1,6 +1,6 @@
 use super::api::{ApiContext, LuaApi};
 use crate::jobs::JobPromiseManager;
-use crate::net::{BattleStatistics, Net, WidgetTracker};
+use crate::net::{Authorization, BattleStatistics, Net, WidgetTracker};
 use crate::plugins::PluginInterface;
 use mlua::Lua;
 use std::cell::RefCell;
@@ -133,7 +133,7 @@ impl PluginInterface for LuaPluginInterface {
         );
     }
 
-    fn handle_authorization(&mut self, net: &mut Net, identity: &str, host: &str, data: &[u8]) {
+    fn handle_authorization(&mut self, net: &mut Net, authorization: &Authorization) {
         handle_event(
             &mut self.scripts,
             &self.all_scripts,
@@ -143,12 +143,13 @@ impl PluginInterface for LuaPluginInterface {
             &mut self.lua_api,
             net,
             |lua_ctx, callback| {
-                let data_string = lua_ctx.create_string(data)?;
+                let data_string = lua_ctx.create_string(&authorization.data)?;
 
                 let event = lua_ctx.create_table()?;
-                event.set("identity", identity)?;
-                event.set("host", host)?;
+                event.set("identity", authorization.identity.as_str())?;
+                event.set("host", authorization.host.as_str())?;
                 event.set("data", data_string)?;
+                event.set("player_id", authorization.player_id.as_deref())?;
 
                 callback.call(("authorization", event))
             },
@@ -359,6 +360,56 @@ impl PluginInterface for LuaPluginInterface {
         prevent_default.get()
     }
 
+    fn handle_player_chat(
+        &mut self,
+        net: &mut Net,
+        player_id: &str,
+        message: &str,
+    ) -> Option<String> {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let prevent_default = Rc::new(Cell::new(false));
+        let mut message = message.to_string();
+
+        handle_event(
+            &mut self.scripts,
+            &self.all_scripts,
+            &mut self.widget_trackers,
+            &mut self.battle_trackers,
+            &mut self.promise_manager,
+            &mut self.lua_api,
+            net,
+            |lua_ctx, callback| {
+                let prevent_default_reference = prevent_default.clone();
+
+                let event = lua_ctx.create_table()?;
+                event.set("player_id", player_id)?;
+                event.set("message", message.as_str())?;
+                event.set(
+                    "prevent_default",
+                    lua_ctx.create_function(move |_, _: ()| {
+                        prevent_default_reference.clone().set(true);
+                        Ok(())
+                    })?,
+                )?;
+
+                callback.call(("player_chat", event.clone()))?;
+
+                // scripts can rewrite the message
+                message = event.get("message")?;
+
+                Ok(())
+            },
+        );
+
+        if prevent_default.get() {
+            None
+        } else {
+            Some(message)
+        }
+    }
+
     fn handle_custom_warp(&mut self, net: &mut Net, player_id: &str, tile_object_id: u32) {
         handle_event(
             &mut self.scripts,
@@ -682,6 +733,25 @@ impl PluginInterface for LuaPluginInterface {
         );
     }
 
+    fn handle_item_use(&mut self, net: &mut Net, player_id: &str, item_id: &str) {
+        handle_event(
+            &mut self.scripts,
+            &self.all_scripts,
+            &mut self.widget_trackers,
+            &mut self.battle_trackers,
+            &mut self.promise_manager,
+            &mut self.lua_api,
+            net,
+            |lua_ctx, callback| {
+                let event = lua_ctx.create_table()?;
+                event.set("player_id", player_id)?;
+                event.set("item_id", item_id)?;
+
+                callback.call(("item_use", event))
+            },
+        );
+    }
+
     fn handle_battle_results(
         &mut self,
         net: &mut Net,
@@ -746,6 +816,51 @@ impl PluginInterface for LuaPluginInterface {
         );
     }
 
+    fn handle_instance_start(
+        &mut self,
+        net: &mut Net,
+        instance_id: &str,
+        template_id: &str,
+        player_ids: &[String],
+    ) {
+        handle_event(
+            &mut self.scripts,
+            &self.all_scripts,
+            &mut self.widget_trackers,
+            &mut self.battle_trackers,
+            &mut self.promise_manager,
+            &mut self.lua_api,
+            net,
+            |lua_ctx, callback| {
+                let event = lua_ctx.create_table()?;
+                event.set("instance_id", instance_id)?;
+                event.set("template_id", template_id)?;
+                event.set("player_ids", player_ids.to_vec())?;
+
+                callback.call(("instance_start", event))
+            },
+        );
+    }
+
+    fn handle_instance_end(&mut self, net: &mut Net, instance_id: &str, template_id: &str) {
+        handle_event(
+            &mut self.scripts,
+            &self.all_scripts,
+            &mut self.widget_trackers,
+            &mut self.battle_trackers,
+            &mut self.promise_manager,
+            &mut self.lua_api,
+            net,
+            |lua_ctx, callback| {
+                let event = lua_ctx.create_table()?;
+                event.set("instance_id", instance_id)?;
+                event.set("template_id", template_id)?;
+
+                callback.call(("instance_end", event))
+            },
+        );
+    }
+
     fn handle_server_message(
         &mut self,
         net: &mut Net,
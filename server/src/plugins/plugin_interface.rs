@@ -1,9 +1,9 @@
-use crate::net::{BattleStatistics, Net};
+use crate::net::{Authorization, BattleStatistics, Net};
 
 pub trait PluginInterface {
     fn init(&mut self, net: &mut Net);
     fn tick(&mut self, net: &mut Net, delta_time: f32);
-    fn handle_authorization(&mut self, net: &mut Net, identity: &str, host: &str, data: &[u8]);
+    fn handle_authorization(&mut self, net: &mut Net, authorization: &Authorization);
     fn handle_player_request(&mut self, net: &mut Net, player_id: &str, data: &str);
     fn handle_player_connect(&mut self, net: &mut Net, player_id: &str);
     fn handle_player_join(&mut self, net: &mut Net, player_id: &str);
@@ -21,6 +21,12 @@ pub trait PluginInterface {
         max_health: u32,
     ) -> bool;
     fn handle_player_emote(&mut self, net: &mut Net, player_id: &str, emote_id: u8) -> bool;
+    fn handle_player_chat(
+        &mut self,
+        net: &mut Net,
+        player_id: &str,
+        message: &str,
+    ) -> Option<String>;
     fn handle_custom_warp(&mut self, net: &mut Net, player_id: &str, tile_object_id: u32);
     fn handle_object_interaction(
         &mut self,
@@ -53,12 +59,21 @@ pub trait PluginInterface {
     fn handle_post_selection(&mut self, net: &mut Net, player_id: &str, post_id: &str);
     fn handle_shop_close(&mut self, net: &mut Net, player_id: &str);
     fn handle_shop_purchase(&mut self, net: &mut Net, player_id: &str, post_id: &str);
+    fn handle_item_use(&mut self, net: &mut Net, player_id: &str, item_id: &str);
     fn handle_battle_results(
         &mut self,
         net: &mut Net,
         player_id: &str,
         battle_stats: &BattleStatistics,
     );
+    fn handle_instance_start(
+        &mut self,
+        net: &mut Net,
+        instance_id: &str,
+        template_id: &str,
+        player_ids: &[String],
+    );
+    fn handle_instance_end(&mut self, net: &mut Net, instance_id: &str, template_id: &str);
     fn handle_server_message(
         &mut self,
         net: &mut Net,
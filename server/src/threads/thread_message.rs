@@ -20,7 +20,7 @@ pub enum ThreadMessage {
     },
     MessageServer {
         socket_address: SocketAddr,
-        data: Vec<u8>,
+        packet: ServerCommPacket,
     },
     PollServer {
         socket_address: SocketAddr,